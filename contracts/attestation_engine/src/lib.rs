@@ -1,11 +1,15 @@
 #![no_std]
-use shared_utils::{BatchError, BatchMode, BatchProcessor, BatchResultVoid, Pausable, RateLimiter};
+use shared_utils::{
+    BatchError, BatchMode, BatchProcessor, BatchResultVoid, Pausable, RateLimiter, RoundingMode,
+    SafeMath,
+};
+pub use shared_utils::{Commitment, CommitmentRules};
 use soroban_sdk::{
     contract, contracterror, contractimpl, contracttype, symbol_short, token, Address, BytesN, Env,
     IntoVal, Map, String, Symbol, TryIntoVal, Val, Vec,
 };
 
-const CURRENT_VERSION: u32 = 1;
+const CURRENT_VERSION: u32 = 2;
 
 // ============================================================================
 // Error Types
@@ -44,6 +48,26 @@ pub enum AttestationError {
     InvalidVersion = 13,
     /// Migration already applied.
     AlreadyMigrated = 14,
+    /// Score cap must be between 0 and 100.
+    InvalidScoreCap = 15,
+    /// Score delta is outside -MAX_SCORE_DELTA..=MAX_SCORE_DELTA.
+    InvalidScoreDelta = 16,
+    /// Time range start is after its end.
+    InvalidTimeRange = 17,
+    /// Attestation type is neither built in nor registered (strict mode).
+    UnknownAttestationType = 18,
+    /// Verifier's time-boxed grant has lapsed.
+    VerifierExpired = 19,
+    /// Treasury share of the fee split exceeds 10000 basis points.
+    InvalidFeeSplit = 20,
+    /// Call re-entered while an attestation was being recorded.
+    ReentrancyDetected = 21,
+    /// Commitment has reached its fee cap and capped attestations are rejected.
+    FeeCapReached = 22,
+    /// Fee asset does not report its decimals like a token contract.
+    InvalidFeeAsset = 23,
+    /// NFT token id could not be resolved to a commitment.
+    UnknownToken = 24,
 }
 
 // ============================================================================
@@ -59,8 +83,13 @@ pub enum DataKey {
     CoreContract,
     /// Verifier whitelist (Address -> bool)
     Verifier(Address),
+    /// Expiry of a time-boxed verifier grant; absent means permanent (Address -> u64)
+    VerifierExpiry(Address),
     /// Attestations for a commitment (commitment_id -> Vec<Attestation>)
     Attestations(String),
+    /// Evidence hash of an attestation, by its index in the commitment's list
+    /// ((commitment_id, index) -> BytesN<32>)
+    AttestationEvidence(String, u32),
     /// Health metrics for a commitment (commitment_id -> HealthMetrics)
     HealthMetrics(String),
     /// Attestation counter for a commitment (commitment_id -> u64)
@@ -75,6 +104,9 @@ pub enum DataKey {
     TotalFees,
     /// Per-verifier analytics: attestation count by verifier
     VerifierAttestationCount(Address),
+    /// Attestations recorded by a verifier, as (commitment_id, index into that
+    /// commitment's Attestations) references (Address -> Vec<(String, u32)>)
+    VerifierAttestations(Address),
     /// Fee collection: protocol treasury for withdrawals
     FeeRecipient,
     /// Attestation verification fee: amount per attestation (0 = no fee)
@@ -83,8 +115,66 @@ pub enum DataKey {
     AttestationFeeAsset,
     /// Collected fees per asset (asset -> i128)
     CollectedFees(Address),
+    /// Decimals of a fee asset, read when it was configured (asset -> u32)
+    FeeDecimals(Address),
+    /// Fee split: protocol treasury and its share of each fee in basis points
+    /// ((Address, u32))
+    FeeSplit,
+    /// Fees accrued to the fee-split treasury per asset (asset -> i128)
+    TreasuryFees(Address),
+    /// Most attestation fees a single commitment is charged; 0 = no cap (i128)
+    MaxFeesPerCommitment,
+    /// Whether attestations past the fee cap are rejected rather than fee-free (bool)
+    RejectOverFeeCap,
+    /// Attestation fees charged so far for a commitment (commitment_id -> i128)
+    CommitmentFeesCharged(String),
     /// Storage schema version
     Version,
+    /// Reconciliation rule for conflicting attestations (ConflictPolicy)
+    ConflictPolicy,
+    /// Maximum compliance score when a high-severity violation exists (u32)
+    HighSeverityCap,
+    /// Recent compliance scores for a commitment (commitment_id -> Vec<ScorePoint>)
+    ScoreHistory(String),
+    /// Running compliance aggregate over commitments with stored metrics
+    /// ((tracked: u32, compliant: u32, score_sum: u64))
+    ComplianceAggregate,
+    /// Amount-weighted compliance aggregate over the same commitments
+    /// ((weight_sum: i128, weighted_score_sum: i128))
+    WeightedComplianceAggregate,
+    /// Compliance weight of a commitment: its core amount when first scored.
+    /// Its presence marks the commitment as counted in the running compliance
+    /// aggregates (commitment_id -> i128)
+    ComplianceWeight(String),
+    /// Registered custom attestation type (type -> score effect i32)
+    AttestationType(String),
+    /// Strict mode for unregistered attestation types (bool); unset keeps the
+    /// legacy `InvalidAttestationType` rejection
+    StrictAttestationTypes,
+    /// Attestations a commitment needs before it can be compliant (u32, default 0)
+    MinAttestationsForCompliance,
+    /// Stored compliance scores bucketed by SCORE_BUCKET_WIDTH
+    /// (Vec<u32> of SCORE_BUCKETS counts)
+    ScoreHistogram,
+    /// Top verifiers by attestation count, highest first
+    /// (Vec<(Address, u32)>, at most MAX_LEADERBOARD_SIZE entries)
+    VerifierLeaderboard,
+}
+
+/// How conflicting attestations on the same condition are reconciled when scoring.
+///
+/// A condition is identified by the attestation's `condition` data field,
+/// falling back to its `attestation_type`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConflictPolicy {
+    /// Every violation is penalized (default)
+    SumAll,
+    /// A condition is violated only if violations are at least as many as
+    /// compliant attestations (ties resolve to violation)
+    MajorityWins,
+    /// A condition is violated if any attestation reports a violation
+    ViolationWins,
 }
 
 #[contracttype]
@@ -108,6 +198,18 @@ pub struct AttestParams {
     pub is_compliant: bool,
 }
 
+/// Cross-contract wiring reported by `link_status`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LinkStatus {
+    /// A commitment_core reference is configured (always true on the core itself)
+    pub core_set: bool,
+    /// A commitment_nft reference is configured (always true on the NFT itself)
+    pub nft_set: bool,
+    /// Every configured reference answered a readiness call
+    pub reachable: bool,
+}
+
 /// Paginated result for get_attestations_page.
 /// Ordering is by timestamp (oldest first, same as insertion order).
 #[contracttype]
@@ -121,33 +223,6 @@ pub struct AttestationsPage {
 /// Maximum number of attestations returned per page (avoids exceeding Soroban limits).
 pub const MAX_PAGE_SIZE: u32 = 100;
 
-// Import Commitment types from commitment_core (define locally for cross-contract calls)
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct CommitmentRules {
-    pub duration_days: u32,
-    pub max_loss_percent: u32,
-    pub commitment_type: String, // "safe", "balanced", "aggressive"
-    pub early_exit_penalty: u32,
-    pub min_fee_threshold: i128,
-    pub grace_period_days: u32,
-}
-
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Commitment {
-    pub commitment_id: String,
-    pub owner: Address,
-    pub nft_token_id: u32,
-    pub rules: CommitmentRules,
-    pub amount: i128,
-    pub asset_address: Address,
-    pub created_at: u64,
-    pub expires_at: u64,
-    pub current_value: i128,
-    pub status: String, // "active", "settled", "violated", "early_exit"
-}
-
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct HealthMetrics {
@@ -161,6 +236,104 @@ pub struct HealthMetrics {
     pub compliance_score: u32, // 0-100
 }
 
+/// Live health metrics together with values derived on read.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HealthMetricsView {
+    pub metrics: HealthMetrics,
+    /// Estimated payout if the owner exited now: `current_value` less the
+    /// early-exit penalty while the commitment is active and unexpired.
+    /// An estimate only; the core contract's rounding and status decide the
+    /// actual amount.
+    pub estimated_payout: i128,
+}
+
+/// Maximum number of score points kept per commitment (oldest are dropped).
+pub const MAX_SCORE_HISTORY: u32 = 20;
+
+/// Maximum number of attestations embedded in a `CommitmentReport`.
+pub const MAX_REPORT_ATTESTATIONS: u32 = 10;
+
+/// Largest score adjustment a verifier may apply via `attest_with_delta`.
+pub const MAX_SCORE_DELTA: i32 = 50;
+
+/// Maximum number of an owner's commitments scanned by `get_owner_summary`.
+pub const MAX_OWNER_SUMMARY_SCAN: u32 = 50;
+
+/// Commitments fetched from the core contract per call in `get_owner_summary`.
+pub const OWNER_SUMMARY_PAGE_SIZE: u32 = 10;
+
+/// Maximum number of verifiers kept on the attestation-count leaderboard.
+pub const MAX_LEADERBOARD_SIZE: u32 = 10;
+
+/// Width of a compliance score histogram bucket; 100 falls in the top bucket.
+pub const SCORE_BUCKET_WIDTH: u32 = 10;
+
+/// Number of buckets in the compliance score histogram.
+pub const SCORE_BUCKETS: u32 = 10;
+
+/// Compliance score recorded after an attestation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScorePoint {
+    pub timestamp: u64,
+    pub score: u32,
+}
+
+/// Stored compliance score at or above which a commitment counts as compliant.
+pub const COMPLIANT_SCORE_THRESHOLD: u32 = 50;
+
+/// System-wide compliance across every commitment with stored health metrics.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SystemCompliance {
+    pub total: u32,
+    pub compliant: u32,
+    pub non_compliant: u32,
+    /// Mean stored compliance score, rounded down (0 when `total` is 0)
+    pub average_score: u32,
+}
+
+/// Core commitment together with its health metrics and compliance flag.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FullHealth {
+    pub commitment: Commitment,
+    pub metrics: HealthMetrics,
+    /// Same result `verify_compliance` would return.
+    pub compliant: bool,
+}
+
+/// Combined view of a commitment's health for clients.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitmentReport {
+    pub metrics: HealthMetrics,
+    /// Most recent attestations, oldest first (at most MAX_REPORT_ATTESTATIONS).
+    pub recent_attestations: Vec<Attestation>,
+    /// Score trend, oldest first (at most MAX_SCORE_HISTORY).
+    pub score_history: Vec<ScorePoint>,
+}
+
+/// Aggregate view of an owner's commitments for dashboards.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnerSummary {
+    /// Commitments (and so NFTs) summarized
+    pub nft_count: u32,
+    /// Sum of committed amounts
+    pub total_committed: i128,
+    /// Sum of current values
+    pub total_current: i128,
+    /// Drawdown of `total_current` against `total_committed` in basis points
+    pub aggregate_drawdown_bps: u32,
+    /// Mean compliance score; 0 when the owner has no commitments
+    pub average_compliance: u32,
+    /// True when the owner has more than MAX_OWNER_SUMMARY_SCAN commitments
+    /// and only the first ones were summarized
+    pub truncated: bool,
+}
+
 #[contract]
 pub struct AttestationEngineContract;
 
@@ -225,6 +398,9 @@ impl AttestationEngineContract {
         e.storage()
             .instance()
             .set(&DataKey::Verifier(verifier.clone()), &true);
+        e.storage()
+            .instance()
+            .remove(&DataKey::VerifierExpiry(verifier.clone()));
 
         // Emit event
         e.events()
@@ -233,6 +409,44 @@ impl AttestationEngineContract {
         Ok(())
     }
 
+    /// Add a verifier whose authorization lapses after `expires_at`
+    ///
+    /// The verifier may attest up to and including `expires_at`; afterwards
+    /// `attest` fails with `VerifierExpired`. Calling `add_verifier` later
+    /// makes the grant permanent again.
+    pub fn add_verifier_until(
+        e: Env,
+        admin: Address,
+        verifier: Address,
+        expires_at: u64,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &admin)?;
+        if expires_at <= e.ledger().timestamp() {
+            return Err(AttestationError::InvalidTimeRange);
+        }
+
+        e.storage()
+            .instance()
+            .set(&DataKey::Verifier(verifier.clone()), &true);
+        e.storage()
+            .instance()
+            .set(&DataKey::VerifierExpiry(verifier.clone()), &expires_at);
+
+        e.events().publish(
+            (Symbol::new(&e, "VerifierAddedUntil"),),
+            (verifier, expires_at),
+        );
+
+        Ok(())
+    }
+
+    /// Get the expiry of a verifier's grant (None if permanent or not a verifier)
+    pub fn get_verifier_expiry(e: Env, verifier: Address) -> Option<u64> {
+        e.storage()
+            .instance()
+            .get(&DataKey::VerifierExpiry(verifier))
+    }
+
     /// Remove a verifier from the whitelist
     ///
     /// # Arguments
@@ -260,6 +474,9 @@ impl AttestationEngineContract {
         e.storage()
             .instance()
             .remove(&DataKey::Verifier(verifier.clone()));
+        e.storage()
+            .instance()
+            .remove(&DataKey::VerifierExpiry(verifier.clone()));
 
         // Emit event
         e.events()
@@ -270,6 +487,12 @@ impl AttestationEngineContract {
 
     /// Check if an address is an authorized verifier
     fn is_authorized_verifier(e: &Env, address: &Address) -> bool {
+        Self::check_verifier(e, address).is_ok()
+    }
+
+    /// Check an address's verifier grant, distinguishing a lapsed time-boxed
+    /// grant (`VerifierExpired`) from no grant at all (`Unauthorized`)
+    fn check_verifier(e: &Env, address: &Address) -> Result<(), AttestationError> {
         // Admin is always authorized
         if let Some(admin) = e
             .storage()
@@ -277,15 +500,29 @@ impl AttestationEngineContract {
             .get::<DataKey, Address>(&DataKey::Admin)
         {
             if *address == admin {
-                return true;
+                return Ok(());
             }
         }
 
         // Check verifier whitelist
-        e.storage()
+        let whitelisted: bool = e
+            .storage()
             .instance()
             .get(&DataKey::Verifier(address.clone()))
-            .unwrap_or(false)
+            .unwrap_or(false);
+        if !whitelisted {
+            return Err(AttestationError::Unauthorized);
+        }
+        let expiry: Option<u64> = e
+            .storage()
+            .instance()
+            .get(&DataKey::VerifierExpiry(address.clone()));
+        match expiry {
+            Some(expires_at) if e.ledger().timestamp() > expires_at => {
+                Err(AttestationError::VerifierExpired)
+            }
+            _ => Ok(()),
+        }
     }
 
     /// Pause the contract
@@ -340,6 +577,12 @@ impl AttestationEngineContract {
         Self::is_authorized_verifier(&e, &address)
     }
 
+    /// Check whether `initialize` has run (the admin is set); a readiness
+    /// probe for deployment tooling.
+    pub fn is_initialized(e: Env) -> bool {
+        e.storage().instance().has(&DataKey::Admin)
+    }
+
     /// Get the admin address
     pub fn get_admin(e: Env) -> Result<Address, AttestationError> {
         e.storage()
@@ -348,6 +591,22 @@ impl AttestationEngineContract {
             .ok_or(AttestationError::NotInitialized)
     }
 
+    /// Check whether the reentrancy guard is currently set.
+    pub fn is_locked(e: Env) -> bool {
+        e.storage().instance().has(&DataKey::ReentrancyGuard)
+    }
+
+    /// Clear a reentrancy guard left set by an aborted operation. Admin only.
+    pub fn force_unlock(e: Env, admin: Address) -> Result<(), AttestationError> {
+        require_admin(&e, &admin)?;
+        e.storage().instance().remove(&DataKey::ReentrancyGuard);
+        e.events().publish(
+            (Symbol::new(&e, "GuardForceCleared"),),
+            (admin, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
     /// Get the core contract address
     pub fn get_core_contract(e: Env) -> Result<Address, AttestationError> {
         e.storage()
@@ -361,6 +620,30 @@ impl AttestationEngineContract {
         read_version(&e)
     }
 
+    /// Report whether the core contract is configured and reachable; a
+    /// readiness signal for deployment tooling.
+    ///
+    /// The engine holds no NFT reference, so `nft_set` and `reachable` come
+    /// from the core contract's own `link_status`.
+    pub fn link_status(e: Env) -> LinkStatus {
+        let core: Option<Address> = e.storage().instance().get(&DataKey::CoreContract);
+        let core_link = core.as_ref().and_then(|core| {
+            match e.try_invoke_contract::<LinkStatus, soroban_sdk::Error>(
+                core,
+                &Symbol::new(&e, "link_status"),
+                Vec::new(&e),
+            ) {
+                Ok(Ok(status)) => Some(status),
+                _ => None,
+            }
+        });
+        LinkStatus {
+            core_set: core.is_some(),
+            nft_set: core_link.as_ref().is_some_and(|link| link.nft_set),
+            reachable: core_link.is_some_and(|link| link.reachable),
+        }
+    }
+
     /// Update admin (admin-only).
     pub fn set_admin(e: Env, caller: Address, new_admin: Address) -> Result<(), AttestationError> {
         require_admin(&e, &caller)?;
@@ -404,11 +687,8 @@ impl AttestationEngineContract {
         if !e.storage().instance().has(&DataKey::TotalFees) {
             e.storage().instance().set(&DataKey::TotalFees, &0i128);
         }
-        if !e.storage().instance().has(&DataKey::ReentrancyGuard) {
-            e.storage()
-                .instance()
-                .set(&DataKey::ReentrancyGuard, &false);
-        }
+        // The reentrancy guard is a presence flag, so it must stay unset here
+        backfill_compliance_aggregates(&e);
 
         e.storage()
             .instance()
@@ -416,6 +696,106 @@ impl AttestationEngineContract {
         Ok(())
     }
 
+    /// Get the recorded compliance score trend for a commitment, oldest first.
+    pub fn get_score_history(e: Env, commitment_id: String) -> Vec<ScorePoint> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::ScoreHistory(commitment_id))
+            .unwrap_or_else(|| Vec::new(&e))
+    }
+
+    /// Summarize an owner's commitments: count, committed and current value,
+    /// aggregate drawdown and average compliance score.
+    ///
+    /// Reads the owner index from the core contract and fetches commitments
+    /// in pages of `OWNER_SUMMARY_PAGE_SIZE`, scanning at most
+    /// `MAX_OWNER_SUMMARY_SCAN` of them (oldest first).
+    pub fn get_owner_summary(e: Env, owner: Address) -> Result<OwnerSummary, AttestationError> {
+        let commitment_core: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::CoreContract)
+            .ok_or(AttestationError::NotInitialized)?;
+        let ids: Vec<String> = match e.try_invoke_contract::<Vec<String>, soroban_sdk::Error>(
+            &commitment_core,
+            &Symbol::new(&e, "get_owner_commitments"),
+            Vec::from_array(&e, [owner.into_val(&e)]),
+        ) {
+            Ok(Ok(ids)) => ids,
+            _ => return Err(AttestationError::CommitmentNotFound),
+        };
+        let scanned = ids.len().min(MAX_OWNER_SUMMARY_SCAN);
+
+        let mut summary = OwnerSummary {
+            nft_count: 0,
+            total_committed: 0,
+            total_current: 0,
+            aggregate_drawdown_bps: 0,
+            average_compliance: 0,
+            truncated: ids.len() > scanned,
+        };
+        let mut score_sum: u64 = 0;
+        let mut start = 0;
+        while start < scanned {
+            let end = (start + OWNER_SUMMARY_PAGE_SIZE).min(scanned);
+            let page: Vec<Commitment> = match e
+                .try_invoke_contract::<Vec<Commitment>, soroban_sdk::Error>(
+                    &commitment_core,
+                    &Symbol::new(&e, "get_commitments"),
+                    Vec::from_array(&e, [ids.slice(start..end).into_val(&e)]),
+                ) {
+                Ok(Ok(page)) => page,
+                _ => return Err(AttestationError::CommitmentNotFound),
+            };
+            for commitment in page.iter() {
+                summary.nft_count += 1;
+                summary.total_committed = SafeMath::add(summary.total_committed, commitment.amount);
+                summary.total_current =
+                    SafeMath::add(summary.total_current, commitment.current_value);
+                let score = Self::calculate_compliance_score(e.clone(), commitment.commitment_id);
+                score_sum += score as u64;
+            }
+            start = end;
+        }
+
+        if summary.nft_count > 0 {
+            summary.average_compliance = (score_sum / summary.nft_count as u64) as u32;
+        }
+        if summary.total_committed > 0 && summary.total_current < summary.total_committed {
+            summary.aggregate_drawdown_bps = ((summary.total_committed - summary.total_current)
+                * 10000
+                / summary.total_committed) as u32;
+        }
+        Ok(summary)
+    }
+
+    /// Get current health metrics, the most recent attestations and the score
+    /// trend for a commitment in a single call.
+    pub fn get_commitment_report(e: Env, commitment_id: String) -> CommitmentReport {
+        let metrics = Self::get_health_metrics(e.clone(), commitment_id.clone());
+        let count = e
+            .storage()
+            .persistent()
+            .get::<_, Vec<Attestation>>(&DataKey::Attestations(commitment_id.clone()))
+            .map(|all| all.len())
+            .unwrap_or(0);
+        let offset = count.saturating_sub(MAX_REPORT_ATTESTATIONS);
+        let recent_attestations = Self::get_attestations_page(
+            e.clone(),
+            commitment_id.clone(),
+            offset,
+            MAX_REPORT_ATTESTATIONS,
+        )
+        .attestations;
+        let score_history = Self::get_score_history(e, commitment_id);
+
+        CommitmentReport {
+            metrics,
+            recent_attestations,
+            score_history,
+        }
+    }
+
     /// Get stored health metrics for a commitment (without recalculation)
     pub fn get_stored_health_metrics(e: Env, commitment_id: String) -> Option<HealthMetrics> {
         let key = DataKey::HealthMetrics(commitment_id);
@@ -426,7 +806,7 @@ impl AttestationEngineContract {
     // Validation Helpers
     // ========================================================================
 
-    /// Validate attestation type is one of the allowed types
+    /// Validate attestation type is one of the built-in types
     fn is_valid_attestation_type(e: &Env, att_type: &String) -> bool {
         let health_check = String::from_str(e, "health_check");
         let violation = String::from_str(e, "violation");
@@ -463,7 +843,33 @@ impl AttestationEngineContract {
             let drawdown_percent_key = String::from_str(e, "drawdown_percent");
             data.contains_key(drawdown_percent_key)
         } else {
-            false
+            // Registered and (outside strict mode) unregistered custom types
+            // carry free-form data
+            true
+        }
+    }
+
+    /// Check an attestation type against the built-in and registered types.
+    ///
+    /// Returns the registered score effect for custom types. Unregistered
+    /// types fail with `UnknownAttestationType` in strict mode and are
+    /// accepted without a score effect once strict mode is explicitly turned
+    /// off. Until either is set they fail with `InvalidAttestationType`.
+    fn check_attestation_type(e: &Env, att_type: &String) -> Result<Option<i32>, AttestationError> {
+        if Self::is_valid_attestation_type(e, att_type) {
+            return Ok(None);
+        }
+        if let Some(effect) = e
+            .storage()
+            .instance()
+            .get::<_, i32>(&DataKey::AttestationType(att_type.clone()))
+        {
+            return Ok(Some(effect));
+        }
+        match Self::get_strict_attestation_types(e.clone()) {
+            Some(true) => Err(AttestationError::UnknownAttestationType),
+            Some(false) => Ok(None),
+            None => Err(AttestationError::InvalidAttestationType),
         }
     }
 
@@ -495,24 +901,74 @@ impl AttestationEngineContract {
     // Health Metrics Update
     // ========================================================================
 
+    /// Stored compliance score after an attestation moves it from `score`
+    ///
+    /// Violations cost 30/20/10 for high/medium/other severity (20 when no
+    /// severity is given) and other compliant attestations add 1, capped at
+    /// 100. When `score_delta` is set it replaces both, clamped to [0, 100].
+    fn next_compliance_score(
+        e: &Env,
+        score: u32,
+        attestation_type: &String,
+        is_compliant: bool,
+        severity: Option<String>,
+        score_delta: Option<i32>,
+    ) -> u32 {
+        let violation = String::from_str(e, "violation");
+        if let Some(delta) = score_delta {
+            // Verifier-supplied adjustment, clamped to [0, 100]
+            return (score as i32).saturating_add(delta).clamp(0, 100) as u32;
+        }
+        if *attestation_type == violation {
+            // Decrease compliance score for violations
+            let penalty = match severity {
+                Some(severity) if severity == String::from_str(e, "high") => 30u32,
+                Some(severity) if severity == String::from_str(e, "medium") => 20u32,
+                Some(_) => 10u32,
+                None => 20u32, // Default penalty
+            };
+            return score.saturating_sub(penalty);
+        }
+        if is_compliant {
+            // Small bonus for compliant attestations, capped at 100
+            return core::cmp::min(100, score.saturating_add(1));
+        }
+        score
+    }
+
     /// Update health metrics after an attestation
-    fn update_health_metrics(e: &Env, commitment_id: &String, attestation: &Attestation) {
+    ///
+    /// When `score_delta` is set it replaces the tiered violation penalty and
+    /// the compliance bonus.
+    fn update_health_metrics(
+        e: &Env,
+        commitment_id: &String,
+        attestation: &Attestation,
+        score_delta: Option<i32>,
+    ) {
         // Get or create health metrics
         let key = DataKey::HealthMetrics(commitment_id.clone());
-        let mut metrics: HealthMetrics =
-            e.storage()
-                .persistent()
-                .get(&key)
-                .unwrap_or_else(|| HealthMetrics {
-                    commitment_id: commitment_id.clone(),
-                    current_value: 0,
-                    initial_value: 0,
-                    drawdown_percent: 0,
-                    fees_generated: 0,
-                    volatility_exposure: 0,
-                    last_attestation: 0,
-                    compliance_score: 100,
-                });
+        let stored: Option<HealthMetrics> = e.storage().persistent().get(&key);
+        // Metrics stored before the running aggregates existed are not counted
+        // in them yet, so they enter the aggregates as new commitments
+        let tracked = e
+            .storage()
+            .persistent()
+            .has(&DataKey::ComplianceWeight(commitment_id.clone()));
+        let previous_score = stored
+            .as_ref()
+            .filter(|_| tracked)
+            .map(|m| m.compliance_score);
+        let mut metrics: HealthMetrics = stored.unwrap_or_else(|| HealthMetrics {
+            commitment_id: commitment_id.clone(),
+            current_value: 0,
+            initial_value: 0,
+            drawdown_percent: 0,
+            fees_generated: 0,
+            volatility_exposure: 0,
+            last_attestation: 0,
+            compliance_score: 100,
+        });
 
         // Update last_attestation timestamp
         metrics.last_attestation = attestation.timestamp;
@@ -520,7 +976,6 @@ impl AttestationEngineContract {
         // Update type-specific metrics
         let fee_generation = String::from_str(e, "fee_generation");
         let drawdown_type = String::from_str(e, "drawdown");
-        let violation = String::from_str(e, "violation");
 
         if attestation.attestation_type == fee_generation {
             // Add to fees_generated
@@ -548,35 +1003,99 @@ impl AttestationEngineContract {
                     metrics.drawdown_percent = drawdown_val;
                 }
             }
-        } else if attestation.attestation_type == violation {
-            // Decrease compliance score for violations
-            let severity_key = String::from_str(e, "severity");
-            let penalty = if let Some(severity) = attestation.data.get(severity_key) {
-                let high = String::from_str(e, "high");
-                let medium = String::from_str(e, "medium");
-                if severity == high {
-                    30u32
-                } else if severity == medium {
-                    20u32
-                } else {
-                    10u32
+        }
+
+        let severity = attestation.data.get(String::from_str(e, "severity"));
+        metrics.compliance_score = Self::next_compliance_score(
+            e,
+            metrics.compliance_score,
+            &attestation.attestation_type,
+            attestation.is_compliant,
+            severity,
+            score_delta,
+        );
+
+        // Store updated metrics
+        e.storage().persistent().set(&key, &metrics);
+        update_compliance_aggregate(e, previous_score, metrics.compliance_score);
+        update_score_histogram(e, previous_score, metrics.compliance_score);
+        update_weighted_compliance_aggregate(
+            e,
+            commitment_id,
+            previous_score,
+            metrics.compliance_score,
+        );
+
+        // Record the score trend, keeping only the most recent points
+        let history_key = DataKey::ScoreHistory(commitment_id.clone());
+        let mut history: Vec<ScorePoint> = e
+            .storage()
+            .persistent()
+            .get(&history_key)
+            .unwrap_or_else(|| Vec::new(e));
+        history.push_back(ScorePoint {
+            timestamp: attestation.timestamp,
+            score: metrics.compliance_score,
+        });
+        while history.len() > MAX_SCORE_HISTORY {
+            history.pop_front();
+        }
+        e.storage().persistent().set(&history_key, &history);
+    }
+
+    /// Count violations across attestations, reconciling conflicts per condition
+    /// according to `policy`.
+    fn count_violations(e: &Env, attestations: &Vec<Attestation>, policy: ConflictPolicy) -> u32 {
+        let violation = String::from_str(e, "violation");
+
+        if policy == ConflictPolicy::SumAll {
+            return attestations
+                .iter()
+                .filter(|att| !att.is_compliant || att.attestation_type == violation)
+                .count() as u32;
+        }
+
+        // Tally violations and compliant attestations per condition
+        let condition_key = String::from_str(e, "condition");
+        let mut conditions: Vec<String> = Vec::new(e);
+        let mut violations: Vec<u32> = Vec::new(e);
+        let mut compliant: Vec<u32> = Vec::new(e);
+
+        for att in attestations.iter() {
+            let condition = att
+                .data
+                .get(condition_key.clone())
+                .unwrap_or(att.attestation_type.clone());
+            let idx = match conditions.first_index_of(&condition) {
+                Some(idx) => idx,
+                None => {
+                    conditions.push_back(condition);
+                    violations.push_back(0);
+                    compliant.push_back(0);
+                    conditions.len() - 1
                 }
-            } else {
-                20u32 // Default penalty
             };
 
-            metrics.compliance_score = metrics.compliance_score.saturating_sub(penalty);
+            if !att.is_compliant || att.attestation_type == violation {
+                violations.set(idx, violations.get(idx).unwrap_or(0) + 1);
+            } else {
+                compliant.set(idx, compliant.get(idx).unwrap_or(0) + 1);
+            }
         }
 
-        // Compliance bonus for compliant attestations
-        if attestation.is_compliant && attestation.attestation_type != violation {
-            // Small bonus for compliant attestations, capped at 100
-            metrics.compliance_score =
-                core::cmp::min(100, metrics.compliance_score.saturating_add(1));
+        let mut count: u32 = 0;
+        for idx in 0..conditions.len() {
+            let v = violations.get(idx).unwrap_or(0);
+            let c = compliant.get(idx).unwrap_or(0);
+            let violated = match policy {
+                ConflictPolicy::MajorityWins => v > 0 && v >= c,
+                _ => v > 0,
+            };
+            if violated {
+                count += 1;
+            }
         }
-
-        // Store updated metrics
-        e.storage().persistent().set(&key, &metrics);
+        count
     }
 
     /// Parse i128 from String (optimized implementation)
@@ -645,31 +1164,139 @@ impl AttestationEngineContract {
         data: Map<String, String>,
         is_compliant: bool,
     ) -> Result<(), AttestationError> {
-        // 1. Reentrancy protection
-        if e.storage().instance().has(&DataKey::ReentrancyGuard) {
-            panic!("Reentrancy detected");
-        }
-        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
-
-        // Check if contract is paused
-        Pausable::require_not_paused(&e);
-
-        // 2. Verify caller signed the transaction
-        caller.require_auth();
+        let params = AttestParams {
+            commitment_id,
+            attestation_type,
+            data,
+            is_compliant,
+        };
+        Self::record_attestation(e, caller, params, None, None)
+    }
 
-        // 3. Check caller is authorized verifier
-        if !Self::is_authorized_verifier(&e, &caller) {
-            e.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(AttestationError::Unauthorized);
-        }
+    /// Record an attestation for the commitment behind an NFT token id
+    ///
+    /// Resolves the commitment id through the core contract's NFT contract,
+    /// then behaves exactly like `attest`.
+    ///
+    /// # Returns
+    /// * `Err(AttestationError::UnknownToken)` if the token cannot be resolved
+    /// * Otherwise the same results as `attest`
+    pub fn attest_by_token(
+        e: Env,
+        caller: Address,
+        token_id: u32,
+        attestation_type: String,
+        data: Map<String, String>,
+        is_compliant: bool,
+    ) -> Result<(), AttestationError> {
+        let params = AttestParams {
+            commitment_id: resolve_token_commitment(&e, token_id)?,
+            attestation_type,
+            data,
+            is_compliant,
+        };
+        Self::record_attestation(e, caller, params, None, None)
+    }
 
-        // 3b. Rate limit attestations per verifier
-        let fn_symbol = Symbol::new(&e, "attest");
-        RateLimiter::check(&e, &caller, &fn_symbol);
+    /// Record an attestation that adjusts the compliance score by an exact amount
+    ///
+    /// For verifiers that compute a precise impact instead of using the
+    /// low/medium/high severity tiers of `attest`. The delta replaces the tiered
+    /// penalty and bonus, is stored in the attestation data under `score_delta`,
+    /// and a negative delta marks the attestation as non-compliant.
+    ///
+    /// # Arguments
+    /// * `score_delta` - Signed adjustment within `-MAX_SCORE_DELTA..=MAX_SCORE_DELTA`
+    ///
+    /// # Returns
+    /// * `Err(AttestationError::InvalidScoreDelta)` if the delta is out of range
+    /// * Otherwise the same results as `attest`
+    pub fn attest_with_delta(
+        e: Env,
+        caller: Address,
+        commitment_id: String,
+        attestation_type: String,
+        data: Map<String, String>,
+        score_delta: i32,
+    ) -> Result<(), AttestationError> {
+        let mut data = data;
+        data.set(
+            String::from_str(&e, "score_delta"),
+            Self::i128_to_string(&e, score_delta as i128),
+        );
+        let params = AttestParams {
+            commitment_id,
+            attestation_type,
+            data,
+            is_compliant: score_delta >= 0,
+        };
+        Self::record_attestation(e, caller, params, Some(score_delta), None)
+    }
 
-        // 4. Validate commitment_id is not empty
-        if commitment_id.len() == 0 {
-            e.storage().instance().remove(&DataKey::ReentrancyGuard);
+    /// Record a violation attestation that references off-chain evidence
+    ///
+    /// `evidence_hash` (e.g. the SHA-256 of a report) is stored next to the
+    /// attestation (see `get_attestation_evidence`) and emitted in a
+    /// `ViolationEvidence` event so a dispute resolver can fetch the
+    /// referenced document. The attestation is recorded
+    /// as a non-compliant "violation", so `data` must carry its usual fields.
+    pub fn attest_with_evidence(
+        e: Env,
+        caller: Address,
+        commitment_id: String,
+        data: Map<String, String>,
+        evidence_hash: BytesN<32>,
+    ) -> Result<(), AttestationError> {
+        let params = AttestParams {
+            commitment_id,
+            attestation_type: String::from_str(&e, "violation"),
+            data,
+            is_compliant: false,
+        };
+        Self::record_attestation(e, caller, params, None, Some(evidence_hash))
+    }
+
+    /// Shared body of `attest`, `attest_with_delta` and `attest_with_evidence`.
+    fn record_attestation(
+        e: Env,
+        caller: Address,
+        params: AttestParams,
+        score_delta: Option<i32>,
+        evidence_hash: Option<BytesN<32>>,
+    ) -> Result<(), AttestationError> {
+        let AttestParams {
+            commitment_id,
+            attestation_type,
+            data,
+            is_compliant,
+        } = params;
+
+        // 1. Reentrancy protection; the guard belongs to the outer call, so
+        // it is left in place
+        if e.storage().instance().has(&DataKey::ReentrancyGuard) {
+            return Err(AttestationError::ReentrancyDetected);
+        }
+        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
+
+        // Check if contract is paused
+        Pausable::require_not_paused(&e);
+
+        // 2. Verify caller signed the transaction
+        caller.require_auth();
+
+        // 3. Check caller is authorized verifier
+        if let Err(err) = Self::check_verifier(&e, &caller) {
+            e.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(err);
+        }
+
+        // 3b. Rate limit attestations per verifier
+        let fn_symbol = Symbol::new(&e, "attest");
+        RateLimiter::check(&e, &caller, &fn_symbol);
+
+        // 4. Validate commitment_id is not empty
+        if commitment_id.len() == 0 {
+            e.storage().instance().remove(&DataKey::ReentrancyGuard);
             return Err(AttestationError::InvalidCommitmentId);
         }
 
@@ -679,11 +1306,15 @@ impl AttestationEngineContract {
             return Err(AttestationError::CommitmentNotFound);
         }
 
-        // 6. Validate attestation type
-        if !Self::is_valid_attestation_type(&e, &attestation_type) {
-            e.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(AttestationError::InvalidAttestationType);
-        }
+        // 6. Validate attestation type; registered types supply a default
+        //    score effect when no explicit delta is given
+        let score_delta = match Self::check_attestation_type(&e, &attestation_type) {
+            Ok(effect) => score_delta.or(effect),
+            Err(err) => {
+                e.storage().instance().remove(&DataKey::ReentrancyGuard);
+                return Err(err);
+            }
+        };
 
         // 7. Validate data format for the attestation type
         if !Self::validate_attestation_data(&e, &attestation_type, &data) {
@@ -691,12 +1322,41 @@ impl AttestationEngineContract {
             return Err(AttestationError::InvalidAttestationData);
         }
 
-        // 7b. Collect attestation verification fee if configured
-        let fee_amount: i128 = e
+        // 7a. Validate explicit score delta bounds
+        if let Some(delta) = score_delta {
+            if !(-MAX_SCORE_DELTA..=MAX_SCORE_DELTA).contains(&delta) {
+                e.storage().instance().remove(&DataKey::ReentrancyGuard);
+                return Err(AttestationError::InvalidScoreDelta);
+            }
+        }
+
+        // 7b. Collect attestation verification fee if configured, up to the
+        // commitment's fee cap
+        let mut fee_amount: i128 = e
             .storage()
             .instance()
             .get(&DataKey::AttestationFeeAmount)
             .unwrap_or(0);
+        let charged_key = DataKey::CommitmentFeesCharged(commitment_id.clone());
+        let charged: i128 = e.storage().persistent().get(&charged_key).unwrap_or(0);
+        let fee_cap: i128 = e
+            .storage()
+            .instance()
+            .get(&DataKey::MaxFeesPerCommitment)
+            .unwrap_or(0);
+        if fee_amount > 0 && fee_cap > 0 {
+            let remaining = (fee_cap - charged).max(0);
+            let reject: bool = e
+                .storage()
+                .instance()
+                .get(&DataKey::RejectOverFeeCap)
+                .unwrap_or(false);
+            if remaining == 0 && reject {
+                e.storage().instance().remove(&DataKey::ReentrancyGuard);
+                return Err(AttestationError::FeeCapReached);
+            }
+            fee_amount = fee_amount.min(remaining);
+        }
         if fee_amount > 0 {
             if let Some(fee_asset) = e
                 .storage()
@@ -706,9 +1366,29 @@ impl AttestationEngineContract {
                 let contract_address = e.current_contract_address();
                 let token_client = token::Client::new(&e, &fee_asset);
                 token_client.transfer(&caller, &contract_address, &fee_amount);
+                let treasury_share = match e
+                    .storage()
+                    .instance()
+                    .get::<DataKey, (Address, u32)>(&DataKey::FeeSplit)
+                {
+                    Some((_, bps)) => fee_amount * bps as i128 / 10000,
+                    None => 0,
+                };
+                if treasury_share > 0 {
+                    let key = DataKey::TreasuryFees(fee_asset.clone());
+                    let current: i128 = e.storage().instance().get(&key).unwrap_or(0);
+                    e.storage()
+                        .instance()
+                        .set(&key, &(current + treasury_share));
+                }
                 let key = DataKey::CollectedFees(fee_asset.clone());
                 let current: i128 = e.storage().instance().get(&key).unwrap_or(0);
-                e.storage().instance().set(&key, &(current + fee_amount));
+                e.storage()
+                    .instance()
+                    .set(&key, &(current + fee_amount - treasury_share));
+                e.storage()
+                    .persistent()
+                    .set(&charged_key, &(charged + fee_amount));
             }
         }
 
@@ -736,9 +1416,26 @@ impl AttestationEngineContract {
 
         // Store updated list
         e.storage().persistent().set(&key, &attestations);
+        if let Some(hash) = evidence_hash.clone() {
+            e.storage().persistent().set(
+                &DataKey::AttestationEvidence(commitment_id.clone(), attestations.len() - 1),
+                &hash,
+            );
+        }
+
+        let verifier_index_key = DataKey::VerifierAttestations(caller.clone());
+        let mut verifier_index: Vec<(String, u32)> = e
+            .storage()
+            .persistent()
+            .get(&verifier_index_key)
+            .unwrap_or_else(|| Vec::new(&e));
+        verifier_index.push_back((commitment_id.clone(), attestations.len() - 1));
+        e.storage()
+            .persistent()
+            .set(&verifier_index_key, &verifier_index);
 
         // 10. Update health metrics
-        Self::update_health_metrics(&e, &commitment_id, &attestation);
+        Self::update_health_metrics(&e, &commitment_id, &attestation, score_delta);
 
         // 11. Increment attestation counter
         let counter_key = DataKey::AttestationCounter(commitment_id.clone());
@@ -779,16 +1476,23 @@ impl AttestationEngineContract {
         e.storage()
             .instance()
             .set(&verifier_key, &(verifier_count + 1));
+        update_verifier_leaderboard(&e, &caller, verifier_count + 1);
 
         // 12. Emit enhanced AttestationRecorded event
         e.events().publish(
             (
                 Symbol::new(&e, "AttestationRecorded"),
-                commitment_id,
+                commitment_id.clone(),
                 caller,
             ),
             (attestation_type, is_compliant, timestamp),
         );
+        if let Some(hash) = evidence_hash {
+            e.events().publish(
+                (Symbol::new(&e, "ViolationEvidence"), commitment_id),
+                (hash, timestamp),
+            );
+        }
 
         // 13. Clear reentrancy guard
         e.storage().instance().remove(&DataKey::ReentrancyGuard);
@@ -796,6 +1500,18 @@ impl AttestationEngineContract {
         Ok(())
     }
 
+    /// Get the evidence hash recorded for the attestation at `index` in a
+    /// commitment's list, if it was made with `attest_with_evidence`.
+    pub fn get_attestation_evidence(
+        e: Env,
+        commitment_id: String,
+        index: u32,
+    ) -> Option<BytesN<32>> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::AttestationEvidence(commitment_id, index))
+    }
+
     /// Get all attestations for a commitment
     pub fn get_attestations(e: Env, commitment_id: String) -> Vec<Attestation> {
         // Retrieve attestations from persistent storage using commitment_id as key
@@ -855,12 +1571,110 @@ impl AttestationEngineContract {
         }
     }
 
+    /// Get the attestations recorded by `verifier` across all commitments,
+    /// oldest first. `limit` is capped at `MAX_PAGE_SIZE`.
+    ///
+    /// References that no longer resolve to an attestation by this verifier
+    /// are skipped.
+    pub fn get_attestations_by_verifier(
+        e: Env,
+        verifier: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Attestation> {
+        let refs: Vec<(String, u32)> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::VerifierAttestations(verifier.clone()))
+            .unwrap_or_else(|| Vec::new(&e));
+
+        let end = offset
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(refs.len());
+        let mut page = Vec::new(&e);
+        for i in offset..end {
+            let (commitment_id, index) = refs.get(i).unwrap();
+            let attestation = Self::get_attestations(e.clone(), commitment_id).get(index);
+            if let Some(attestation) = attestation {
+                if attestation.verified_by == verifier {
+                    page.push_back(attestation);
+                }
+            }
+        }
+        page
+    }
+
+    /// Get a page of a commitment's attestations with `from_ts <= timestamp <= to_ts`
+    ///
+    /// `offset` and `limit` apply to the matching attestations, oldest first;
+    /// `limit` is capped at MAX_PAGE_SIZE.
+    pub fn get_attestations_in_range(
+        e: Env,
+        commitment_id: String,
+        from_ts: u64,
+        to_ts: u64,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Attestation>, AttestationError> {
+        if from_ts > to_ts {
+            return Err(AttestationError::InvalidTimeRange);
+        }
+        let limit = limit.min(MAX_PAGE_SIZE);
+        let mut page = Vec::new(&e);
+        let mut skipped: u32 = 0;
+        for attestation in Self::get_attestations(e.clone(), commitment_id).iter() {
+            if page.len() >= limit {
+                break;
+            }
+            if attestation.timestamp < from_ts || attestation.timestamp > to_ts {
+                continue;
+            }
+            if skipped < offset {
+                skipped += 1;
+                continue;
+            }
+            page.push_back(attestation);
+        }
+        Ok(page)
+    }
+
     /// Get attestation count for a commitment
     pub fn get_attestation_count(e: Env, commitment_id: String) -> u64 {
         let key = DataKey::AttestationCounter(commitment_id);
         e.storage().persistent().get(&key).unwrap_or(0)
     }
 
+    /// Preview the stored compliance score an attestation would produce
+    ///
+    /// Read-only and unauthenticated: applies the same scoring as `attest`
+    /// (including a registered type's score effect) to the commitment's
+    /// current stored score, 100 if it has none. `severity` is only used for
+    /// violations. A type `attest` would reject leaves the score unchanged.
+    pub fn preview_score(
+        e: Env,
+        commitment_id: String,
+        attestation_type: String,
+        positive: bool,
+        severity: Option<String>,
+    ) -> u32 {
+        let current = e
+            .storage()
+            .persistent()
+            .get::<_, HealthMetrics>(&DataKey::HealthMetrics(commitment_id))
+            .map_or(100, |m| m.compliance_score);
+        match Self::check_attestation_type(&e, &attestation_type) {
+            Ok(effect) => Self::next_compliance_score(
+                &e,
+                current,
+                &attestation_type,
+                positive,
+                severity,
+                effect,
+            ),
+            Err(_) => current,
+        }
+    }
+
     /// Get current health metrics for a commitment
     pub fn get_health_metrics(e: Env, commitment_id: String) -> HealthMetrics {
         let commitment_core: Address = e
@@ -875,14 +1689,79 @@ impl AttestationEngineContract {
             e.invoke_contract(&commitment_core, &Symbol::new(&e, "get_commitment"), args);
         let commitment: Commitment = commitment_val.try_into_val(&e).unwrap();
 
+        Self::build_health_metrics(&e, commitment_id, &commitment)
+    }
+
+    /// Get live health metrics plus the estimated exit payout
+    ///
+    /// The payout is derived from the core commitment on every call and is
+    /// never persisted.
+    pub fn get_health_metrics_view(e: Env, commitment_id: String) -> HealthMetricsView {
+        let commitment_core: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::CoreContract)
+            .unwrap_or_else(|| panic!("Contract not initialized"));
+
+        let mut args = Vec::new(&e);
+        args.push_back(commitment_id.clone().into_val(&e));
+        let commitment_val: Val =
+            e.invoke_contract(&commitment_core, &Symbol::new(&e, "get_commitment"), args);
+        let commitment: Commitment = commitment_val.try_into_val(&e).unwrap();
+
+        HealthMetricsView {
+            metrics: Self::build_health_metrics(&e, commitment_id, &commitment),
+            estimated_payout: Self::estimate_payout(&e, &commitment),
+        }
+    }
+
+    /// Get a commitment, its live health metrics and compliance in one call
+    ///
+    /// Reads the core commitment once, so the returned metrics always match
+    /// the returned commitment. `compliant` uses the same rule as
+    /// `verify_compliance`.
+    pub fn get_full_health(e: Env, commitment_id: String) -> Result<FullHealth, AttestationError> {
+        let commitment_core: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::CoreContract)
+            .ok_or(AttestationError::NotInitialized)?;
+
+        let mut args = Vec::new(&e);
+        args.push_back(commitment_id.clone().into_val(&e));
+        let commitment: Commitment = match e.try_invoke_contract::<Val, soroban_sdk::Error>(
+            &commitment_core,
+            &Symbol::new(&e, "get_commitment"),
+            args,
+        ) {
+            Ok(Ok(val)) => val
+                .try_into_val(&e)
+                .map_err(|_| AttestationError::CommitmentNotFound)?,
+            _ => return Err(AttestationError::CommitmentNotFound),
+        };
+
+        let metrics = Self::build_health_metrics(&e, commitment_id.clone(), &commitment);
+        let compliant =
+            Self::compliance_failure(&e, &commitment_id, &commitment, &metrics).is_none();
+
+        Ok(FullHealth {
+            commitment,
+            metrics,
+            compliant,
+        })
+    }
+
+    /// Derive health metrics from an already-fetched core commitment
+    fn build_health_metrics(
+        e: &Env,
+        commitment_id: String,
+        commitment: &Commitment,
+    ) -> HealthMetrics {
+        let e = e.clone();
         let initial_value = commitment.amount;
         let current_value = commitment.current_value;
         let drawdown_percent = if initial_value > 0 {
-            let diff = initial_value.checked_sub(current_value).unwrap_or(0);
-            diff.checked_mul(100)
-                .unwrap_or(0)
-                .checked_div(initial_value)
-                .unwrap_or(0)
+            SafeMath::loss_percent_rounded(initial_value, current_value, core_rounding_mode(&e))
         } else {
             0
         };
@@ -919,11 +1798,132 @@ impl AttestationEngineContract {
         }
     }
 
+    /// Estimate what the owner would receive by exiting a commitment now
+    fn estimate_payout(e: &Env, commitment: &Commitment) -> i128 {
+        // Exiting before expiry costs the early-exit penalty; at or after
+        // expiry the commitment settles for its full current value
+        let penalty_applies = commitment.status == String::from_str(e, "active")
+            && e.ledger().timestamp() < commitment.expires_at;
+        if penalty_applies && commitment.current_value > 0 {
+            SafeMath::apply_penalty(
+                commitment.current_value,
+                commitment.rules.early_exit_penalty,
+            )
+        } else {
+            commitment.current_value
+        }
+    }
+
+    /// Get current drawdown of a commitment in basis points (1 bps = 0.01%)
+    ///
+    /// `HealthMetrics::drawdown_percent` truncates to whole percent, so small
+    /// losses read as 0; this keeps two extra digits. Gains report 0 and losses
+    /// beyond the initial amount are capped at 10000.
+    pub fn get_drawdown_bps(e: Env, commitment_id: String) -> u32 {
+        let commitment_core: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::CoreContract)
+            .unwrap_or_else(|| panic!("Contract not initialized"));
+
+        let mut args = Vec::new(&e);
+        args.push_back(commitment_id.into_val(&e));
+        let commitment_val: Val =
+            e.invoke_contract(&commitment_core, &Symbol::new(&e, "get_commitment"), args);
+        let commitment: Commitment = commitment_val.try_into_val(&e).unwrap();
+
+        if commitment.amount <= 0 {
+            return 0;
+        }
+        SafeMath::loss_bps(commitment.amount, commitment.current_value).clamp(0, 10000) as u32
+    }
+
+    /// Get the rule a commitment is nearest to breaching: `drawdown`, `fees`,
+    /// `duration` or `score`.
+    ///
+    /// Each rule's remaining headroom is scaled to basis points of its limit
+    /// and the smallest wins (ties resolve in the order listed):
+    /// - drawdown: loss left before `max_loss_percent`
+    /// - fees: fees generated vs. the pro-rata share of `min_fee_threshold`
+    ///   expected by now
+    /// - duration: time left before expiry
+    /// - score: compliance score left above `COMPLIANT_SCORE_THRESHOLD`
+    pub fn get_binding_constraint(e: Env, commitment_id: String) -> Symbol {
+        let commitment_core: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::CoreContract)
+            .unwrap_or_else(|| panic!("Contract not initialized"));
+
+        let mut args = Vec::new(&e);
+        args.push_back(commitment_id.clone().into_val(&e));
+        let commitment_val: Val =
+            e.invoke_contract(&commitment_core, &Symbol::new(&e, "get_commitment"), args);
+        let commitment: Commitment = commitment_val.try_into_val(&e).unwrap();
+        let metrics = Self::get_health_metrics(e.clone(), commitment_id);
+
+        let max_loss_bps = commitment.rules.max_loss_percent as i128 * 100;
+        let drawdown = if max_loss_bps == 0 || commitment.amount <= 0 {
+            0
+        } else {
+            let loss_bps = SafeMath::loss_bps(commitment.amount, commitment.current_value).max(0);
+            (max_loss_bps - loss_bps).max(0) * 10000 / max_loss_bps
+        };
+
+        let now = e.ledger().timestamp();
+        let total = commitment.expires_at.saturating_sub(commitment.created_at) as i128;
+        let remaining = commitment.expires_at.saturating_sub(now) as i128;
+        let duration = if total == 0 {
+            0
+        } else {
+            remaining.min(total) * 10000 / total
+        };
+
+        let expected_fees = if total == 0 {
+            commitment.rules.min_fee_threshold
+        } else {
+            commitment.rules.min_fee_threshold * (total - remaining.min(total)) / total
+        };
+        let fees = if expected_fees <= 0 {
+            10000
+        } else {
+            (metrics.fees_generated.max(0) * 10000 / expected_fees).min(10000)
+        };
+
+        let score_headroom = metrics
+            .compliance_score
+            .saturating_sub(COMPLIANT_SCORE_THRESHOLD) as i128;
+        let score = score_headroom * 10000 / (100 - COMPLIANT_SCORE_THRESHOLD) as i128;
+
+        let mut binding = (drawdown, symbol_short!("drawdown"));
+        for candidate in [
+            (fees, symbol_short!("fees")),
+            (duration, symbol_short!("duration")),
+            (score, symbol_short!("score")),
+        ] {
+            if candidate.0 < binding.0 {
+                binding = candidate;
+            }
+        }
+        binding.1
+    }
+
     /// Verify commitment compliance
     pub fn verify_compliance(e: Env, commitment_id: String) -> bool {
+        Self::verify_compliance_with_reason(e, commitment_id).0
+    }
+
+    /// Verify commitment compliance and report why it failed
+    ///
+    /// Returns `(true, "compliant")` or `(false, reason)`, where reason is the
+    /// first failing check: `unavailable` (core commitment unreadable),
+    /// `insufficient_data` (fewer attestations than the configured minimum),
+    /// `drawdown` (loss above the commitment's limit) or `low_score`.
+    pub fn verify_compliance_with_reason(e: Env, commitment_id: String) -> (bool, Symbol) {
+        let unavailable = (false, Symbol::new(&e, "unavailable"));
         let commitment_core: Address = match e.storage().instance().get(&DataKey::CoreContract) {
             Some(addr) => addr,
-            None => return false,
+            None => return unavailable,
         };
 
         let mut args = Vec::new(&e);
@@ -934,16 +1934,42 @@ impl AttestationEngineContract {
             args,
         ) {
             Ok(Ok(val)) => val,
-            _ => return false,
+            _ => return unavailable,
         };
         let commitment: Commitment = match commitment_val.try_into_val(&e) {
             Ok(c) => c,
-            Err(_) => return false,
+            Err(_) => return unavailable,
         };
 
-        let metrics = Self::get_health_metrics(e.clone(), commitment_id);
-        let max_loss = commitment.rules.max_loss_percent as i128;
-        metrics.drawdown_percent <= max_loss && metrics.compliance_score >= 50
+        let metrics = Self::get_health_metrics(e.clone(), commitment_id.clone());
+        match Self::compliance_failure(&e, &commitment_id, &commitment, &metrics) {
+            Some(reason) => (false, reason),
+            None => (true, Symbol::new(&e, "compliant")),
+        }
+    }
+
+    /// First compliance check a commitment fails, or None if it is compliant
+    fn compliance_failure(
+        e: &Env,
+        commitment_id: &String,
+        commitment: &Commitment,
+        metrics: &HealthMetrics,
+    ) -> Option<Symbol> {
+        let min_attestations: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::MinAttestationsForCompliance)
+            .unwrap_or(0);
+        if Self::get_attestation_count(e.clone(), commitment_id.clone()) < min_attestations as u64 {
+            return Some(Symbol::new(e, "insufficient_data"));
+        }
+        if metrics.drawdown_percent > commitment.rules.max_loss_percent as i128 {
+            return Some(Symbol::new(e, "drawdown"));
+        }
+        if metrics.compliance_score < COMPLIANT_SCORE_THRESHOLD {
+            return Some(Symbol::new(e, "low_score"));
+        }
+        None
     }
 
     /// Convenience wrapper for fee_generation attestations
@@ -1078,14 +2104,23 @@ impl AttestationEngineContract {
     /// - SP-4: State consistency (read-only)
     /// - SP-3: Arithmetic safety
     pub fn calculate_compliance_score(e: Env, commitment_id: String) -> u32 {
-        // First check if we have stored metrics with a compliance score
+        let policy = read_conflict_policy(&e);
+
+        // First check if we have stored metrics with a compliance score.
+        // Stored scores accumulate every attestation, so they only apply under SumAll.
         let metrics_key = DataKey::HealthMetrics(commitment_id.clone());
-        if let Some(stored_metrics) = e
-            .storage()
-            .persistent()
-            .get::<DataKey, HealthMetrics>(&metrics_key)
-        {
-            return stored_metrics.compliance_score;
+        if policy == ConflictPolicy::SumAll {
+            if let Some(stored_metrics) = e
+                .storage()
+                .persistent()
+                .get::<DataKey, HealthMetrics>(&metrics_key)
+            {
+                return Self::apply_high_severity_cap(
+                    &e,
+                    &commitment_id,
+                    stored_metrics.compliance_score,
+                );
+            }
         }
 
         // Get commitment from core contract
@@ -1107,13 +2142,8 @@ impl AttestationEngineContract {
         // Base score: 100
         let mut score: i32 = 100;
 
-        // Count violations: -20 per violation
-        let violation_count = attestations
-            .iter()
-            .filter(|att| {
-                !att.is_compliant || att.attestation_type == String::from_str(&e, "violation")
-            })
-            .count() as i32;
+        // Count violations (reconciled per condition): -20 per violation
+        let violation_count = Self::count_violations(&e, &attestations, policy) as i32;
         score = score
             .checked_sub(violation_count.checked_mul(20).unwrap_or(0))
             .unwrap_or(0);
@@ -1124,13 +2154,11 @@ impl AttestationEngineContract {
         let max_loss_percent = commitment.rules.max_loss_percent as i128;
 
         if initial_value > 0 {
-            let drawdown_percent = {
-                let diff = initial_value.checked_sub(current_value).unwrap_or(0);
-                diff.checked_mul(100)
-                    .unwrap_or(0)
-                    .checked_div(initial_value)
-                    .unwrap_or(0)
-            };
+            let drawdown_percent = SafeMath::loss_percent_rounded(
+                initial_value,
+                current_value,
+                core_rounding_mode(&e),
+            );
 
             if drawdown_percent > max_loss_percent {
                 let over_threshold = drawdown_percent.checked_sub(max_loss_percent).unwrap_or(0);
@@ -1202,13 +2230,46 @@ impl AttestationEngineContract {
             score = 100;
         }
 
+        // High-severity violations cap the score regardless of bonuses
+        let score = Self::apply_high_severity_cap(&e, &commitment_id, score as u32);
+
         // Emit compliance score update event
         e.events().publish(
             (symbol_short!("ScoreUpd"), commitment_id),
-            (score as u32, e.ledger().timestamp()),
+            (score, e.ledger().timestamp()),
         );
 
-        score as u32
+        score
+    }
+
+    /// Cap `score` at the configured ceiling if the commitment has any
+    /// high-severity violation. No-op when no cap is configured.
+    fn apply_high_severity_cap(e: &Env, commitment_id: &String, score: u32) -> u32 {
+        let Some(cap) = e
+            .storage()
+            .instance()
+            .get::<_, u32>(&DataKey::HighSeverityCap)
+        else {
+            return score;
+        };
+        if score <= cap {
+            return score;
+        }
+
+        let violation = String::from_str(e, "violation");
+        let severity_key = String::from_str(e, "severity");
+        let high = String::from_str(e, "high");
+        let attestations = Self::get_attestations(e.clone(), commitment_id.clone());
+        let has_high_violation = attestations.iter().any(|att| {
+            att.attestation_type == violation
+                && att.data.get(severity_key.clone()) == Some(high.clone())
+        });
+
+        if has_high_violation {
+            cap
+        } else {
+            score
+        }
     }
 
     /// Get high-level protocol analytics combining commitment and attestation data.
@@ -1251,6 +2312,65 @@ impl AttestationEngineContract {
         )
     }
 
+    /// Get system-wide compliance counts and the average stored score.
+    ///
+    /// Read from a running aggregate maintained on every attestation, so the
+    /// cost does not grow with the number of commitments.
+    pub fn get_system_compliance(e: Env) -> SystemCompliance {
+        let (total, compliant, score_sum) = read_compliance_aggregate(&e);
+        let average_score = if total > 0 {
+            (score_sum / total as u64) as u32
+        } else {
+            0
+        };
+        SystemCompliance {
+            total,
+            compliant,
+            non_compliant: total.saturating_sub(compliant),
+            average_score,
+        }
+    }
+
+    /// Get the approximate median stored compliance score.
+    ///
+    /// Derived from a histogram of scores in buckets of SCORE_BUCKET_WIDTH,
+    /// so the result is the lower bound of the bucket holding the (lower)
+    /// median, e.g. 70 for a median anywhere in 70..=79; the top bucket also
+    /// holds 100. Returns 0 when no commitment has been scored.
+    pub fn get_median_compliance(e: Env) -> u32 {
+        let histogram = read_score_histogram(&e);
+        let total: u32 = histogram.iter().sum();
+        if total == 0 {
+            return 0;
+        }
+        let rank = total.div_ceil(2);
+        let mut seen = 0;
+        for (bucket, count) in histogram.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                return bucket as u32 * SCORE_BUCKET_WIDTH;
+            }
+        }
+        0
+    }
+
+    /// Get system-wide compliance with the average score weighted by each
+    /// commitment's amount, so large commitments dominate the figure.
+    ///
+    /// Counts match `get_system_compliance`. A commitment's weight is its core
+    /// amount when it was first scored; commitments the core could not report
+    /// carry no weight. Rounded down; 0 when no weight is tracked.
+    pub fn get_system_compliance_weighted(e: Env) -> SystemCompliance {
+        let mut stats = Self::get_system_compliance(e.clone());
+        let (weight_sum, weighted_score_sum) = read_weighted_compliance_aggregate(&e);
+        stats.average_score = if weight_sum > 0 {
+            (weighted_score_sum / weight_sum) as u32
+        } else {
+            0
+        };
+        stats
+    }
+
     /// Get analytics for a given verifier (attestation recorder).
     ///
     /// Returns the total number of attestations recorded by this verifier.
@@ -1259,6 +2379,17 @@ impl AttestationEngineContract {
         e.storage().instance().get(&key).unwrap_or(0)
     }
 
+    /// Get the verifiers with the most recorded attestations, highest first.
+    ///
+    /// The leaderboard is maintained incrementally on each attestation and
+    /// holds at most `MAX_LEADERBOARD_SIZE` verifiers; ties keep the verifier
+    /// that reached the count first ahead. `limit` is capped at that size.
+    pub fn get_verifier_leaderboard(e: Env, limit: u32) -> Vec<(Address, u32)> {
+        let leaderboard = read_verifier_leaderboard(&e);
+        let end = limit.min(leaderboard.len());
+        leaderboard.slice(0..end)
+    }
+
     // ========================================================================
     // Batch Operations
     // ========================================================================
@@ -1271,7 +2402,8 @@ impl AttestationEngineContract {
     /// * `mode` - BatchMode::Atomic or BatchMode::BestEffort
     ///
     /// # Returns
-    /// BatchResult with empty results and any errors
+    /// BatchResult with empty results and any errors. `stats` reports how many
+    /// entries were examined before completion or an atomic abort.
     ///
     /// # Gas Optimization
     /// - Batch read of analytics counters
@@ -1283,9 +2415,16 @@ impl AttestationEngineContract {
         params_list: Vec<AttestParams>,
         mode: BatchMode,
     ) -> BatchResultVoid {
-        // Reentrancy protection
+        // Reentrancy protection; the guard belongs to the outer call, so
+        // it is left in place
         if e.storage().instance().has(&DataKey::ReentrancyGuard) {
-            panic!("Reentrancy detected");
+            let mut errors = Vec::new(&e);
+            errors.push_back(BatchError {
+                index: 0,
+                error_code: AttestationError::ReentrancyDetected as u32,
+                context: String::from_str(&e, "reentrancy"),
+            });
+            return BatchResultVoid::failure(&e, errors).with_stats(params_list.len(), 0);
         }
         e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
 
@@ -1293,31 +2432,32 @@ impl AttestationEngineContract {
         caller.require_auth();
 
         // Check caller is authorized verifier
-        if !Self::is_authorized_verifier(&e, &caller) {
+        if let Err(err) = Self::check_verifier(&e, &caller) {
             e.storage().instance().remove(&DataKey::ReentrancyGuard);
             let mut errors = Vec::new(&e);
             errors.push_back(BatchError {
                 index: 0,
-                error_code: AttestationError::Unauthorized as u32,
+                error_code: err as u32,
                 context: String::from_str(&e, "not_authorized_verifier"),
             });
-            return BatchResultVoid::failure(&e, errors);
+            return BatchResultVoid::failure(&e, errors).with_stats(params_list.len(), 0);
         }
 
         // Validate batch size
         let batch_size = params_list.len();
         let contract_name = String::from_str(&e, "attestation_engine");
         if let Err(error_code) =
-            BatchProcessor::enforce_batch_limits(&e, batch_size, Some(contract_name))
+            BatchProcessor::enforce_batch_limits(&e, batch_size, Some(contract_name.clone()))
         {
             e.storage().instance().remove(&DataKey::ReentrancyGuard);
+            BatchProcessor::emit_batch_rejected(&e, batch_size, Some(contract_name), error_code);
             let mut errors = Vec::new(&e);
             errors.push_back(BatchError {
                 index: 0,
                 error_code,
                 context: String::from_str(&e, "batch_size_validation"),
             });
-            return BatchResultVoid::failure(&e, errors);
+            return BatchResultVoid::failure(&e, errors).with_stats(batch_size, 0);
         }
 
         let mut errors = Vec::new(&e);
@@ -1340,6 +2480,13 @@ impl AttestationEngineContract {
             (total_att, total_viol, ver_count)
         };
 
+        let verifier_index_key = DataKey::VerifierAttestations(caller.clone());
+        let mut verifier_index: Vec<(String, u32)> = e
+            .storage()
+            .persistent()
+            .get(&verifier_index_key)
+            .unwrap_or_else(|| Vec::new(&e));
+
         let timestamp = e.ledger().timestamp();
         let violation_type = String::from_str(&e, "violation");
 
@@ -1356,7 +2503,7 @@ impl AttestationEngineContract {
                         error_code: AttestationError::InvalidCommitmentId as u32,
                         context: String::from_str(&e, "empty_commitment_id"),
                     });
-                    return BatchResultVoid::failure(&e, errors);
+                    return BatchResultVoid::failure(&e, errors).with_stats(batch_size, i + 1);
                 } else {
                     errors.push_back(BatchError {
                         index: i,
@@ -1376,7 +2523,7 @@ impl AttestationEngineContract {
                         error_code: AttestationError::CommitmentNotFound as u32,
                         context: String::from_str(&e, "commitment_not_found"),
                     });
-                    return BatchResultVoid::failure(&e, errors);
+                    return BatchResultVoid::failure(&e, errors).with_stats(batch_size, i + 1);
                 } else {
                     errors.push_back(BatchError {
                         index: i,
@@ -1388,24 +2535,21 @@ impl AttestationEngineContract {
             }
 
             // Validate attestation type
-            if !Self::is_valid_attestation_type(&e, &params.attestation_type) {
-                if mode == BatchMode::Atomic {
-                    e.storage().instance().remove(&DataKey::ReentrancyGuard);
-                    errors.push_back(BatchError {
-                        index: i,
-                        error_code: AttestationError::InvalidAttestationType as u32,
-                        context: String::from_str(&e, "invalid_type"),
-                    });
-                    return BatchResultVoid::failure(&e, errors);
-                } else {
+            let score_effect = match Self::check_attestation_type(&e, &params.attestation_type) {
+                Ok(effect) => effect,
+                Err(err) => {
                     errors.push_back(BatchError {
                         index: i,
-                        error_code: AttestationError::InvalidAttestationType as u32,
+                        error_code: err as u32,
                         context: String::from_str(&e, "invalid_type"),
                     });
+                    if mode == BatchMode::Atomic {
+                        e.storage().instance().remove(&DataKey::ReentrancyGuard);
+                        return BatchResultVoid::failure(&e, errors).with_stats(batch_size, i + 1);
+                    }
                     continue;
                 }
-            }
+            };
 
             // Validate data format
             if !Self::validate_attestation_data(&e, &params.attestation_type, &params.data) {
@@ -1416,7 +2560,7 @@ impl AttestationEngineContract {
                         error_code: AttestationError::InvalidAttestationData as u32,
                         context: String::from_str(&e, "invalid_data"),
                     });
-                    return BatchResultVoid::failure(&e, errors);
+                    return BatchResultVoid::failure(&e, errors).with_stats(batch_size, i + 1);
                 } else {
                     errors.push_back(BatchError {
                         index: i,
@@ -1446,9 +2590,10 @@ impl AttestationEngineContract {
                 .unwrap_or_else(|| Vec::new(&e));
             attestations.push_back(attestation.clone());
             e.storage().persistent().set(&key, &attestations);
+            verifier_index.push_back((params.commitment_id.clone(), attestations.len() - 1));
 
             // Update health metrics
-            Self::update_health_metrics(&e, &params.commitment_id, &attestation);
+            Self::update_health_metrics(&e, &params.commitment_id, &attestation, score_effect);
 
             // Increment attestation counter
             let counter_key = DataKey::AttestationCounter(params.commitment_id.clone());
@@ -1488,6 +2633,10 @@ impl AttestationEngineContract {
             .set(&DataKey::TotalViolations, &total_violations);
         let verifier_key = DataKey::VerifierAttestationCount(caller.clone());
         e.storage().instance().set(&verifier_key, &verifier_count);
+        update_verifier_leaderboard(&e, &caller, verifier_count);
+        e.storage()
+            .persistent()
+            .set(&verifier_index_key, &verifier_index);
 
         // Clear reentrancy guard
         e.storage().instance().remove(&DataKey::ReentrancyGuard);
@@ -1548,12 +2697,162 @@ impl AttestationEngineContract {
         Ok(())
     }
 
+    // ========================================================================
+    // Scoring configuration
+    // ========================================================================
+
+    /// Set how conflicting attestations on the same condition are reconciled
+    /// when computing compliance scores. Admin only.
+    pub fn set_conflict_policy(
+        e: Env,
+        admin: Address,
+        policy: ConflictPolicy,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &admin)?;
+        e.storage()
+            .instance()
+            .set(&DataKey::ConflictPolicy, &policy);
+        e.events().publish(
+            (Symbol::new(&e, "ConflictPolicySet"), admin),
+            (policy, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Get the current conflict policy (defaults to SumAll).
+    pub fn get_conflict_policy(e: Env) -> ConflictPolicy {
+        read_conflict_policy(&e)
+    }
+
+    /// Register a custom attestation type with the score change it applies
+    /// when attested without an explicit delta. Admin only.
+    ///
+    /// `score_effect` must be within -MAX_SCORE_DELTA..=MAX_SCORE_DELTA;
+    /// built-in types cannot be re-registered. Registering again updates the
+    /// effect.
+    pub fn register_attestation_type(
+        e: Env,
+        admin: Address,
+        attestation_type: String,
+        score_effect: i32,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &admin)?;
+        if attestation_type.is_empty() || Self::is_valid_attestation_type(&e, &attestation_type) {
+            return Err(AttestationError::InvalidAttestationType);
+        }
+        if !(-MAX_SCORE_DELTA..=MAX_SCORE_DELTA).contains(&score_effect) {
+            return Err(AttestationError::InvalidScoreDelta);
+        }
+        e.storage().instance().set(
+            &DataKey::AttestationType(attestation_type.clone()),
+            &score_effect,
+        );
+        e.events().publish(
+            (
+                Symbol::new(&e, "AttestationTypeRegistered"),
+                attestation_type,
+            ),
+            (score_effect, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Get the score effect of a registered custom attestation type.
+    pub fn get_attestation_type(e: Env, attestation_type: String) -> Option<i32> {
+        e.storage()
+            .instance()
+            .get(&DataKey::AttestationType(attestation_type))
+    }
+
+    /// Enable or disable strict attestation types. Admin only.
+    ///
+    /// In strict mode `attest` rejects types that are neither built in nor
+    /// registered with `UnknownAttestationType`; with it off they are accepted
+    /// without a score effect. Until either is set such types keep failing
+    /// with `InvalidAttestationType`.
+    pub fn set_strict_attestation_types(
+        e: Env,
+        admin: Address,
+        enabled: bool,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &admin)?;
+        e.storage()
+            .instance()
+            .set(&DataKey::StrictAttestationTypes, &enabled);
+        e.events().publish(
+            (Symbol::new(&e, "StrictTypesSet"), admin),
+            (enabled, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Get the strict attestation types setting.
+    ///
+    /// `Some(true)` rejects unregistered types with `UnknownAttestationType`
+    /// and `Some(false)` accepts them. `None` means it was never set, and
+    /// unregistered types are rejected with `InvalidAttestationType`.
+    pub fn get_strict_attestation_types(e: Env) -> Option<bool> {
+        e.storage()
+            .instance()
+            .get(&DataKey::StrictAttestationTypes)
+    }
+
+    /// Cap the compliance score of any commitment with a high-severity violation
+    /// at `cap`, so serious breaches can't be offset by bonuses. Admin only.
+    pub fn set_high_severity_cap(e: Env, admin: Address, cap: u32) -> Result<(), AttestationError> {
+        require_admin(&e, &admin)?;
+        if cap > 100 {
+            return Err(AttestationError::InvalidScoreCap);
+        }
+        e.storage().instance().set(&DataKey::HighSeverityCap, &cap);
+        e.events().publish(
+            (Symbol::new(&e, "HighSeverityCapSet"), admin),
+            (cap, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Get the high-severity score cap, if configured.
+    pub fn get_high_severity_cap(e: Env) -> Option<u32> {
+        e.storage().instance().get(&DataKey::HighSeverityCap)
+    }
+
+    /// Require at least `min` attestations before a commitment can be
+    /// compliant, whatever its score. Admin only; 0 (default) disables it.
+    pub fn set_min_compliance_attestations(
+        e: Env,
+        admin: Address,
+        min: u32,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &admin)?;
+        e.storage()
+            .instance()
+            .set(&DataKey::MinAttestationsForCompliance, &min);
+        e.events().publish(
+            (Symbol::new(&e, "MinAttestationsSet"), admin),
+            (min, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Get the minimum attestation count required for compliance (0 if unset).
+    pub fn get_min_compliance_attestations(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::MinAttestationsForCompliance)
+            .unwrap_or(0)
+    }
+
     // ========================================================================
     // Fee collection (protocol revenue)
     // ========================================================================
 
     /// Set attestation verification fee: amount per attestation and token. Admin only.
     /// Set amount to 0 to disable.
+    ///
+    /// The token's `decimals()` is read once here and kept for
+    /// `get_fee_decimals`; a charged fee fails with `InvalidFeeAsset` if the
+    /// asset cannot report it.
     pub fn set_attestation_fee(
         e: Env,
         caller: Address,
@@ -1572,6 +2871,14 @@ impl AttestationEngineContract {
         if amount < 0 {
             return Err(AttestationError::InvalidFeeAmount);
         }
+        match token::Client::new(&e, &asset).try_decimals() {
+            Ok(Ok(decimals)) => e
+                .storage()
+                .instance()
+                .set(&DataKey::FeeDecimals(asset.clone()), &decimals),
+            _ if amount > 0 => return Err(AttestationError::InvalidFeeAsset),
+            _ => {}
+        }
         e.storage()
             .instance()
             .set(&DataKey::AttestationFeeAmount, &amount);
@@ -1610,30 +2917,165 @@ impl AttestationEngineContract {
         Ok(())
     }
 
-    /// Withdraw collected fees to the configured fee recipient. Admin only.
-    pub fn withdraw_fees(
+    /// Cap the attestation fees charged for any single commitment. Admin only.
+    ///
+    /// Once a commitment's charged fees reach `cap`, further attestations on it
+    /// are recorded fee-free (the fee that reaches the cap is reduced to fit),
+    /// or rejected with `FeeCapReached` when `set_reject_over_fee_cap` is on.
+    /// A cap of 0 removes the limit.
+    pub fn set_max_fees_per_commitment(
+        e: Env,
+        admin: Address,
+        cap: i128,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &admin)?;
+        if cap < 0 {
+            return Err(AttestationError::InvalidFeeAmount);
+        }
+        e.storage()
+            .instance()
+            .set(&DataKey::MaxFeesPerCommitment, &cap);
+        e.events().publish(
+            (Symbol::new(&e, "MaxFeesSet"), admin),
+            (cap, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Reject attestations on commitments that reached the fee cap instead of
+    /// recording them fee-free. Admin only.
+    pub fn set_reject_over_fee_cap(
+        e: Env,
+        admin: Address,
+        reject: bool,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &admin)?;
+        e.storage()
+            .instance()
+            .set(&DataKey::RejectOverFeeCap, &reject);
+        e.events().publish(
+            (Symbol::new(&e, "RejectOverFeeCapSet"), admin),
+            (reject, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Get the per-commitment fee cap (0 if unset).
+    pub fn get_max_fees_per_commitment(e: Env) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::MaxFeesPerCommitment)
+            .unwrap_or(0)
+    }
+
+    /// Get the attestation fees charged so far for a commitment.
+    pub fn get_commitment_fees_charged(e: Env, commitment_id: String) -> i128 {
+        e.storage()
+            .persistent()
+            .get(&DataKey::CommitmentFeesCharged(commitment_id))
+            .unwrap_or(0)
+    }
+
+    /// Route `treasury_bps` of every attestation fee to `treasury`; the rest
+    /// accrues to the collected fees pool as before. Admin only.
+    pub fn set_fee_split(
+        e: Env,
+        admin: Address,
+        treasury: Address,
+        treasury_bps: u32,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &admin)?;
+        if treasury_bps > 10000 {
+            return Err(AttestationError::InvalidFeeSplit);
+        }
+        e.storage()
+            .instance()
+            .set(&DataKey::FeeSplit, &(treasury.clone(), treasury_bps));
+        e.events().publish(
+            (Symbol::new(&e, "FeeSplitSet"), admin),
+            (treasury, treasury_bps, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Withdraw fees accrued under the fee split to the treasury. Treasury only.
+    pub fn withdraw_treasury_fees(
         e: Env,
         caller: Address,
         asset_address: Address,
         amount: i128,
     ) -> Result<(), AttestationError> {
         caller.require_auth();
-        let admin: Address = e
+        let (treasury, _): (Address, u32) = e
             .storage()
             .instance()
-            .get(&DataKey::Admin)
-            .ok_or(AttestationError::NotInitialized)?;
-        if caller != admin {
+            .get(&DataKey::FeeSplit)
+            .ok_or(AttestationError::FeeRecipientNotSet)?;
+        if caller != treasury {
             return Err(AttestationError::Unauthorized);
         }
         if amount <= 0 {
             return Err(AttestationError::InvalidFeeAmount);
         }
+        let key = DataKey::TreasuryFees(asset_address.clone());
+        let accrued: i128 = e.storage().instance().get(&key).unwrap_or(0);
+        if amount > accrued {
+            return Err(AttestationError::InsufficientFees);
+        }
+        e.storage().instance().set(&key, &(accrued - amount));
+        let contract_address = e.current_contract_address();
+        let token_client = token::Client::new(&e, &asset_address);
+        token_client.transfer(&contract_address, &treasury, &amount);
+        e.events().publish(
+            (Symbol::new(&e, "TreasuryFeesWithdrawn"), treasury),
+            (asset_address, amount, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Withdraw collected fees to the configured fee recipient. Admin only.
+    pub fn withdraw_fees(
+        e: Env,
+        caller: Address,
+        asset_address: Address,
+        amount: i128,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &caller)?;
+        if amount <= 0 {
+            return Err(AttestationError::InvalidFeeAmount);
+        }
         let recipient: Address = e
             .storage()
             .instance()
             .get(&DataKey::FeeRecipient)
             .ok_or(AttestationError::FeeRecipientNotSet)?;
+        Self::pay_out_fees(&e, caller, asset_address, amount, recipient)
+    }
+
+    /// Withdraw collected fees to an explicit recipient, e.g. a one-off treasury sweep.
+    /// Admin only; does not change the configured fee recipient.
+    pub fn withdraw_fees_to(
+        e: Env,
+        caller: Address,
+        asset_address: Address,
+        amount: i128,
+        recipient: Address,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &caller)?;
+        if amount <= 0 {
+            return Err(AttestationError::InvalidFeeAmount);
+        }
+        Self::pay_out_fees(&e, caller, asset_address, amount, recipient)
+    }
+
+    /// Deduct `amount` from collected fees and transfer it to `recipient`.
+    fn pay_out_fees(
+        e: &Env,
+        caller: Address,
+        asset_address: Address,
+        amount: i128,
+        recipient: Address,
+    ) -> Result<(), AttestationError> {
         let key = DataKey::CollectedFees(asset_address.clone());
         let collected: i128 = e.storage().instance().get(&key).unwrap_or(0);
         if amount > collected {
@@ -1641,10 +3083,10 @@ impl AttestationEngineContract {
         }
         e.storage().instance().set(&key, &(collected - amount));
         let contract_address = e.current_contract_address();
-        let token_client = token::Client::new(&e, &asset_address);
+        let token_client = token::Client::new(e, &asset_address);
         token_client.transfer(&contract_address, &recipient, &amount);
         e.events().publish(
-            (Symbol::new(&e, "FeesWithdrawn"), caller, recipient),
+            (Symbol::new(e, "FeesWithdrawn"), caller, recipient),
             (asset_address, amount, e.ledger().timestamp()),
         );
         Ok(())
@@ -1661,11 +3103,33 @@ impl AttestationEngineContract {
         (amount, asset)
     }
 
+    /// Get the decimals recorded for a fee asset when it was configured,
+    /// so fee amounts can be rendered. 0 if the asset was never configured.
+    pub fn get_fee_decimals(e: Env, asset: Address) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::FeeDecimals(asset))
+            .unwrap_or(0)
+    }
+
     /// Get fee recipient. None if not set.
     pub fn get_fee_recipient(e: Env) -> Option<Address> {
         e.storage().instance().get(&DataKey::FeeRecipient)
     }
 
+    /// Get the fee split (treasury, treasury_bps). None if not set.
+    pub fn get_fee_split(e: Env) -> Option<(Address, u32)> {
+        e.storage().instance().get(&DataKey::FeeSplit)
+    }
+
+    /// Get fees accrued to the fee-split treasury for an asset.
+    pub fn get_treasury_fees(e: Env, asset_address: Address) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::TreasuryFees(asset_address))
+            .unwrap_or(0)
+    }
+
     /// Get collected fees for an asset.
     pub fn get_collected_fees(e: Env, asset_address: Address) -> i128 {
         e.storage()
@@ -1682,6 +3146,253 @@ fn read_version(e: &Env) -> u32 {
         .unwrap_or(0)
 }
 
+fn read_compliance_aggregate(e: &Env) -> (u32, u32, u64) {
+    e.storage()
+        .instance()
+        .get(&DataKey::ComplianceAggregate)
+        .unwrap_or((0, 0, 0))
+}
+
+/// Move a commitment's stored score from `previous` (None if untracked) to
+/// `score` in the running compliance aggregate.
+fn update_compliance_aggregate(e: &Env, previous: Option<u32>, score: u32) {
+    let (mut total, mut compliant, mut score_sum) = read_compliance_aggregate(e);
+    match previous {
+        Some(old) => {
+            score_sum = score_sum.saturating_sub(old as u64);
+            if old >= COMPLIANT_SCORE_THRESHOLD {
+                compliant = compliant.saturating_sub(1);
+            }
+        }
+        None => total += 1,
+    }
+    score_sum += score as u64;
+    if score >= COMPLIANT_SCORE_THRESHOLD {
+        compliant += 1;
+    }
+    e.storage().instance().set(
+        &DataKey::ComplianceAggregate,
+        &(total, compliant, score_sum),
+    );
+}
+
+/// Count health metrics stored before the running compliance aggregates and
+/// score histogram existed into them. Commitments are listed by the core contract; those
+/// already counted (see `DataKey::ComplianceWeight`) are skipped.
+fn backfill_compliance_aggregates(e: &Env) {
+    let commitment_core: Option<Address> = e.storage().instance().get(&DataKey::CoreContract);
+    let Some(commitment_core) = commitment_core else {
+        return;
+    };
+    let args = Vec::from_array(e, [0u64.into_val(e), u64::MAX.into_val(e)]);
+    let ids = match e.try_invoke_contract::<Vec<String>, soroban_sdk::Error>(
+        &commitment_core,
+        &Symbol::new(e, "get_commitments_created_between"),
+        args,
+    ) {
+        Ok(Ok(ids)) => ids,
+        _ => return,
+    };
+    for commitment_id in ids.iter() {
+        if e.storage()
+            .persistent()
+            .has(&DataKey::ComplianceWeight(commitment_id.clone()))
+        {
+            continue;
+        }
+        let metrics: Option<HealthMetrics> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::HealthMetrics(commitment_id.clone()));
+        if let Some(metrics) = metrics {
+            update_compliance_aggregate(e, None, metrics.compliance_score);
+            update_score_histogram(e, None, metrics.compliance_score);
+            update_weighted_compliance_aggregate(e, &commitment_id, None, metrics.compliance_score);
+        }
+    }
+}
+
+fn read_score_histogram(e: &Env) -> Vec<u32> {
+    e.storage()
+        .instance()
+        .get(&DataKey::ScoreHistogram)
+        .unwrap_or_else(|| {
+            let mut histogram = Vec::new(e);
+            for _ in 0..SCORE_BUCKETS {
+                histogram.push_back(0u32);
+            }
+            histogram
+        })
+}
+
+fn score_bucket(score: u32) -> u32 {
+    (score / SCORE_BUCKET_WIDTH).min(SCORE_BUCKETS - 1)
+}
+
+/// Move a commitment's stored score from `previous` (None if untracked) to
+/// `score` in the compliance score histogram.
+fn update_score_histogram(e: &Env, previous: Option<u32>, score: u32) {
+    let mut histogram = read_score_histogram(e);
+    if let Some(old) = previous {
+        let bucket = score_bucket(old);
+        let count = histogram.get(bucket).unwrap_or(0);
+        histogram.set(bucket, count.saturating_sub(1));
+    }
+    let bucket = score_bucket(score);
+    let count = histogram.get(bucket).unwrap_or(0);
+    histogram.set(bucket, count + 1);
+    e.storage()
+        .instance()
+        .set(&DataKey::ScoreHistogram, &histogram);
+}
+
+fn read_weighted_compliance_aggregate(e: &Env) -> (i128, i128) {
+    e.storage()
+        .instance()
+        .get(&DataKey::WeightedComplianceAggregate)
+        .unwrap_or((0, 0))
+}
+
+/// Move a commitment's stored score from `previous` (None if untracked) to
+/// `score` in the amount-weighted compliance aggregate, fetching its weight
+/// from the core contract the first time it is scored.
+fn update_weighted_compliance_aggregate(
+    e: &Env,
+    commitment_id: &String,
+    previous: Option<u32>,
+    score: u32,
+) {
+    let weight_key = DataKey::ComplianceWeight(commitment_id.clone());
+    let (mut weight_sum, mut weighted_score_sum) = read_weighted_compliance_aggregate(e);
+    let weight = match previous {
+        Some(old) => {
+            let weight: i128 = e.storage().persistent().get(&weight_key).unwrap_or(0);
+            weighted_score_sum =
+                weighted_score_sum.saturating_sub(weight.saturating_mul(old as i128));
+            weight
+        }
+        None => {
+            let weight = core_commitment_amount(e, commitment_id);
+            e.storage().persistent().set(&weight_key, &weight);
+            weight_sum = weight_sum.saturating_add(weight);
+            weight
+        }
+    };
+    weighted_score_sum = weighted_score_sum.saturating_add(weight.saturating_mul(score as i128));
+    e.storage().instance().set(
+        &DataKey::WeightedComplianceAggregate,
+        &(weight_sum, weighted_score_sum),
+    );
+}
+
+/// Rounding the core contract applies to loss percentages, or Floor if it
+/// cannot be read.
+fn core_rounding_mode(e: &Env) -> RoundingMode {
+    let commitment_core: Option<Address> = e.storage().instance().get(&DataKey::CoreContract);
+    let Some(commitment_core) = commitment_core else {
+        return RoundingMode::Floor;
+    };
+    match e.try_invoke_contract::<RoundingMode, soroban_sdk::Error>(
+        &commitment_core,
+        &Symbol::new(e, "get_rounding_mode"),
+        Vec::new(e),
+    ) {
+        Ok(Ok(mode)) => mode,
+        _ => RoundingMode::Floor,
+    }
+}
+
+/// Amount of a commitment in the core contract, or 0 if it cannot be read.
+fn core_commitment_amount(e: &Env, commitment_id: &String) -> i128 {
+    let commitment_core: Option<Address> = e.storage().instance().get(&DataKey::CoreContract);
+    let Some(commitment_core) = commitment_core else {
+        return 0;
+    };
+    let args = Vec::from_array(e, [commitment_id.clone().into_val(e)]);
+    match e.try_invoke_contract::<Val, soroban_sdk::Error>(
+        &commitment_core,
+        &Symbol::new(e, "get_commitment"),
+        args,
+    ) {
+        Ok(Ok(val)) => val
+            .try_into_val(e)
+            .map(|c: Commitment| c.amount.max(0))
+            .unwrap_or(0),
+        _ => 0,
+    }
+}
+
+/// Commitment id behind an NFT token, read through the core contract's NFT
+/// contract.
+fn resolve_token_commitment(e: &Env, token_id: u32) -> Result<String, AttestationError> {
+    let commitment_core: Address = e
+        .storage()
+        .instance()
+        .get(&DataKey::CoreContract)
+        .ok_or(AttestationError::NotInitialized)?;
+    let nft_contract = match e.try_invoke_contract::<Address, soroban_sdk::Error>(
+        &commitment_core,
+        &Symbol::new(e, "get_nft_contract"),
+        Vec::new(e),
+    ) {
+        Ok(Ok(nft_contract)) => nft_contract,
+        _ => return Err(AttestationError::UnknownToken),
+    };
+    let args = Vec::from_array(e, [token_id.into_val(e)]);
+    match e.try_invoke_contract::<String, soroban_sdk::Error>(
+        &nft_contract,
+        &Symbol::new(e, "get_commitment_id"),
+        args,
+    ) {
+        Ok(Ok(commitment_id)) => Ok(commitment_id),
+        _ => Err(AttestationError::UnknownToken),
+    }
+}
+
+fn read_verifier_leaderboard(e: &Env) -> Vec<(Address, u32)> {
+    e.storage()
+        .instance()
+        .get(&DataKey::VerifierLeaderboard)
+        .unwrap_or_else(|| Vec::new(e))
+}
+
+/// Record `verifier`'s new attestation `count` on the bounded leaderboard.
+///
+/// Counts only grow, so the verifier is removed and re-inserted after every
+/// entry with an equal or higher count; the lowest entry falls off when the
+/// board is over capacity.
+fn update_verifier_leaderboard(e: &Env, verifier: &Address, count: u64) {
+    let count = count.min(u32::MAX as u64) as u32;
+    let mut leaderboard = read_verifier_leaderboard(e);
+    if let Some(pos) = leaderboard.iter().position(|(addr, _)| addr == *verifier) {
+        leaderboard.remove(pos as u32);
+    } else if leaderboard.len() >= MAX_LEADERBOARD_SIZE {
+        let (_, lowest) = leaderboard.last().unwrap();
+        if count <= lowest {
+            return;
+        }
+    }
+
+    let pos = leaderboard
+        .iter()
+        .position(|(_, existing)| existing < count)
+        .map_or(leaderboard.len(), |pos| pos as u32);
+    leaderboard.insert(pos, (verifier.clone(), count));
+    if leaderboard.len() > MAX_LEADERBOARD_SIZE {
+        leaderboard.pop_back();
+    }
+    e.storage()
+        .instance()
+        .set(&DataKey::VerifierLeaderboard, &leaderboard);
+}
+
+fn read_conflict_policy(e: &Env) -> ConflictPolicy {
+    e.storage()
+        .instance()
+        .get(&DataKey::ConflictPolicy)
+        .unwrap_or(ConflictPolicy::SumAll)
+}
+
 fn require_admin(e: &Env, caller: &Address) -> Result<(), AttestationError> {
     caller.require_auth();
     let admin: Address = e
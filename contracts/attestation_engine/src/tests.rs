@@ -1,7 +1,112 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, Map, String};
+use shared_utils::{BatchMode, BatchStats};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Events, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, BytesN, Env, IntoVal, Map, String, Symbol,
+};
+
+#[contract]
+pub struct MockCoreContract;
+
+#[contracttype]
+#[derive(Clone)]
+enum MockDataKey {
+    Commitment(String),
+    CommitmentIds,
+    RoundingMode,
+}
+
+#[contractimpl]
+impl MockCoreContract {
+    pub fn get_commitment(e: Env, commitment_id: String) -> Commitment {
+        e.storage()
+            .instance()
+            .get::<_, Commitment>(&MockDataKey::Commitment(commitment_id))
+            .unwrap_or_else(|| panic!("commitment not found"))
+    }
+
+    pub fn set_commitment(e: Env, commitment_id: String, commitment: Commitment) {
+        let key = MockDataKey::Commitment(commitment_id.clone());
+        if !e.storage().instance().has(&key) {
+            let mut ids = Self::get_commitments_created_between(e.clone(), 0, u64::MAX);
+            ids.push_back(commitment_id);
+            e.storage()
+                .instance()
+                .set(&MockDataKey::CommitmentIds, &ids);
+        }
+        e.storage().instance().set(&key, &commitment);
+    }
+
+    pub fn set_rounding_mode(e: Env, mode: RoundingMode) {
+        e.storage()
+            .instance()
+            .set(&MockDataKey::RoundingMode, &mode);
+    }
+
+    pub fn get_rounding_mode(e: Env) -> RoundingMode {
+        e.storage()
+            .instance()
+            .get(&MockDataKey::RoundingMode)
+            .unwrap_or(RoundingMode::Floor)
+    }
+
+    pub fn get_commitments_created_between(e: Env, _from_ts: u64, _to_ts: u64) -> Vec<String> {
+        e.storage()
+            .instance()
+            .get(&MockDataKey::CommitmentIds)
+            .unwrap_or_else(|| Vec::new(&e))
+    }
+}
+
+/// Register the engine wired to a mock core contract.
+/// Returns (engine client, admin, core contract id).
+fn setup_with_mock_core(e: &Env) -> (AttestationEngineContractClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let core_id = e.register_contract(None, MockCoreContract);
+    let contract_id = e.register_contract(None, AttestationEngineContract);
+    let client = AttestationEngineContractClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin, &core_id);
+    (client, admin, core_id)
+}
+
+/// Store an active commitment in the mock core contract.
+fn store_core_commitment(e: &Env, core_id: &Address, commitment_id: &str, amount: i128) {
+    let id = String::from_str(e, commitment_id);
+    let commitment = Commitment {
+        commitment_id: id.clone(),
+        owner: Address::generate(e),
+        nft_token_id: 1,
+        rules: CommitmentRules {
+            duration_days: 30,
+            max_loss_percent: 10,
+            commitment_type: String::from_str(e, "balanced"),
+            early_exit_penalty: 5,
+            min_fee_threshold: 100,
+            grace_period_days: 0,
+        },
+        amount,
+        asset_address: Address::generate(e),
+        created_at: e.ledger().timestamp(),
+        expires_at: e.ledger().timestamp() + 30 * 86400,
+        current_value: amount,
+        status: String::from_str(e, "active"),
+    };
+    MockCoreContractClient::new(e, core_id).set_commitment(&id, &commitment);
+}
+
+fn health_check_params(e: &Env, commitment_id: &str) -> AttestParams {
+    AttestParams {
+        commitment_id: String::from_str(e, commitment_id),
+        attestation_type: String::from_str(e, "health_check"),
+        data: Map::new(e),
+        is_compliant: true,
+    }
+}
 
 #[test]
 fn test_initialize_and_getters() {
@@ -78,6 +183,17 @@ fn test_get_admin_not_initialized_returns_error() {
     assert_eq!(result, Err(AttestationError::NotInitialized));
 }
 
+#[test]
+fn test_is_initialized_reflects_initialize() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, AttestationEngineContract);
+    let client = AttestationEngineContractClient::new(&e, &contract_id);
+
+    assert!(!client.is_initialized());
+    client.initialize(&Address::generate(&e), &Address::generate(&e));
+    assert!(client.is_initialized());
+}
+
 #[test]
 fn test_get_core_contract_not_initialized_returns_error() {
     let e = Env::default();
@@ -149,3 +265,1411 @@ fn test_fee_queries_not_initialized_return_defaults() {
     assert_eq!(collected_fees, 0);
 }
 
+#[test]
+fn test_batch_attest_best_effort_reports_stats() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    store_core_commitment(&e, &core_id, "c_2", 1000);
+
+    let params = vec![
+        &e,
+        health_check_params(&e, "c_1"),
+        health_check_params(&e, ""),
+        health_check_params(&e, "c_2"),
+    ];
+    let result = client.batch_attest(&admin, &params, &BatchMode::BestEffort);
+
+    assert!(!result.success);
+    assert_eq!(result.success_count, 2);
+    assert_eq!(result.errors.len(), 1);
+    assert_eq!(result.stats, BatchStats::new(3, 3));
+}
+
+#[test]
+fn test_batch_attest_atomic_reports_skipped() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+
+    let params = vec![
+        &e,
+        health_check_params(&e, "c_1"),
+        health_check_params(&e, "missing"),
+        health_check_params(&e, "c_1"),
+        health_check_params(&e, "c_1"),
+    ];
+    let result = client.batch_attest(&admin, &params, &BatchMode::Atomic);
+
+    assert!(!result.success);
+    assert_eq!(result.errors.get(0).unwrap().index, 1);
+    assert_eq!(result.stats.total, 4);
+    assert_eq!(result.stats.processed, 2);
+    assert_eq!(result.stats.skipped, 2);
+}
+
+/// Attest a condition-tagged attestation as the admin.
+fn attest_condition(
+    e: &Env,
+    client: &AttestationEngineContractClient,
+    admin: &Address,
+    commitment_id: &str,
+    condition: &str,
+    is_compliant: bool,
+) {
+    let mut data = Map::new(e);
+    data.set(
+        String::from_str(e, "condition"),
+        String::from_str(e, condition),
+    );
+    let attestation_type = if is_compliant {
+        String::from_str(e, "health_check")
+    } else {
+        data.set(
+            String::from_str(e, "violation_type"),
+            String::from_str(e, condition),
+        );
+        data.set(
+            String::from_str(e, "severity"),
+            String::from_str(e, "medium"),
+        );
+        String::from_str(e, "violation")
+    };
+    client.attest(
+        admin,
+        &String::from_str(e, commitment_id),
+        &attestation_type,
+        &data,
+        &is_compliant,
+    );
+}
+
+/// Record conflicting attestations: "max_loss" has 2 compliant vs 1 violation,
+/// "liquidity" has 1 compliant vs 1 violation.
+fn setup_conflicting_attestations(
+    e: &Env,
+) -> (AttestationEngineContractClient<'_>, Address, String) {
+    let (client, admin, core_id) = setup_with_mock_core(e);
+    store_core_commitment(e, &core_id, "c_1", 1000);
+
+    attest_condition(e, &client, &admin, "c_1", "max_loss", true);
+    attest_condition(e, &client, &admin, "c_1", "max_loss", true);
+    attest_condition(e, &client, &admin, "c_1", "max_loss", false);
+    attest_condition(e, &client, &admin, "c_1", "liquidity", true);
+    attest_condition(e, &client, &admin, "c_1", "liquidity", false);
+
+    (client, admin, String::from_str(e, "c_1"))
+}
+
+#[test]
+fn test_conflict_policy_sum_all_is_default() {
+    let e = Env::default();
+    let (client, _admin, commitment_id) = setup_conflicting_attestations(&e);
+
+    assert_eq!(client.get_conflict_policy(), ConflictPolicy::SumAll);
+    // 100 -> 100 -> 80 -> 81 -> 61 (each medium violation costs 20)
+    assert_eq!(client.calculate_compliance_score(&commitment_id), 61);
+}
+
+#[test]
+fn test_conflict_policy_majority_wins() {
+    let e = Env::default();
+    let (client, admin, commitment_id) = setup_conflicting_attestations(&e);
+
+    client.set_conflict_policy(&admin, &ConflictPolicy::MajorityWins);
+    assert_eq!(client.get_conflict_policy(), ConflictPolicy::MajorityWins);
+    // max_loss: compliant majority; liquidity: tie resolves to violation.
+    // 100 - 20 (one violated condition) + 10 (duration on track)
+    assert_eq!(client.calculate_compliance_score(&commitment_id), 90);
+}
+
+#[test]
+fn test_conflict_policy_violation_wins() {
+    let e = Env::default();
+    let (client, admin, commitment_id) = setup_conflicting_attestations(&e);
+
+    client.set_conflict_policy(&admin, &ConflictPolicy::ViolationWins);
+    // Both conditions violated: 100 - 40 + 10
+    assert_eq!(client.calculate_compliance_score(&commitment_id), 70);
+}
+
+#[test]
+fn test_set_conflict_policy_non_admin_fails() {
+    let e = Env::default();
+    let (client, _admin, _core_id) = setup_with_mock_core(&e);
+    let other = Address::generate(&e);
+
+    let result = client.try_set_conflict_policy(&other, &ConflictPolicy::ViolationWins);
+    assert_eq!(result, Err(Ok(AttestationError::Unauthorized)));
+    assert_eq!(client.get_conflict_policy(), ConflictPolicy::SumAll);
+}
+
+/// Fund the engine with `amount` of a fresh token and record it as collected fees.
+fn seed_collected_fees(e: &Env, contract_id: &Address, amount: i128) -> Address {
+    let token_admin = Address::generate(e);
+    let asset = e.register_stellar_asset_contract_v2(token_admin).address();
+    StellarAssetClient::new(e, &asset).mint(contract_id, &amount);
+    e.as_contract(contract_id, || {
+        e.storage()
+            .instance()
+            .set(&DataKey::CollectedFees(asset.clone()), &amount);
+    });
+    asset
+}
+
+#[test]
+fn test_withdraw_fees_to_recipient() {
+    let e = Env::default();
+    let (client, admin, _core_id) = setup_with_mock_core(&e);
+    let asset = seed_collected_fees(&e, &client.address, 1000);
+    let treasury = Address::generate(&e);
+
+    client.withdraw_fees_to(&admin, &asset, &400, &treasury);
+
+    assert_eq!(TokenClient::new(&e, &asset).balance(&treasury), 400);
+    assert_eq!(client.get_collected_fees(&asset), 600);
+    // The configured fee recipient is left untouched
+    assert!(client.get_fee_recipient().is_none());
+}
+
+#[test]
+fn test_withdraw_fees_to_over_withdrawal_fails() {
+    let e = Env::default();
+    let (client, admin, _core_id) = setup_with_mock_core(&e);
+    let asset = seed_collected_fees(&e, &client.address, 100);
+    let treasury = Address::generate(&e);
+
+    let result = client.try_withdraw_fees_to(&admin, &asset, &101, &treasury);
+    assert_eq!(result, Err(Ok(AttestationError::InsufficientFees)));
+    assert_eq!(client.get_collected_fees(&asset), 100);
+}
+
+#[test]
+fn test_withdraw_fees_to_non_admin_fails() {
+    let e = Env::default();
+    let (client, _admin, _core_id) = setup_with_mock_core(&e);
+    let asset = seed_collected_fees(&e, &client.address, 100);
+    let other = Address::generate(&e);
+
+    let result = client.try_withdraw_fees_to(&other, &asset, &50, &other);
+    assert_eq!(result, Err(Ok(AttestationError::Unauthorized)));
+}
+
+#[test]
+fn test_fee_split_routes_share_to_treasury() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    let asset = e
+        .register_stellar_asset_contract_v2(Address::generate(&e))
+        .address();
+    StellarAssetClient::new(&e, &asset).mint(&admin, &1000);
+    let treasury = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    client.set_attestation_fee(&admin, &100, &asset);
+    client.set_fee_recipient(&admin, &recipient);
+    client.set_fee_split(&admin, &treasury, &3000);
+    assert_eq!(client.get_fee_split(), Some((treasury.clone(), 3000)));
+
+    attest_times(&e, &client, &admin, 2);
+
+    assert_eq!(client.get_treasury_fees(&asset), 60);
+    assert_eq!(client.get_collected_fees(&asset), 140);
+
+    // Each pool is withdrawn on its own without touching the other
+    client.withdraw_treasury_fees(&treasury, &asset, &60);
+    assert_eq!(client.get_treasury_fees(&asset), 0);
+    assert_eq!(client.get_collected_fees(&asset), 140);
+    client.withdraw_fees(&admin, &asset, &140);
+    assert_eq!(client.get_collected_fees(&asset), 0);
+
+    let token = TokenClient::new(&e, &asset);
+    assert_eq!(token.balance(&treasury), 60);
+    assert_eq!(token.balance(&recipient), 140);
+
+    let result = client.try_withdraw_treasury_fees(&treasury, &asset, &1);
+    assert_eq!(result, Err(Ok(AttestationError::InsufficientFees)));
+    let result = client.try_withdraw_treasury_fees(&recipient, &asset, &1);
+    assert_eq!(result, Err(Ok(AttestationError::Unauthorized)));
+}
+
+/// Engine with a 100-unit attestation fee paid by the admin (funded with
+/// 1000) and the commitment "c_1". Returns (client, admin, fee asset).
+fn setup_fee_cap_test(e: &Env) -> (AttestationEngineContractClient<'_>, Address, Address) {
+    let (client, admin, core_id) = setup_with_mock_core(e);
+    store_core_commitment(e, &core_id, "c_1", 1000);
+    let asset = e
+        .register_stellar_asset_contract_v2(Address::generate(e))
+        .address();
+    StellarAssetClient::new(e, &asset).mint(&admin, &1000);
+    client.set_attestation_fee(&admin, &100, &asset);
+    (client, admin, asset)
+}
+
+#[test]
+fn test_fee_cap_stops_fees_but_records_attestations() {
+    let e = Env::default();
+    let (client, admin, asset) = setup_fee_cap_test(&e);
+    client.set_max_fees_per_commitment(&admin, &250);
+    let id = String::from_str(&e, "c_1");
+
+    // 100 + 100 + 50 (clipped to the cap), then fee-free
+    attest_times(&e, &client, &admin, 5);
+
+    assert_eq!(client.get_commitment_fees_charged(&id), 250);
+    assert_eq!(client.get_collected_fees(&asset), 250);
+    assert_eq!(TokenClient::new(&e, &asset).balance(&admin), 750);
+    assert_eq!(client.get_attestations(&id).len(), 5);
+}
+
+#[test]
+fn test_fee_cap_rejects_when_flag_set() {
+    let e = Env::default();
+    let (client, admin, asset) = setup_fee_cap_test(&e);
+    client.set_max_fees_per_commitment(&admin, &200);
+    client.set_reject_over_fee_cap(&admin, &true);
+    attest_times(&e, &client, &admin, 2);
+
+    let params = health_check_params(&e, "c_1");
+    let result = client.try_attest(
+        &admin,
+        &params.commitment_id,
+        &params.attestation_type,
+        &params.data,
+        &params.is_compliant,
+    );
+    assert_eq!(result, Err(Ok(AttestationError::FeeCapReached)));
+    assert_eq!(client.get_attestations(&params.commitment_id).len(), 2);
+    assert_eq!(client.get_collected_fees(&asset), 200);
+    assert!(!client.is_locked());
+}
+
+#[test]
+fn test_set_attestation_fee_records_decimals() {
+    let e = Env::default();
+    let (client, admin, asset) = setup_fee_cap_test(&e);
+    // Stellar asset contracts report 7 decimals
+    assert_eq!(client.get_fee_decimals(&asset), 7);
+
+    // A fee in an asset that is not a token is rejected; disabling is not
+    let not_token = Address::generate(&e);
+    let result = client.try_set_attestation_fee(&admin, &100, &not_token);
+    assert_eq!(result, Err(Ok(AttestationError::InvalidFeeAsset)));
+    client.set_attestation_fee(&admin, &0, &not_token);
+    assert_eq!(client.get_fee_decimals(&not_token), 0);
+}
+
+#[test]
+fn test_fee_split_rejects_bps_over_10000() {
+    let e = Env::default();
+    let (client, admin, _core_id) = setup_with_mock_core(&e);
+    let treasury = Address::generate(&e);
+
+    let result = client.try_set_fee_split(&admin, &treasury, &10001);
+    assert_eq!(result, Err(Ok(AttestationError::InvalidFeeSplit)));
+    assert!(client.get_fee_split().is_none());
+
+    client.set_fee_split(&admin, &treasury, &10000);
+    assert_eq!(client.get_fee_split(), Some((treasury, 10000)));
+}
+
+#[test]
+fn test_force_unlock_clears_stuck_guard() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    assert!(!client.is_locked());
+
+    e.as_contract(&client.address, || {
+        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
+    });
+    assert!(client.is_locked());
+
+    client.force_unlock(&admin);
+    assert!(!client.is_locked());
+    let params = health_check_params(&e, "c_1");
+    client.attest(
+        &admin,
+        &params.commitment_id,
+        &params.attestation_type,
+        &params.data,
+        &params.is_compliant,
+    );
+}
+
+#[test]
+fn test_force_unlock_non_admin_fails() {
+    let e = Env::default();
+    let (client, _admin, _core_id) = setup_with_mock_core(&e);
+    e.as_contract(&client.address, || {
+        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
+    });
+
+    let result = client.try_force_unlock(&Address::generate(&e));
+    assert_eq!(result, Err(Ok(AttestationError::Unauthorized)));
+    assert!(client.is_locked());
+}
+
+#[test]
+fn test_reentrant_attest_returns_error() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    // Simulate a call arriving while another attestation holds the guard
+    e.as_contract(&client.address, || {
+        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
+    });
+
+    let params = health_check_params(&e, "c_1");
+    let result = client.try_attest(
+        &admin,
+        &params.commitment_id,
+        &params.attestation_type,
+        &params.data,
+        &params.is_compliant,
+    );
+    assert_eq!(result, Err(Ok(AttestationError::ReentrancyDetected)));
+    // The outer call's guard is left in place and nothing was recorded
+    assert!(client.is_locked());
+    assert_eq!(client.get_attestations(&params.commitment_id).len(), 0);
+
+    let result = client.batch_attest(&admin, &vec![&e, params], &BatchMode::BestEffort);
+    assert!(!result.success);
+    assert_eq!(
+        result.errors.get(0).unwrap().error_code,
+        AttestationError::ReentrancyDetected as u32
+    );
+    assert!(client.is_locked());
+}
+
+/// Attest a health check followed by a violation of the given severity.
+fn attest_with_violation(
+    e: &Env,
+    client: &AttestationEngineContractClient,
+    admin: &Address,
+    commitment_id: &str,
+    severity: &str,
+) {
+    let params = health_check_params(e, commitment_id);
+    client.attest(
+        admin,
+        &params.commitment_id,
+        &params.attestation_type,
+        &params.data,
+        &params.is_compliant,
+    );
+
+    let mut data = Map::new(e);
+    data.set(
+        String::from_str(e, "violation_type"),
+        String::from_str(e, "max_loss"),
+    );
+    data.set(
+        String::from_str(e, "severity"),
+        String::from_str(e, severity),
+    );
+    client.attest(
+        admin,
+        &String::from_str(e, commitment_id),
+        &String::from_str(e, "violation"),
+        &data,
+        &false,
+    );
+}
+
+#[test]
+fn test_high_severity_cap_limits_score() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    attest_with_violation(&e, &client, &admin, "c_1", "high");
+    let commitment_id = String::from_str(&e, "c_1");
+
+    assert_eq!(client.get_high_severity_cap(), None);
+    assert_eq!(client.calculate_compliance_score(&commitment_id), 70);
+
+    client.set_high_severity_cap(&admin, &50);
+    assert_eq!(client.get_high_severity_cap(), Some(50));
+    assert_eq!(client.calculate_compliance_score(&commitment_id), 50);
+}
+
+#[test]
+fn test_high_severity_cap_ignores_low_violations() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    attest_with_violation(&e, &client, &admin, "c_1", "low");
+
+    client.set_high_severity_cap(&admin, &50);
+    assert_eq!(
+        client.calculate_compliance_score(&String::from_str(&e, "c_1")),
+        90
+    );
+}
+
+#[test]
+fn test_high_severity_cap_applies_to_reconciled_score() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    attest_with_violation(&e, &client, &admin, "c_1", "high");
+
+    // MajorityWins recomputes: 100 - 20 + 10 = 90 before the cap
+    client.set_conflict_policy(&admin, &ConflictPolicy::MajorityWins);
+    client.set_high_severity_cap(&admin, &50);
+    assert_eq!(
+        client.calculate_compliance_score(&String::from_str(&e, "c_1")),
+        50
+    );
+}
+
+#[test]
+fn test_set_high_severity_cap_rejects_invalid_cap() {
+    let e = Env::default();
+    let (client, admin, _core_id) = setup_with_mock_core(&e);
+
+    let result = client.try_set_high_severity_cap(&admin, &101);
+    assert_eq!(result, Err(Ok(AttestationError::InvalidScoreCap)));
+}
+
+#[test]
+fn test_batch_attest_oversized_emits_batch_rejected() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+
+    let mut params = Vec::new(&e);
+    for _ in 0..51 {
+        params.push_back(health_check_params(&e, "c_1"));
+    }
+    let result = client.batch_attest(&admin, &params, &BatchMode::BestEffort);
+
+    assert!(!result.success);
+    assert_eq!(result.errors.get(0).unwrap().error_code, 2);
+    assert_eq!(
+        client.get_attestation_count(&String::from_str(&e, "c_1")),
+        0
+    );
+
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(topics, (Symbol::new(&e, "BatchRejected"),).into_val(&e));
+    let (size, limit, code): (u32, u32, u32) = data.into_val(&e);
+    assert_eq!((size, limit, code), (51, 50, 2));
+}
+
+#[test]
+fn test_commitment_report_matches_individual_queries() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    let commitment_id = String::from_str(&e, "c_1");
+
+    for _ in 0..11 {
+        let params = health_check_params(&e, "c_1");
+        client.attest(
+            &admin,
+            &params.commitment_id,
+            &params.attestation_type,
+            &params.data,
+            &params.is_compliant,
+        );
+    }
+    attest_with_violation(&e, &client, &admin, "c_1", "high");
+
+    let report = client.get_commitment_report(&commitment_id);
+    assert_eq!(report.metrics, client.get_health_metrics(&commitment_id));
+
+    // 13 attestations: the report embeds the last MAX_REPORT_ATTESTATIONS
+    let page = client.get_attestations_page(&commitment_id, &3, &MAX_REPORT_ATTESTATIONS);
+    assert_eq!(report.recent_attestations.len(), MAX_REPORT_ATTESTATIONS);
+    assert_eq!(report.recent_attestations, page.attestations);
+
+    assert_eq!(report.score_history.len(), 13);
+    assert_eq!(report.score_history.last().unwrap().score, 70);
+}
+
+#[test]
+fn test_score_history_is_capped() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+
+    for _ in 0..MAX_SCORE_HISTORY + 2 {
+        let params = health_check_params(&e, "c_1");
+        client.attest(
+            &admin,
+            &params.commitment_id,
+            &params.attestation_type,
+            &params.data,
+            &params.is_compliant,
+        );
+    }
+
+    let history = client.get_score_history(&String::from_str(&e, "c_1"));
+    assert_eq!(history.len(), MAX_SCORE_HISTORY);
+}
+
+#[test]
+fn test_drawdown_bps_resolves_sub_percent_loss() {
+    let e = Env::default();
+    let (client, _admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+
+    // 0.5% drawdown: 1000 -> 995
+    let id = String::from_str(&e, "c_1");
+    let core = MockCoreContractClient::new(&e, &core_id);
+    let mut commitment = core.get_commitment(&id);
+    commitment.current_value = 995;
+    core.set_commitment(&id, &commitment);
+
+    assert_eq!(client.get_health_metrics(&id).drawdown_percent, 0);
+    assert_eq!(client.get_drawdown_bps(&id), 50);
+}
+
+#[test]
+fn test_drawdown_bps_reports_zero_on_gain() {
+    let e = Env::default();
+    let (client, _admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+
+    let id = String::from_str(&e, "c_1");
+    let core = MockCoreContractClient::new(&e, &core_id);
+    let mut commitment = core.get_commitment(&id);
+    commitment.current_value = 1200;
+    core.set_commitment(&id, &commitment);
+
+    assert_eq!(client.get_drawdown_bps(&id), 0);
+}
+
+#[test]
+fn test_attest_with_delta_moves_score() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    let id = String::from_str(&e, "c_1");
+
+    // Tiered high-severity violation: 100 -> 70
+    attest_with_violation(&e, &client, &admin, "c_1", "high");
+    assert_eq!(client.calculate_compliance_score(&id), 70);
+
+    client.attest_with_delta(
+        &admin,
+        &id,
+        &String::from_str(&e, "health_check"),
+        &Map::new(&e),
+        &10,
+    );
+    assert_eq!(client.calculate_compliance_score(&id), 80);
+
+    let mut data = Map::new(&e);
+    data.set(
+        String::from_str(&e, "violation_type"),
+        String::from_str(&e, "max_loss"),
+    );
+    // The delta replaces the tiered penalty (low would be -10)
+    data.set(
+        String::from_str(&e, "severity"),
+        String::from_str(&e, "low"),
+    );
+    client.attest_with_delta(&admin, &id, &String::from_str(&e, "violation"), &data, &-30);
+    assert_eq!(client.calculate_compliance_score(&id), 50);
+
+    let last = client.get_attestations(&id).last().unwrap();
+    assert!(!last.is_compliant);
+    assert_eq!(
+        last.data.get(String::from_str(&e, "score_delta")),
+        Some(String::from_str(&e, "-30"))
+    );
+}
+
+#[test]
+fn test_attest_with_delta_out_of_range_rejected() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    let id = String::from_str(&e, "c_1");
+    let health_check = String::from_str(&e, "health_check");
+
+    for delta in [MAX_SCORE_DELTA + 1, -MAX_SCORE_DELTA - 1] {
+        let result =
+            client.try_attest_with_delta(&admin, &id, &health_check, &Map::new(&e), &delta);
+        assert_eq!(result, Err(Ok(AttestationError::InvalidScoreDelta)));
+    }
+    assert_eq!(client.get_attestation_count(&id), 0);
+    assert!(!client.is_locked());
+}
+
+fn violation_data(e: &Env, severity: &str) -> Map<String, String> {
+    let mut data = Map::new(e);
+    data.set(
+        String::from_str(e, "violation_type"),
+        String::from_str(e, "max_loss"),
+    );
+    data.set(
+        String::from_str(e, "severity"),
+        String::from_str(e, severity),
+    );
+    data
+}
+
+#[test]
+fn test_attest_with_evidence_persists_hash() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    let id = String::from_str(&e, "c_1");
+    let evidence = BytesN::from_array(&e, &[7u8; 32]);
+
+    client.attest_with_evidence(&admin, &id, &violation_data(&e, "medium"), &evidence);
+
+    let stored = client.get_attestations(&id).last().unwrap();
+    assert_eq!(stored.attestation_type, String::from_str(&e, "violation"));
+    assert!(!stored.is_compliant);
+    let index = client.get_attestations(&id).len() - 1;
+    assert_eq!(
+        client.get_attestation_evidence(&id, &index),
+        Some(evidence.clone())
+    );
+
+    let event = e.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        vec![
+            &e,
+            Symbol::new(&e, "ViolationEvidence").into_val(&e),
+            id.into_val(&e)
+        ]
+    );
+    let (hash, _ts): (BytesN<32>, u64) = event.2.into_val(&e);
+    assert_eq!(hash, evidence);
+}
+
+#[test]
+fn test_attest_without_evidence_leaves_hash_empty() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    let id = String::from_str(&e, "c_1");
+
+    client.attest(
+        &admin,
+        &id,
+        &String::from_str(&e, "violation"),
+        &violation_data(&e, "low"),
+        &false,
+    );
+
+    let index = client.get_attestations(&id).len() - 1;
+    assert_eq!(client.get_attestation_evidence(&id, &index), None);
+}
+
+#[test]
+fn test_system_compliance_tracks_violations() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    assert_eq!(
+        client.get_system_compliance(),
+        SystemCompliance {
+            total: 0,
+            compliant: 0,
+            non_compliant: 0,
+            average_score: 0,
+        }
+    );
+
+    for id in ["c_1", "c_2", "c_3"] {
+        store_core_commitment(&e, &core_id, id, 1000);
+        let params = health_check_params(&e, id);
+        client.attest(
+            &admin,
+            &params.commitment_id,
+            &params.attestation_type,
+            &params.data,
+            &params.is_compliant,
+        );
+    }
+    let stats = client.get_system_compliance();
+    assert_eq!((stats.total, stats.compliant), (3, 3));
+    assert_eq!(stats.average_score, 100);
+
+    // c_1: 100 -> 70, then 71 -> 41 (below the compliance threshold)
+    attest_with_violation(&e, &client, &admin, "c_1", "high");
+    let stats = client.get_system_compliance();
+    assert_eq!((stats.compliant, stats.non_compliant), (3, 0));
+    attest_with_violation(&e, &client, &admin, "c_1", "high");
+
+    let stats = client.get_system_compliance();
+    assert_eq!(stats.total, 3);
+    assert_eq!(stats.compliant, 2);
+    assert_eq!(stats.non_compliant, 1);
+    assert_eq!(stats.average_score, (41 + 100 + 100) / 3);
+}
+
+#[test]
+fn test_migrate_backfills_compliance_aggregates() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    attest_with_violation(&e, &client, &admin, "c_1", "high");
+    attest_with_violation(&e, &client, &admin, "c_1", "high");
+
+    // Simulate metrics stored before the running aggregates existed
+    e.as_contract(&client.address, || {
+        e.storage().instance().remove(&DataKey::ComplianceAggregate);
+        e.storage().instance().remove(&DataKey::ScoreHistogram);
+        e.storage()
+            .instance()
+            .remove(&DataKey::WeightedComplianceAggregate);
+        e.storage()
+            .persistent()
+            .remove(&DataKey::ComplianceWeight(String::from_str(&e, "c_1")));
+    });
+    assert_eq!(client.get_system_compliance().total, 0);
+    assert_eq!(client.get_median_compliance(), 0);
+
+    client.migrate(&admin, &0);
+    assert_eq!(client.get_median_compliance(), 40);
+    let stats = client.get_system_compliance();
+    assert_eq!((stats.total, stats.non_compliant), (1, 1));
+    assert_eq!(stats.average_score, 41);
+    assert_eq!(client.get_system_compliance_weighted().average_score, 41);
+
+    // Re-scoring moves the backfilled score instead of underflowing
+    let params = health_check_params(&e, "c_1");
+    client.attest(
+        &admin,
+        &params.commitment_id,
+        &params.attestation_type,
+        &params.data,
+        &params.is_compliant,
+    );
+    let score = client.get_health_metrics(&params.commitment_id).compliance_score;
+    let stats = client.get_system_compliance();
+    assert_eq!(stats.total, 1);
+    assert_eq!(stats.average_score, score);
+    assert_eq!(client.get_median_compliance(), score / 10 * 10);
+}
+
+#[test]
+fn test_median_compliance_reports_median_bucket() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    assert_eq!(client.get_median_compliance(), 0);
+
+    // Scores: c_1 = c_2 = 100, c_3 = 70, c_4 = c_5 = 41
+    for id in ["c_1", "c_2", "c_3", "c_4", "c_5"] {
+        store_core_commitment(&e, &core_id, id, 1000);
+    }
+    for id in ["c_1", "c_2"] {
+        let params = health_check_params(&e, id);
+        client.attest(
+            &admin,
+            &params.commitment_id,
+            &params.attestation_type,
+            &params.data,
+            &params.is_compliant,
+        );
+    }
+    attest_with_violation(&e, &client, &admin, "c_3", "high");
+    for id in ["c_4", "c_5"] {
+        attest_with_violation(&e, &client, &admin, id, "high");
+        attest_with_violation(&e, &client, &admin, id, "high");
+    }
+    // Sorted 41, 41, 70, 100, 100: the median lies in the 70..=79 bucket
+    assert_eq!(client.get_median_compliance(), 70);
+
+    // Re-scoring c_3 moves it between buckets rather than adding a sample
+    attest_with_violation(&e, &client, &admin, "c_3", "high");
+    assert_eq!(client.get_median_compliance(), 40);
+}
+
+#[test]
+fn test_weighted_system_compliance_favors_large_commitments() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    assert_eq!(client.get_system_compliance_weighted().average_score, 0);
+
+    store_core_commitment(&e, &core_id, "c_big", 1_000_000);
+    store_core_commitment(&e, &core_id, "c_small", 10);
+    let params = health_check_params(&e, "c_big");
+    client.attest(
+        &admin,
+        &params.commitment_id,
+        &params.attestation_type,
+        &params.data,
+        &params.is_compliant,
+    );
+    // c_small: 100 -> 70, then 71 -> 41 (non-compliant)
+    attest_with_violation(&e, &client, &admin, "c_small", "high");
+    attest_with_violation(&e, &client, &admin, "c_small", "high");
+
+    let unweighted = client.get_system_compliance();
+    let weighted = client.get_system_compliance_weighted();
+    assert_eq!(unweighted.average_score, (100 + 41) / 2);
+    assert_eq!(
+        weighted.average_score,
+        ((100 * 1_000_000 + 41 * 10) / 1_000_010) as u32
+    );
+    assert!(weighted.average_score > unweighted.average_score);
+    assert_eq!(
+        (weighted.total, weighted.compliant, weighted.non_compliant),
+        (2, 1, 1)
+    );
+}
+
+#[test]
+fn test_binding_constraint_near_drawdown_limit() {
+    let e = Env::default();
+    let (client, _admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+
+    // 9% loss against a 10% limit leaves 10% of the drawdown headroom
+    let id = String::from_str(&e, "c_1");
+    let core = MockCoreContractClient::new(&e, &core_id);
+    let mut commitment = core.get_commitment(&id);
+    commitment.current_value = 910;
+    core.set_commitment(&id, &commitment);
+
+    assert_eq!(
+        client.get_binding_constraint(&id),
+        symbol_short!("drawdown")
+    );
+}
+
+#[test]
+fn test_binding_constraint_near_maturity() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    let id = String::from_str(&e, "c_1");
+
+    // One day of thirty left, with the fee target already met
+    e.ledger().with_mut(|l| l.timestamp += 29 * 86400);
+    client.record_fees(&admin, &id, &100);
+
+    assert_eq!(
+        client.get_binding_constraint(&id),
+        symbol_short!("duration")
+    );
+}
+
+#[test]
+fn test_attestations_by_verifier_pages_across_commitments() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    let other = Address::generate(&e);
+    client.add_verifier(&admin, &other);
+    for id in ["c_1", "c_2", "c_3"] {
+        store_core_commitment(&e, &core_id, id, 1000);
+    }
+
+    let params = health_check_params(&e, "c_1");
+    client.attest(
+        &admin,
+        &params.commitment_id,
+        &params.attestation_type,
+        &params.data,
+        &params.is_compliant,
+    );
+    let params = health_check_params(&e, "c_2");
+    client.attest(
+        &other,
+        &params.commitment_id,
+        &params.attestation_type,
+        &params.data,
+        &params.is_compliant,
+    );
+    let batch = vec![
+        &e,
+        health_check_params(&e, "c_2"),
+        health_check_params(&e, "c_3"),
+    ];
+    client.batch_attest(&admin, &batch, &BatchMode::Atomic);
+
+    let first = client.get_attestations_by_verifier(&admin, &0, &2);
+    let second = client.get_attestations_by_verifier(&admin, &2, &2);
+    assert_eq!(first.len(), 2);
+    assert_eq!(second.len(), 1);
+    let mut ids = Vec::new(&e);
+    for att in first.iter().chain(second.iter()) {
+        assert_eq!(att.verified_by, admin);
+        ids.push_back(att.commitment_id);
+    }
+    assert_eq!(
+        ids,
+        vec![
+            &e,
+            String::from_str(&e, "c_1"),
+            String::from_str(&e, "c_2"),
+            String::from_str(&e, "c_3"),
+        ]
+    );
+
+    // The other verifier only sees their own attestation
+    let theirs = client.get_attestations_by_verifier(&other, &0, &10);
+    assert_eq!(theirs.len(), 1);
+    assert_eq!(theirs.get(0).unwrap().verified_by, other);
+    assert_eq!(
+        theirs.get(0).unwrap().commitment_id,
+        String::from_str(&e, "c_2")
+    );
+}
+
+#[test]
+fn test_get_full_health_matches_commitment_and_metrics() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    let commitment_id = String::from_str(&e, "c_1");
+    client.record_fees(&admin, &commitment_id, &50);
+
+    let health = client.get_full_health(&commitment_id);
+    assert_eq!(health.commitment.commitment_id, commitment_id);
+    assert_eq!(health.metrics, client.get_health_metrics(&commitment_id));
+    assert_eq!(health.metrics.initial_value, health.commitment.amount);
+    assert_eq!(health.metrics.fees_generated, 50);
+    assert_eq!(health.compliant, client.verify_compliance(&commitment_id));
+    assert!(health.compliant);
+}
+
+#[test]
+fn test_get_full_health_unknown_commitment() {
+    let e = Env::default();
+    let (client, _admin, _core_id) = setup_with_mock_core(&e);
+
+    let result = client.try_get_full_health(&String::from_str(&e, "missing"));
+    assert_eq!(result, Err(Ok(AttestationError::CommitmentNotFound)));
+}
+
+#[test]
+fn test_drawdown_percent_uses_core_rounding_mode() {
+    let e = Env::default();
+    let (client, _admin, core_id) = setup_with_mock_core(&e);
+    let id = String::from_str(&e, "c_1");
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+
+    // 9.6% loss
+    let core = MockCoreContractClient::new(&e, &core_id);
+    let mut commitment = core.get_commitment(&id);
+    commitment.current_value = 904;
+    core.set_commitment(&id, &commitment);
+    assert_eq!(client.get_health_metrics(&id).drawdown_percent, 9);
+
+    core.set_rounding_mode(&RoundingMode::Nearest);
+    assert_eq!(client.get_health_metrics(&id).drawdown_percent, 10);
+    core.set_rounding_mode(&RoundingMode::Ceil);
+    assert_eq!(client.get_health_metrics(&id).drawdown_percent, 10);
+}
+
+#[test]
+fn test_estimated_payout_deducts_early_exit_penalty() {
+    let e = Env::default();
+    let (client, _admin, core_id) = setup_with_mock_core(&e);
+    // 5% early-exit penalty, 30 days to expiry
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+
+    let view = client.get_health_metrics_view(&String::from_str(&e, "c_1"));
+    assert_eq!(view.metrics.current_value, 1000);
+    assert_eq!(view.estimated_payout, 950);
+}
+
+#[test]
+fn test_estimated_payout_equals_current_value_without_penalty() {
+    let e = Env::default();
+    let (client, _admin, core_id) = setup_with_mock_core(&e);
+    let id = String::from_str(&e, "c_1");
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+
+    // Zero penalty: exiting now returns the full value
+    let core = MockCoreContractClient::new(&e, &core_id);
+    let mut commitment = core.get_commitment(&id);
+    commitment.rules.early_exit_penalty = 0;
+    core.set_commitment(&id, &commitment);
+    assert_eq!(client.get_health_metrics_view(&id).estimated_payout, 1000);
+
+    // Past expiry the penalty no longer applies either
+    commitment.rules.early_exit_penalty = 5;
+    core.set_commitment(&id, &commitment);
+    e.ledger().with_mut(|l| l.timestamp = commitment.expires_at);
+    assert_eq!(client.get_health_metrics_view(&id).estimated_payout, 1000);
+}
+
+#[test]
+fn test_get_attestations_in_range_filters_by_timestamp() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    let id = String::from_str(&e, "c_1");
+    let params = health_check_params(&e, "c_1");
+    for ts in [100u64, 200, 300, 400] {
+        e.ledger().with_mut(|l| l.timestamp = ts);
+        client.attest(
+            &admin,
+            &id,
+            &params.attestation_type,
+            &params.data,
+            &params.is_compliant,
+        );
+    }
+
+    let timestamps = |atts: Vec<Attestation>| {
+        let mut out = Vec::new(&e);
+        for att in atts.iter() {
+            out.push_back(att.timestamp);
+        }
+        out
+    };
+
+    let window = client.get_attestations_in_range(&id, &200, &300, &0, &10);
+    assert_eq!(timestamps(window), vec![&e, 200u64, 300]);
+
+    // Offset and limit page through the matches only
+    let second = client.get_attestations_in_range(&id, &150, &400, &1, &1);
+    assert_eq!(timestamps(second), vec![&e, 300u64]);
+
+    let empty = client.get_attestations_in_range(&id, &401, &500, &0, &10);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_get_attestations_in_range_rejects_inverted_range() {
+    let e = Env::default();
+    let (client, _admin, _core_id) = setup_with_mock_core(&e);
+
+    let result =
+        client.try_get_attestations_in_range(&String::from_str(&e, "c_1"), &300, &200, &0, &10);
+    assert_eq!(result, Err(Ok(AttestationError::InvalidTimeRange)));
+}
+
+#[test]
+fn test_unregistered_attestation_type_rejected_in_strict_mode() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    assert_eq!(client.get_strict_attestation_types(), None);
+
+    // Until strict mode is set, unregistered types keep the legacy error
+    let result = client.try_attest(
+        &admin,
+        &String::from_str(&e, "c_1"),
+        &String::from_str(&e, "helth_check"),
+        &Map::new(&e),
+        &true,
+    );
+    assert_eq!(result, Err(Ok(AttestationError::InvalidAttestationType)));
+
+    client.set_strict_attestation_types(&admin, &true);
+    assert_eq!(client.get_strict_attestation_types(), Some(true));
+    let result = client.try_attest(
+        &admin,
+        &String::from_str(&e, "c_1"),
+        &String::from_str(&e, "helth_check"),
+        &Map::new(&e),
+        &true,
+    );
+    assert_eq!(result, Err(Ok(AttestationError::UnknownAttestationType)));
+    assert_eq!(
+        client.get_attestation_count(&String::from_str(&e, "c_1")),
+        0
+    );
+}
+
+#[test]
+fn test_unregistered_attestation_type_accepted_when_not_strict() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    client.set_strict_attestation_types(&admin, &false);
+    assert_eq!(client.get_strict_attestation_types(), Some(false));
+
+    client.attest(
+        &admin,
+        &String::from_str(&e, "c_1"),
+        &String::from_str(&e, "helth_check"),
+        &Map::new(&e),
+        &true,
+    );
+    assert_eq!(
+        client.get_attestation_count(&String::from_str(&e, "c_1")),
+        1
+    );
+}
+
+#[test]
+fn test_registered_attestation_type_applies_score_effect() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    let audit = String::from_str(&e, "audit_finding");
+    client.register_attestation_type(&admin, &audit, &-15);
+    assert_eq!(client.get_attestation_type(&audit), Some(-15));
+
+    let id = String::from_str(&e, "c_1");
+    client.attest(&admin, &id, &audit, &Map::new(&e), &false);
+    let metrics = client.get_stored_health_metrics(&id).unwrap();
+    assert_eq!(metrics.compliance_score, 85);
+}
+
+#[test]
+fn test_register_attestation_type_validation() {
+    let e = Env::default();
+    let (client, admin, _core_id) = setup_with_mock_core(&e);
+    let audit = String::from_str(&e, "audit_finding");
+
+    let builtin =
+        client.try_register_attestation_type(&admin, &String::from_str(&e, "violation"), &-10);
+    assert_eq!(builtin, Err(Ok(AttestationError::InvalidAttestationType)));
+    let too_large = client.try_register_attestation_type(&admin, &audit, &(MAX_SCORE_DELTA + 1));
+    assert_eq!(too_large, Err(Ok(AttestationError::InvalidScoreDelta)));
+    let stranger = Address::generate(&e);
+    let unauthorized = client.try_register_attestation_type(&stranger, &audit, &-10);
+    assert_eq!(unauthorized, Err(Ok(AttestationError::Unauthorized)));
+    assert_eq!(client.get_attestation_type(&audit), None);
+}
+
+/// Record `count` health-check attestations on "c_1" from `verifier`.
+fn attest_times(
+    e: &Env,
+    client: &AttestationEngineContractClient<'_>,
+    verifier: &Address,
+    count: u32,
+) {
+    let params = health_check_params(e, "c_1");
+    for _ in 0..count {
+        client.attest(
+            verifier,
+            &params.commitment_id,
+            &params.attestation_type,
+            &params.data,
+            &params.is_compliant,
+        );
+    }
+}
+
+#[test]
+fn test_verifier_leaderboard_orders_by_count() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    let second = Address::generate(&e);
+    let third = Address::generate(&e);
+    client.add_verifier(&admin, &second);
+    client.add_verifier(&admin, &third);
+
+    attest_times(&e, &client, &second, 1);
+    attest_times(&e, &client, &third, 2);
+    attest_times(&e, &client, &admin, 1);
+    let batch = vec![
+        &e,
+        health_check_params(&e, "c_1"),
+        health_check_params(&e, "c_1"),
+    ];
+    client.batch_attest(&admin, &batch, &BatchMode::Atomic);
+
+    let leaderboard = client.get_verifier_leaderboard(&10);
+    assert_eq!(
+        leaderboard,
+        vec![
+            &e,
+            (admin.clone(), 3u32),
+            (third.clone(), 2u32),
+            (second, 1u32)
+        ]
+    );
+    assert_eq!(
+        client.get_verifier_leaderboard(&2),
+        vec![&e, (admin, 3u32), (third, 2u32)]
+    );
+}
+
+#[test]
+fn test_verifier_leaderboard_is_capped() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+
+    let mut verifiers = Vec::new(&e);
+    for _ in 0..MAX_LEADERBOARD_SIZE {
+        let verifier = Address::generate(&e);
+        client.add_verifier(&admin, &verifier);
+        attest_times(&e, &client, &verifier, 2);
+        verifiers.push_back(verifier);
+    }
+
+    // A verifier below the lowest entry does not displace anyone
+    let late = Address::generate(&e);
+    client.add_verifier(&admin, &late);
+    attest_times(&e, &client, &late, 2);
+    let leaderboard = client.get_verifier_leaderboard(&MAX_PAGE_SIZE);
+    assert_eq!(leaderboard.len(), MAX_LEADERBOARD_SIZE);
+    assert!(!leaderboard.iter().any(|(addr, _)| addr == late));
+
+    // Overtaking the lowest entry moves it onto the board, in order
+    attest_times(&e, &client, &late, 2);
+    let leaderboard = client.get_verifier_leaderboard(&MAX_PAGE_SIZE);
+    assert_eq!(leaderboard.len(), MAX_LEADERBOARD_SIZE);
+    assert_eq!(leaderboard.get(0).unwrap(), (late, 4u32));
+    assert_eq!(
+        leaderboard.get(1).unwrap(),
+        (verifiers.get(0).unwrap(), 2u32)
+    );
+    assert!(!leaderboard
+        .iter()
+        .any(|(addr, _)| addr == verifiers.get(MAX_LEADERBOARD_SIZE - 1).unwrap()));
+}
+
+#[test]
+fn test_time_boxed_verifier_expires() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    e.ledger().with_mut(|l| l.timestamp = 1_000);
+    let verifier = Address::generate(&e);
+    client.add_verifier_until(&admin, &verifier, &2_000);
+    assert_eq!(client.get_verifier_expiry(&verifier), Some(2_000));
+
+    // Allowed up to and including the expiry
+    e.ledger().with_mut(|l| l.timestamp = 2_000);
+    attest_times(&e, &client, &verifier, 1);
+    assert!(client.is_verifier(&verifier));
+
+    e.ledger().with_mut(|l| l.timestamp = 2_001);
+    assert!(!client.is_verifier(&verifier));
+    let params = health_check_params(&e, "c_1");
+    let result = client.try_attest(
+        &verifier,
+        &params.commitment_id,
+        &params.attestation_type,
+        &params.data,
+        &params.is_compliant,
+    );
+    assert_eq!(result, Err(Ok(AttestationError::VerifierExpired)));
+    let batch = client.batch_attest(&verifier, &vec![&e, params], &BatchMode::BestEffort);
+    assert_eq!(
+        batch.errors.get(0).unwrap().error_code,
+        AttestationError::VerifierExpired as u32
+    );
+    assert_eq!(
+        client.get_attestations(&String::from_str(&e, "c_1")).len(),
+        1
+    );
+
+    // Re-adding with add_verifier makes the grant permanent
+    client.add_verifier(&admin, &verifier);
+    assert_eq!(client.get_verifier_expiry(&verifier), None);
+    attest_times(&e, &client, &verifier, 1);
+}
+
+#[test]
+fn test_add_verifier_until_rejects_past_expiry() {
+    let e = Env::default();
+    let (client, admin, _core_id) = setup_with_mock_core(&e);
+    e.ledger().with_mut(|l| l.timestamp = 1_000);
+    let verifier = Address::generate(&e);
+
+    let result = client.try_add_verifier_until(&admin, &verifier, &1_000);
+    assert_eq!(result, Err(Ok(AttestationError::InvalidTimeRange)));
+    assert!(!client.is_verifier(&verifier));
+
+    let result = client.try_add_verifier_until(&verifier, &verifier, &5_000);
+    assert_eq!(result, Err(Ok(AttestationError::Unauthorized)));
+}
+
+#[test]
+fn test_min_attestations_gate_compliance() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    let commitment_id = String::from_str(&e, "c_1");
+
+    // Default 0: an unattested commitment passes on its starting score
+    assert_eq!(client.get_min_compliance_attestations(), 0);
+    assert!(client.verify_compliance(&commitment_id));
+
+    client.set_min_compliance_attestations(&admin, &2);
+    assert_eq!(client.get_min_compliance_attestations(), 2);
+    assert_eq!(
+        client.verify_compliance_with_reason(&commitment_id),
+        (false, Symbol::new(&e, "insufficient_data"))
+    );
+    assert!(!client.get_full_health(&commitment_id).compliant);
+
+    attest_times(&e, &client, &admin, 1);
+    assert!(!client.verify_compliance(&commitment_id));
+    attest_times(&e, &client, &admin, 1);
+    assert_eq!(
+        client.verify_compliance_with_reason(&commitment_id),
+        (true, Symbol::new(&e, "compliant"))
+    );
+    assert!(client.get_full_health(&commitment_id).compliant);
+}
+
+#[test]
+fn test_set_min_compliance_attestations_non_admin_fails() {
+    let e = Env::default();
+    let (client, _admin, _core_id) = setup_with_mock_core(&e);
+    let attacker = Address::generate(&e);
+
+    let result = client.try_set_min_compliance_attestations(&attacker, &3);
+    assert_eq!(result, Err(Ok(AttestationError::Unauthorized)));
+    assert_eq!(client.get_min_compliance_attestations(), 0);
+}
+
+#[test]
+fn test_preview_score_matches_recorded_score() {
+    let e = Env::default();
+    let (client, admin, core_id) = setup_with_mock_core(&e);
+    store_core_commitment(&e, &core_id, "c_1", 1000);
+    let id = String::from_str(&e, "c_1");
+    let stored_score = || {
+        client
+            .get_stored_health_metrics(&id)
+            .unwrap()
+            .compliance_score
+    };
+    let violation = String::from_str(&e, "violation");
+    let violation_data = |severity: &str| {
+        let mut data = Map::new(&e);
+        data.set(
+            String::from_str(&e, "violation_type"),
+            String::from_str(&e, "max_loss"),
+        );
+        data.set(
+            String::from_str(&e, "severity"),
+            String::from_str(&e, severity),
+        );
+        data
+    };
+
+    // Untracked commitments preview from 100; previewing writes nothing
+    let high = Some(String::from_str(&e, "high"));
+    let preview = client.preview_score(&id, &violation, &false, &high);
+    assert_eq!(preview, 70);
+    assert!(client.get_stored_health_metrics(&id).is_none());
+    client.attest(&admin, &id, &violation, &violation_data("high"), &false);
+    assert_eq!(stored_score(), preview);
+
+    let params = health_check_params(&e, "c_1");
+    let preview = client.preview_score(&id, &params.attestation_type, &true, &None);
+    assert_eq!(preview, 71);
+    attest_times(&e, &client, &admin, 1);
+    assert_eq!(stored_score(), preview);
+
+    let medium = Some(String::from_str(&e, "medium"));
+    let preview = client.preview_score(&id, &violation, &false, &medium);
+    assert_eq!(preview, 51);
+    client.attest(&admin, &id, &violation, &violation_data("medium"), &false);
+    assert_eq!(stored_score(), preview);
+
+    // Registered types preview with their score effect
+    let audit = String::from_str(&e, "audit_finding");
+    client.register_attestation_type(&admin, &audit, &-15);
+    let preview = client.preview_score(&id, &audit, &false, &None);
+    assert_eq!(preview, 36);
+    client.attest(&admin, &id, &audit, &Map::new(&e), &false);
+    assert_eq!(stored_score(), preview);
+
+    // Types attest would reject leave the score unchanged
+    let unknown = String::from_str(&e, "unknown_type");
+    assert_eq!(client.preview_score(&id, &unknown, &true, &None), 36);
+}
@@ -1,11 +1,44 @@
 #![no_std]
 
-use shared_utils::{emit_error_event, Pausable, RateLimiter, SafeMath, TimeUtils, Validation};
+use shared_utils::{
+    emit_error_event, BatchError, BatchMode, BatchProcessor, BatchResultI128, Pausable,
+    RateLimiter, RoundingMode, SafeMath, TimeUtils, Validation,
+};
+pub use shared_utils::{Commitment, CommitmentRules};
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, log, symbol_short, token, Address, Env,
-    IntoVal, String, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, log, symbol_short, token,
+    xdr::{FromXdr, ToXdr},
+    Address, Bytes, BytesN, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec,
 };
 
+/// Supported commitment types, in the order reported by `get_type_distribution`.
+pub const COMMITMENT_TYPES: [&str; 3] = ["safe", "balanced", "aggressive"];
+
+/// Maximum number of commitments rewritten per `migrate` call.
+pub const MIGRATION_BATCH_SIZE: u32 = 20;
+
+/// Maximum number of points kept in the TVL history (oldest are evicted).
+pub const MAX_TVL_HISTORY: u32 = 50;
+
+/// Maximum number of commitment ids returned per page by paginated queries.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Longest strategy name accepted by `set_strategy`, in bytes.
+pub const MAX_STRATEGY_NAME_LEN: u32 = 32;
+
+/// Longest strategy description accepted by `set_strategy`, in bytes.
+pub const MAX_STRATEGY_DESCRIPTION_LEN: u32 = 256;
+
+/// Maximum number of value corrections kept per commitment (oldest are evicted).
+pub const MAX_VALUE_CORRECTIONS: u32 = 20;
+
+/// Longest reason accepted by `admin_correct_value`, in bytes.
+pub const MAX_CORRECTION_REASON_LEN: u32 = 256;
+
+/// Statuses a commitment can be in; restored records must use one of them.
+pub const COMMITMENT_STATUSES: [&str; 5] =
+    ["active", "settled", "early_exit", "violated", "disputed"];
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -27,13 +60,53 @@ pub enum CommitmentError {
     NotExpired = 15,
     /// Duration would cause expires_at to overflow u64
     ExpirationOverflow = 16,
+    /// Partial exit fraction outside 1..=9999 basis points
+    InvalidFraction = 17,
+    /// Commitment is under dispute until an admin resolves it
+    Disputed = 18,
+    /// A value, balance or counter would exceed its numeric range
+    Overflow = 19,
+    /// A value or balance would drop below its numeric range
+    Underflow = 20,
+    /// Transferring a settlement or exit payout to the owner failed
+    PayoutFailed = 21,
+    /// Amount is below the configured minimum commitment amount
+    AmountTooSmall = 22,
+    /// Serialized commitment could not be decoded or failed validation
+    InvalidCommitmentData = 23,
+    /// Relinquish effective time is not in the future
+    InvalidEffectiveTime = 24,
+    /// No admin relinquishment is scheduled, or its effective time has not passed
+    RelinquishNotReady = 25,
+    /// Strategy name or description exceeds its length bound
+    InvalidStrategy = 26,
+    /// Replacement asset is this contract or the commitment's current asset
+    InvalidAsset = 27,
+    /// Batch is larger than the configured limit or batching is disabled
+    InvalidBatch = 28,
+    /// Penalty waiver score above 100 or waiver above 10000 basis points
+    InvalidPenaltyWaiver = 29,
+    /// Value correction reason is empty or exceeds its length bound
+    InvalidReason = 30,
+    /// Late penalty cap above 10000 basis points
+    InvalidLatePenalty = 31,
+    /// Stop-loss threshold above 10000 basis points
+    InvalidStopLoss = 32,
+    /// Idempotency key reused with a different amount, asset or rules
+    IdempotencyConflict = 33,
+    /// Custody of an asset has not been seeded since an upgrade
+    CustodyNotSeeded = 34,
+    /// Forced settlement value exceeds the commitment's current value
+    FinalValueTooHigh = 35,
 }
 
 impl CommitmentError {
     /// Human-readable message for debugging and error events.
     pub fn message(&self) -> &'static str {
         match self {
-            CommitmentError::InvalidDuration => "Invalid duration: must be greater than zero",
+            CommitmentError::InvalidDuration => {
+                "Invalid duration: must be greater than zero and within the maximum"
+            }
             CommitmentError::InvalidMaxLossPercent => "Invalid max loss: must be 0-100",
             CommitmentError::InvalidCommitmentType => "Invalid commitment type",
             CommitmentError::InvalidAmount => "Invalid amount: must be greater than zero",
@@ -51,6 +124,37 @@ impl CommitmentError {
             CommitmentError::ExpirationOverflow => {
                 "Duration would cause expiration timestamp overflow"
             }
+            CommitmentError::InvalidFraction => "Invalid fraction: must be 1-9999 basis points",
+            CommitmentError::Disputed => "Commitment is under dispute",
+            CommitmentError::Overflow => "Arithmetic overflow",
+            CommitmentError::Underflow => "Arithmetic underflow",
+            CommitmentError::PayoutFailed => "Payout transfer to owner failed",
+            CommitmentError::AmountTooSmall => "Amount below minimum commitment amount",
+            CommitmentError::InvalidCommitmentData => "Invalid serialized commitment data",
+            CommitmentError::InvalidEffectiveTime => "Effective time must be in the future",
+            CommitmentError::RelinquishNotReady => {
+                "Admin relinquish not scheduled or not yet effective"
+            }
+            CommitmentError::InvalidStrategy => "Invalid strategy: name or description too long",
+            CommitmentError::InvalidAsset => "Invalid asset address",
+            CommitmentError::InvalidBatch => "Invalid batch: too large or batching disabled",
+            CommitmentError::InvalidPenaltyWaiver => {
+                "Invalid penalty waiver: score must be 0-100 and waiver 0-10000 bps"
+            }
+            CommitmentError::InvalidReason => "Invalid reason: empty or too long",
+            CommitmentError::InvalidLatePenalty => {
+                "Invalid late penalty: max must be at most 10000 bps"
+            }
+            CommitmentError::InvalidStopLoss => "Invalid stop loss: must be at most 10000 bps",
+            CommitmentError::IdempotencyConflict => {
+                "Idempotency key already used with different parameters"
+            }
+            CommitmentError::CustodyNotSeeded => {
+                "Custody not seeded: run seed_custody for this asset first"
+            }
+            CommitmentError::FinalValueTooHigh => {
+                "Final value exceeds the commitment's current value"
+            }
         }
     }
 }
@@ -73,30 +177,133 @@ pub struct CommitmentCreatedEvent {
     pub timestamp: u64,
 }
 
+/// Total value locked after a TVL-changing operation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TvlPoint {
+    pub timestamp: u64,
+    pub tvl: i128,
+}
+
+/// Cross-contract wiring reported by `link_status`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LinkStatus {
+    /// A commitment_core reference is configured (always true on the core itself)
+    pub core_set: bool,
+    /// A commitment_nft reference is configured (always true on the NFT itself)
+    pub nft_set: bool,
+    /// Every configured reference answered a readiness call
+    pub reachable: bool,
+}
+
+/// Audit entry for an admin correction of a commitment's current value.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValueCorrection {
+    pub previous_value: i128,
+    pub corrected_value: i128,
+    pub reason: String,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+/// Compliance score point as returned by the attestation engine's
+/// `get_score_history`; decoded when applying the early-exit penalty waiver.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScorePoint {
+    pub timestamp: u64,
+    pub score: u32,
+}
+
+/// Optional strategy descriptor attached to a commitment, beyond its coarse type.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StrategyInfo {
+    /// Short strategy name (at most MAX_STRATEGY_NAME_LEN bytes)
+    pub name: String,
+    /// Target annual yield in basis points
+    pub target_apy_bps: u32,
+    /// Seconds between rebalances; 0 = never rebalanced
+    pub rebalance_cadence_secs: u64,
+    /// Free-form description (at most MAX_STRATEGY_DESCRIPTION_LEN bytes)
+    pub description: String,
+}
+
+/// Aggregate exposure of open commitments in a single asset.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetRisk {
+    /// Sum of committed amounts
+    pub total_committed: i128,
+    /// Sum of current values
+    pub total_current: i128,
+    /// Drawdown of `total_current` against `total_committed` in basis points
+    /// (0 when not below)
+    pub aggregate_drawdown_bps: u32,
+}
+
+/// Request recorded for an idempotency key; a replay must repeat it exactly.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IdempotentRequest {
+    pub commitment_id: String,
+    pub amount: i128,
+    pub asset_address: Address,
+    pub rules: CommitmentRules,
+}
+
+/// Rules layout prior to the addition of `grace_period_days`.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct CommitmentRules {
+pub struct LegacyCommitmentRules {
     pub duration_days: u32,
     pub max_loss_percent: u32,
-    pub commitment_type: String, // "safe", "balanced", "aggressive"
+    pub commitment_type: String,
     pub early_exit_penalty: u32,
     pub min_fee_threshold: i128,
-    pub grace_period_days: u32,
 }
 
+/// Commitment layout using `LegacyCommitmentRules`.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Commitment {
+pub struct LegacyCommitment {
     pub commitment_id: String,
     pub owner: Address,
     pub nft_token_id: u32,
-    pub rules: CommitmentRules,
+    pub rules: LegacyCommitmentRules,
     pub amount: i128,
     pub asset_address: Address,
     pub created_at: u64,
     pub expires_at: u64,
     pub current_value: i128,
-    pub status: String, // "active", "settled", "violated", "early_exit"
+    pub status: String,
+}
+
+impl LegacyCommitment {
+    /// Convert to the current layout, filling defaults for new fields.
+    fn upgrade(self) -> Commitment {
+        Commitment {
+            commitment_id: self.commitment_id,
+            owner: self.owner,
+            nft_token_id: self.nft_token_id,
+            rules: CommitmentRules {
+                duration_days: self.rules.duration_days,
+                max_loss_percent: self.rules.max_loss_percent,
+                commitment_type: self.rules.commitment_type,
+                early_exit_penalty: self.rules.early_exit_penalty,
+                min_fee_threshold: self.rules.min_fee_threshold,
+                grace_period_days: 0,
+            },
+            amount: self.amount,
+            asset_address: self.asset_address,
+            created_at: self.created_at,
+            expires_at: self.expires_at,
+            current_value: self.current_value,
+            status: self.status,
+        }
+    }
 }
 
 #[contracttype]
@@ -111,6 +318,52 @@ pub enum DataKey {
     TotalValueLocked,          // aggregate value locked across active commitments
     /// All commitment IDs for time-range queries (analytics). Appended on create.
     AllCommitmentIds,
+    /// Index into AllCommitmentIds where the next `migrate` call resumes.
+    MigrationCursor,
+    /// Open (not settled or exited) commitments per commitment_type (type -> u32)
+    TypeCount(String),
+    /// Recent TVL points, oldest first (Vec<TvlPoint>, at most MAX_TVL_HISTORY)
+    TvlHistory,
+    /// Address allowed to call `update_value` for a commitment (commitment_id -> Address)
+    ValueReporter(String),
+    /// Tokens held through commitment flows, per asset (asset -> i128)
+    AssetCustody(Address),
+    /// Grace period applied at creation when rules leave it at zero (u32 days)
+    DefaultGracePeriod,
+    /// Longest allowed commitment duration in days; 0 = unlimited (u32)
+    MaxDurationDays,
+    /// Rounding applied to loss percentages and penalties (RoundingMode, default Floor)
+    RoundingMode,
+    /// Smallest amount accepted by create_commitment; 0 = no minimum (i128)
+    MinAmount,
+    /// Time after which `finalize_relinquish` may clear the admin (u64)
+    RelinquishAt,
+    /// Set once the admin has been relinquished; blocks re-initialization (bool)
+    AdminRelinquished,
+    /// Open exposure per asset ((total_committed: i128, total_current: i128))
+    AssetRisk(Address),
+    /// Strategy descriptor attached to a commitment (commitment_id -> StrategyInfo)
+    Strategy(String),
+    /// min_fee_threshold applied at creation when rules leave it at zero (type -> i128)
+    TypeFeeThreshold(String),
+    /// Attestation engine consulted for compliance scores (Address)
+    AttestationEngine,
+    /// Early-exit penalty waiver ((min_score: u32, waiver_bps: u32))
+    PenaltyWaiver,
+    /// Admin value corrections, oldest first (commitment_id -> Vec<ValueCorrection>)
+    ValueCorrections(String),
+    /// Late-settlement penalty ((bps_per_day: u32, max_bps: u32))
+    LatePenalty,
+    /// Owner-set stop-loss drawdown in basis points (commitment_id -> u32)
+    AutoStop(String),
+    /// Client-supplied creation key ((owner, key) -> IdempotentRequest)
+    IdempotencyKey(Address, BytesN<32>),
+    /// Display precision of an asset, if configured (asset -> u32)
+    AssetDecimals(Address),
+    /// Set at initialization: custody has been tracked since deployment (bool)
+    CustodyTracked,
+    /// Custody of an asset was recomputed after an upgrade (asset -> bool)
+    CustodySeeded(Address),
 }
 
 /// Transfer assets from owner to contract
@@ -142,6 +395,7 @@ fn call_nft_mint(
     early_exit_penalty: u32,
 ) -> u32 {
     let mut args = Vec::new(e);
+    args.push_back(e.current_contract_address().into_val(e));
     args.push_back(owner.clone().into_val(e));
     args.push_back(commitment_id.clone().into_val(e));
     args.push_back(duration_days.into_val(e));
@@ -176,6 +430,285 @@ fn has_commitment(e: &Env, commitment_id: &String) -> bool {
         .has(&DataKey::Commitment(commitment_id.clone()))
 }
 
+/// Whether a raw stored commitment predates `CommitmentRules::grace_period_days`.
+///
+/// Layouts are told apart by field names, since decoding a struct with the wrong
+/// number of fields traps in the host rather than returning an error.
+fn is_legacy_layout(e: &Env, raw: &Map<Symbol, Val>) -> bool {
+    let Some(rules_val) = raw.get(symbol_short!("rules")) else {
+        return false;
+    };
+    let Ok(rules) = Map::<Symbol, Val>::try_from_val(e, &rules_val) else {
+        return false;
+    };
+    !rules.contains_key(Symbol::new(e, "grace_period_days"))
+}
+
+/// Increment or decrement the open-commitment counter for a commitment type.
+fn update_type_count(e: &Env, commitment_type: &String, increment: bool) {
+    let key = DataKey::TypeCount(commitment_type.clone());
+    let count = e.storage().instance().get::<_, u32>(&key).unwrap_or(0);
+    let updated = if increment {
+        count.saturating_add(1)
+    } else {
+        count.saturating_sub(1)
+    };
+    e.storage().instance().set(&key, &updated);
+}
+
+/// `a + b`, failing with `Overflow` instead of wrapping or panicking.
+fn checked_add(e: &Env, a: i128, b: i128, context: &str) -> i128 {
+    a.checked_add(b)
+        .unwrap_or_else(|| fail(e, CommitmentError::Overflow, context))
+}
+
+/// `a - b`, failing with `Underflow` instead of wrapping or panicking.
+fn checked_sub(e: &Env, a: i128, b: i128, context: &str) -> i128 {
+    a.checked_sub(b)
+        .unwrap_or_else(|| fail(e, CommitmentError::Underflow, context))
+}
+
+/// Transfer `amount` of the commitment's asset from custody to its owner,
+/// failing with `PayoutFailed` if the token transfer does not succeed.
+fn pay_owner(e: &Env, commitment: &Commitment, amount: i128, context: &str) {
+    let token_client = token::Client::new(e, &commitment.asset_address);
+    let result =
+        token_client.try_transfer(&e.current_contract_address(), &commitment.owner, &amount);
+    if !matches!(result, Ok(Ok(()))) {
+        set_reentrancy_guard(e, false);
+        fail(e, CommitmentError::PayoutFailed, context);
+    }
+    adjust_custody(e, &commitment.asset_address, -amount);
+}
+
+/// Adjust the amount of `asset` the contract holds through commitment flows.
+/// Anything above this balance was sent directly and is rescuable.
+fn adjust_custody(e: &Env, asset: &Address, delta: i128) {
+    let key = DataKey::AssetCustody(asset.clone());
+    let custody = e.storage().instance().get::<_, i128>(&key).unwrap_or(0);
+    let custody = checked_add(e, custody, delta, "adjust_custody");
+    e.storage().instance().set(&key, &custody);
+}
+
+/// Adjust the committed amount and current value tracked for `asset`.
+fn adjust_asset_risk(e: &Env, asset: &Address, committed_delta: i128, current_delta: i128) {
+    let key = DataKey::AssetRisk(asset.clone());
+    let (committed, current) = e
+        .storage()
+        .instance()
+        .get::<_, (i128, i128)>(&key)
+        .unwrap_or((0, 0));
+    let committed = checked_add(e, committed, committed_delta, "adjust_asset_risk");
+    let current = checked_add(e, current, current_delta, "adjust_asset_risk");
+    e.storage().instance().set(&key, &(committed, current));
+}
+
+/// Sum the committed amounts and current values of open (not settled or
+/// exited) commitments in `asset`. Gas cost is O(n) in total commitments.
+fn scan_open_exposure(e: &Env, asset: &Address) -> (i128, i128) {
+    let all_ids = e
+        .storage()
+        .instance()
+        .get::<_, Vec<String>>(&DataKey::AllCommitmentIds)
+        .unwrap_or(Vec::new(e));
+    let settled = String::from_str(e, "settled");
+    let early_exit = String::from_str(e, "early_exit");
+    let (mut committed, mut current) = (0i128, 0i128);
+    for id in all_ids.iter() {
+        let Some(c) = read_commitment(e, &id) else {
+            continue;
+        };
+        if c.asset_address != *asset || c.status == settled || c.status == early_exit {
+            continue;
+        }
+        committed = checked_add(e, committed, c.amount, "scan_open_exposure");
+        current = checked_add(e, current, c.current_value, "scan_open_exposure");
+    }
+    (committed, current)
+}
+
+/// Append `commitment_id` to `owner`'s commitment list.
+fn add_owner_commitment(e: &Env, owner: &Address, commitment_id: &String) {
+    let key = DataKey::OwnerCommitments(owner.clone());
+    let mut commitments = e
+        .storage()
+        .instance()
+        .get::<_, Vec<String>>(&key)
+        .unwrap_or(Vec::new(e));
+    commitments.push_back(commitment_id.clone());
+    e.storage().instance().set(&key, &commitments);
+}
+
+/// Remove `commitment_id` from `owner`'s commitment list, if present.
+fn remove_owner_commitment(e: &Env, owner: &Address, commitment_id: &String) {
+    let key = DataKey::OwnerCommitments(owner.clone());
+    let mut commitments = e
+        .storage()
+        .instance()
+        .get::<_, Vec<String>>(&key)
+        .unwrap_or(Vec::new(e));
+    if let Some(index) = commitments.first_index_of(commitment_id) {
+        commitments.remove(index);
+    }
+    e.storage().instance().set(&key, &commitments);
+}
+
+/// Rounding mode for loss and penalty math; `Floor` when unset.
+fn rounding_mode(e: &Env) -> RoundingMode {
+    e.storage()
+        .instance()
+        .get::<_, RoundingMode>(&DataKey::RoundingMode)
+        .unwrap_or(RoundingMode::Floor)
+}
+
+/// Latest compliance score the attestation engine recorded for a commitment.
+///
+/// Reads the engine's stored score history, which does not call back into
+/// this contract. Returns `None` when no engine is configured, the call fails
+/// or no score has been recorded yet.
+fn latest_compliance_score(e: &Env, commitment_id: &String) -> Option<u32> {
+    let engine = e
+        .storage()
+        .instance()
+        .get::<_, Address>(&DataKey::AttestationEngine)?;
+    let mut args = Vec::new(e);
+    args.push_back(commitment_id.clone().into_val(e));
+    match e.try_invoke_contract::<Vec<ScorePoint>, soroban_sdk::Error>(
+        &engine,
+        &Symbol::new(e, "get_score_history"),
+        args,
+    ) {
+        Ok(Ok(history)) => history.last().map(|point| point.score),
+        _ => None,
+    }
+}
+
+/// Early-exit penalty and payout for `value` of a commitment.
+///
+/// When a penalty waiver is configured and the commitment's latest compliance
+/// score meets its minimum, the penalty is reduced by `waiver_bps`; otherwise
+/// the full penalty applies. Returns (penalty_amount, returned_amount).
+fn calculate_exit_payout(e: &Env, commitment: &Commitment, value: i128) -> (i128, i128) {
+    let mut penalty_amount = SafeMath::penalty_amount_rounded(
+        value,
+        commitment.rules.early_exit_penalty,
+        rounding_mode(e),
+    );
+    if penalty_amount > 0 {
+        if let Some((min_score, waiver_bps)) = e
+            .storage()
+            .instance()
+            .get::<_, (u32, u32)>(&DataKey::PenaltyWaiver)
+        {
+            let waived = waiver_bps > 0
+                && latest_compliance_score(e, &commitment.commitment_id)
+                    .is_some_and(|score| score >= min_score);
+            if waived {
+                let reduction =
+                    SafeMath::div(SafeMath::mul(penalty_amount, waiver_bps as i128), 10000);
+                penalty_amount = SafeMath::sub(penalty_amount, reduction);
+            }
+        }
+    }
+    (penalty_amount, SafeMath::sub(value, penalty_amount))
+}
+
+/// Emit the uniform `StatusChanged` event for a lifecycle transition, with
+/// the commitment's new status and current value. `old_status` is empty for
+/// a newly created commitment.
+fn emit_status_changed(e: &Env, commitment: &Commitment, old_status: String) {
+    e.events().publish(
+        (
+            Symbol::new(e, "StatusChanged"),
+            commitment.commitment_id.clone(),
+        ),
+        (
+            old_status,
+            commitment.status.clone(),
+            commitment.current_value,
+            e.ledger().timestamp(),
+        ),
+    );
+}
+
+/// Whether `contract` answers `is_initialized` with true.
+fn ping_initialized(e: &Env, contract: &Address) -> bool {
+    matches!(
+        e.try_invoke_contract::<bool, soroban_sdk::Error>(
+            contract,
+            &Symbol::new(e, "is_initialized"),
+            Vec::new(e),
+        ),
+        Ok(Ok(true))
+    )
+}
+
+/// Late-settlement penalty for settling `commitment` at `now`.
+///
+/// Accrues `bps_per_day` for every full day past `expires_at` plus the grace
+/// period, capped at `max_bps`. Returns (days_late, penalty_bps); zero when no
+/// late penalty is configured.
+fn late_penalty_bps(e: &Env, commitment: &Commitment, now: u64) -> (u64, u32) {
+    let (bps_per_day, max_bps) = e
+        .storage()
+        .instance()
+        .get::<_, (u32, u32)>(&DataKey::LatePenalty)
+        .unwrap_or((0, 0));
+    if bps_per_day == 0 || max_bps == 0 {
+        return (0, 0);
+    }
+    let grace_secs = (commitment.rules.grace_period_days as u64).saturating_mul(86400);
+    let deadline = commitment.expires_at.saturating_add(grace_secs);
+    let days_late = now.saturating_sub(deadline) / 86400;
+    let bps = days_late
+        .saturating_mul(bps_per_day as u64)
+        .min(max_bps as u64) as u32;
+    (days_late, bps)
+}
+
+/// Loss of a commitment in whole percent under the configured rounding mode.
+/// Zero-amount commitments cannot meaningfully lose value and report 0.
+fn commitment_loss_percent(e: &Env, commitment: &Commitment) -> i128 {
+    if commitment.amount > 0 {
+        SafeMath::loss_percent_rounded(
+            commitment.amount,
+            commitment.current_value,
+            rounding_mode(e),
+        )
+    } else {
+        0
+    }
+}
+
+/// Resolve the address allowed to report values for a commitment: its
+/// configured value reporter, or the admin when none is set.
+fn value_reporter(e: &Env, commitment_id: &String, context: &str) -> Address {
+    e.storage()
+        .instance()
+        .get::<_, Address>(&DataKey::ValueReporter(commitment_id.clone()))
+        .or_else(|| e.storage().instance().get::<_, Address>(&DataKey::Admin))
+        .unwrap_or_else(|| fail(e, CommitmentError::NotInitialized, context))
+}
+
+/// Store the new total value locked and record it in the TVL history.
+fn set_tvl(e: &Env, tvl: i128) {
+    e.storage().instance().set(&DataKey::TotalValueLocked, &tvl);
+
+    let mut history = e
+        .storage()
+        .instance()
+        .get::<_, Vec<TvlPoint>>(&DataKey::TvlHistory)
+        .unwrap_or_else(|| Vec::new(e));
+    history.push_back(TvlPoint {
+        timestamp: e.ledger().timestamp(),
+        tvl,
+    });
+    while history.len() > MAX_TVL_HISTORY {
+        history.pop_front();
+    }
+    e.storage().instance().set(&DataKey::TvlHistory, &history);
+}
+
 /// Reentrancy protection helpers
 fn require_no_reentrancy(e: &Env) {
     let guard: bool = e
@@ -199,6 +732,223 @@ fn set_reentrancy_guard(e: &Env, value: bool) {
         .set(&DataKey::ReentrancyGuard, &value);
 }
 
+/// Settle an expired, active commitment, optionally replacing its current
+/// value with `final_value` first.
+///
+/// With `force`, expiry is not required and a disputed commitment may be
+/// settled too; an unexpired NFT is marked inactive rather than settled. With
+/// `burn_nft`, the settled NFT is burned in the same call.
+///
+/// # Reentrancy Protection
+/// Uses checks-effects-interactions pattern with reentrancy guard.
+fn settle_commitment(
+    e: &Env,
+    commitment_id: String,
+    final_value: Option<i128>,
+    force: bool,
+    burn_nft: bool,
+) {
+    // Reentrancy protection
+    require_no_reentrancy(e);
+    set_reentrancy_guard(e, true);
+
+    // Check if contract is paused
+    Pausable::require_not_paused(e);
+
+    // CHECKS: Get and validate commitment
+    let mut commitment = read_commitment(e, &commitment_id).unwrap_or_else(|| {
+        set_reentrancy_guard(e, false);
+        fail(e, CommitmentError::CommitmentNotFound, "settle")
+    });
+
+    // Verify commitment is expired
+    let current_time = e.ledger().timestamp();
+    let expired = current_time >= commitment.expires_at;
+    if !expired && !force {
+        set_reentrancy_guard(e, false);
+        fail(e, CommitmentError::NotExpired, "settle");
+    }
+
+    // Verify commitment is active (or disputed, when forced)
+    if !force {
+        require_not_disputed(e, &commitment, "settle");
+    }
+    let active_status = String::from_str(e, "active");
+    let disputed = force && commitment.status == String::from_str(e, "disputed");
+    if commitment.status != active_status && !disputed {
+        set_reentrancy_guard(e, false);
+        fail(e, CommitmentError::NotActive, "settle");
+    }
+
+    // EFFECTS: Update state before external calls
+    let previous_value = commitment.current_value;
+    if let Some(final_value) = final_value {
+        commitment.current_value = final_value;
+    }
+    let settlement_amount = commitment.current_value;
+    // Forced settlements close stuck commitments and are not charged late
+    let (days_late, late_bps) = if force {
+        (0, 0)
+    } else {
+        late_penalty_bps(e, &commitment, current_time)
+    };
+    let late_penalty = SafeMath::div(SafeMath::mul(settlement_amount, late_bps as i128), 10000);
+    let payout = SafeMath::sub(settlement_amount, late_penalty);
+    let old_status = commitment.status.clone();
+    commitment.status = String::from_str(e, "settled");
+    set_commitment(e, &commitment);
+    emit_status_changed(e, &commitment, old_status);
+    update_type_count(e, &commitment.rules.commitment_type, false);
+    adjust_asset_risk(
+        e,
+        &commitment.asset_address,
+        -commitment.amount,
+        -previous_value,
+    );
+
+    // Decrease total value locked
+    let current_tvl = e
+        .storage()
+        .instance()
+        .get::<_, i128>(&DataKey::TotalValueLocked)
+        .unwrap_or(0);
+    let new_tvl = checked_sub(e, current_tvl, previous_value, "settle");
+    set_tvl(e, new_tvl);
+
+    // INTERACTIONS: External calls (token transfer, NFT settlement)
+    // Transfer assets back to owner
+    pay_owner(e, &commitment, payout, "settle");
+
+    // Call NFT contract to mark NFT as settled
+    let nft_contract = e
+        .storage()
+        .instance()
+        .get::<_, Address>(&DataKey::NftContract)
+        .unwrap_or_else(|| {
+            set_reentrancy_guard(e, false);
+            fail(e, CommitmentError::NotInitialized, "settle")
+        });
+
+    // The NFT only settles once expired; a forced early settlement deactivates it
+    let nft_fn = match (expired, burn_nft) {
+        (true, true) => "settle_and_burn",
+        (true, false) => "settle",
+        (false, _) => "mark_inactive",
+    };
+    let mut args = Vec::new(e);
+    args.push_back(commitment.nft_token_id.into_val(e));
+    e.invoke_contract::<()>(&nft_contract, &Symbol::new(e, nft_fn), args);
+
+    // Clear reentrancy guard
+    set_reentrancy_guard(e, false);
+
+    // Emit settlement events
+    if final_value.is_some() {
+        e.events().publish(
+            (symbol_short!("SettleVal"), commitment_id.clone()),
+            (previous_value, settlement_amount, e.ledger().timestamp()),
+        );
+    }
+    if late_penalty > 0 {
+        e.events().publish(
+            (symbol_short!("LatePen"), commitment_id.clone()),
+            (days_late, late_bps, late_penalty, e.ledger().timestamp()),
+        );
+    }
+    e.events().publish(
+        (symbol_short!("Settled"), commitment_id),
+        (settlement_amount, e.ledger().timestamp()),
+    );
+}
+
+/// Close an active commitment early: keep the early-exit penalty, return the
+/// rest of its current value to the owner and mark the NFT inactive.
+/// Returns (penalty_amount, returned_amount).
+fn exit_with_penalty(e: &Env, mut commitment: Commitment, context: &str) -> (i128, i128) {
+    // EFFECTS: Calculate penalty (less any compliance waiver)
+    let (penalty_amount, returned_amount) =
+        calculate_exit_payout(e, &commitment, commitment.current_value);
+    let original_value = commitment.current_value;
+
+    // Update commitment status to early_exit
+    let old_status = commitment.status.clone();
+    commitment.status = String::from_str(e, "early_exit");
+    commitment.current_value = 0; // All value has been distributed
+    set_commitment(e, &commitment);
+    emit_status_changed(e, &commitment, old_status);
+    update_type_count(e, &commitment.rules.commitment_type, false);
+    adjust_asset_risk(
+        e,
+        &commitment.asset_address,
+        -commitment.amount,
+        -original_value,
+    );
+
+    // Decrease total value locked by full current value (no longer locked)
+    let current_tvl = e
+        .storage()
+        .instance()
+        .get::<_, i128>(&DataKey::TotalValueLocked)
+        .unwrap_or(0);
+    let new_tvl = checked_sub(e, current_tvl, original_value, context);
+    set_tvl(e, new_tvl);
+
+    // INTERACTIONS: External calls (token transfer)
+    // Transfer remaining amount (after penalty) to owner
+    if returned_amount > 0 {
+        pay_owner(e, &commitment, returned_amount, context);
+    }
+
+    // Call NFT contract to mark as inactive (early exited, not settled)
+    let nft_contract = e
+        .storage()
+        .instance()
+        .get::<_, Address>(&DataKey::NftContract)
+        .unwrap_or_else(|| {
+            set_reentrancy_guard(e, false);
+            fail(e, CommitmentError::NotInitialized, context)
+        });
+
+    // Call mark_inactive on NFT instead of settle (since not expired)
+    let mut args = Vec::new(e);
+    args.push_back(commitment.nft_token_id.into_val(e));
+    e.invoke_contract::<()>(&nft_contract, &Symbol::new(e, "mark_inactive"), args);
+
+    (penalty_amount, returned_amount)
+}
+
+/// Check that `owner` may early-exit `commitment_id` as part of a batch.
+///
+/// `claimed` holds ids already accepted earlier in the same batch, so a
+/// repeated id is reported as no longer active.
+fn batch_exit_candidate(
+    e: &Env,
+    commitment_id: &String,
+    owner: &Address,
+    claimed: &Vec<String>,
+) -> Result<Commitment, (CommitmentError, &'static str)> {
+    let commitment = read_commitment(e, commitment_id)
+        .ok_or((CommitmentError::CommitmentNotFound, "commitment_not_found"))?;
+    if commitment.owner != *owner {
+        return Err((CommitmentError::Unauthorized, "not_owner"));
+    }
+    if commitment.status == String::from_str(e, "disputed") {
+        return Err((CommitmentError::Disputed, "disputed"));
+    }
+    if commitment.status != String::from_str(e, "active") || claimed.contains(commitment_id) {
+        return Err((CommitmentError::NotActive, "not_active"));
+    }
+    Ok(commitment)
+}
+
+/// Fail with `Disputed` (clearing the reentrancy guard) while a dispute is open.
+fn require_not_disputed(e: &Env, commitment: &Commitment, context: &str) {
+    if commitment.status == String::from_str(e, "disputed") {
+        set_reentrancy_guard(e, false);
+        fail(e, CommitmentError::Disputed, context);
+    }
+}
+
 /// Require that the caller is the admin stored in this contract.
 fn require_admin(e: &Env, caller: &Address) {
     caller.require_auth();
@@ -271,8 +1021,7 @@ impl CommitmentCoreContract {
         Validation::require_valid_percent(rules.max_loss_percent);
 
         // Commitment type must be valid
-        let valid_types = ["safe", "balanced", "aggressive"];
-        Validation::require_valid_commitment_type(e, &rules.commitment_type, &valid_types);
+        Validation::require_valid_commitment_type(e, &rules.commitment_type, &COMMITMENT_TYPES);
     }
 
     /// Generate unique commitment ID
@@ -311,8 +1060,10 @@ impl CommitmentCoreContract {
 
     /// Initialize the core commitment contract
     pub fn initialize(e: Env, admin: Address, nft_contract: Address) {
-        // Check if already initialized
-        if e.storage().instance().has(&DataKey::Admin) {
+        // Check if already initialized (or initialized and since relinquished)
+        if e.storage().instance().has(&DataKey::Admin)
+            || e.storage().instance().has(&DataKey::AdminRelinquished)
+        {
             fail(&e, CommitmentError::AlreadyInitialized, "initialize");
         }
 
@@ -339,6 +1090,9 @@ impl CommitmentCoreContract {
 
         // Initialize paused state (default: not paused)
         e.storage().instance().set(&Pausable::PAUSED_KEY, &false);
+
+        // Every deposit into this deployment is tracked in AssetCustody
+        e.storage().instance().set(&DataKey::CustodyTracked, &true);
     }
 
     /// Create a new commitment
@@ -398,10 +1152,30 @@ impl CommitmentCoreContract {
 
         // Validate amount > 0 using shared utilities
         Validation::require_positive(amount);
+        if amount < Self::get_min_amount(e.clone()) {
+            fail(&e, CommitmentError::AmountTooSmall, "create_commitment");
+        }
 
         // Validate rules
         Self::validate_rules(&e, &rules);
 
+        // New commitments must also fit the configured maximum duration, if any;
+        // restored records are not held to it
+        let max_duration = Self::get_max_duration_days(e.clone());
+        if max_duration > 0 && rules.duration_days > max_duration {
+            fail(&e, CommitmentError::InvalidDuration, "create_commitment");
+        }
+
+        // Zero means "unset": inherit the protocol-wide default
+        let mut rules = rules;
+        if rules.grace_period_days == 0 {
+            rules.grace_period_days = Self::get_default_grace_period(e.clone());
+        }
+        if rules.min_fee_threshold == 0 {
+            rules.min_fee_threshold =
+                Self::get_type_fee_threshold(e.clone(), rules.commitment_type.clone());
+        }
+
         // Reject duration_days that would cause expires_at to overflow u64
         let expires_at = TimeUtils::checked_calculate_expiration(&e, rules.duration_days)
             .unwrap_or_else(|| {
@@ -474,12 +1248,16 @@ impl CommitmentCoreContract {
         );
 
         // OPTIMIZATION: Increment both counters using already-read values
+        let next_total = current_total
+            .checked_add(1)
+            .unwrap_or_else(|| fail(&e, CommitmentError::Overflow, "create_commitment"));
         e.storage()
             .instance()
-            .set(&DataKey::TotalCommitments, &(current_total + 1));
-        e.storage()
-            .instance()
-            .set(&DataKey::TotalValueLocked, &(current_tvl + amount));
+            .set(&DataKey::TotalCommitments, &next_total);
+        let new_tvl = checked_add(&e, current_tvl, amount, "create_commitment");
+        set_tvl(&e, new_tvl);
+        update_type_count(&e, &rules.commitment_type, true);
+        adjust_asset_risk(&e, &asset_address, amount, amount);
 
         // Append to AllCommitmentIds for time-range queries (#143)
         let mut all_ids = e
@@ -496,6 +1274,7 @@ impl CommitmentCoreContract {
         // Transfer assets from owner to contract
         let contract_address = e.current_contract_address();
         transfer_assets(&e, &owner, &contract_address, &asset_address, amount);
+        adjust_custody(&e, &asset_address, amount);
 
         // Mint NFT
         let nft_token_id = call_nft_mint(
@@ -515,6 +1294,7 @@ impl CommitmentCoreContract {
         let mut updated_commitment = commitment;
         updated_commitment.nft_token_id = nft_token_id;
         set_commitment(&e, &updated_commitment);
+        emit_status_changed(&e, &updated_commitment, String::from_str(&e, ""));
 
         // Clear reentrancy guard
         set_reentrancy_guard(&e, false);
@@ -531,12 +1311,125 @@ impl CommitmentCoreContract {
         commitment_id
     }
 
+    /// Create a commitment and return the full stored record, including the
+    /// computed timestamps and NFT token id. Same behavior as `create_commitment`.
+    pub fn create_commitment_full(
+        e: Env,
+        owner: Address,
+        amount: i128,
+        asset_address: Address,
+        rules: CommitmentRules,
+    ) -> Commitment {
+        let commitment_id = Self::create_commitment(e.clone(), owner, amount, asset_address, rules);
+        read_commitment(&e, &commitment_id).unwrap_or_else(|| {
+            fail(
+                &e,
+                CommitmentError::CommitmentNotFound,
+                "create_commitment_full",
+            )
+        })
+    }
+
+    /// Create a commitment, deduplicating retries by `idempotency_key`.
+    ///
+    /// Keys are scoped to `owner`, who must authorize every call. When the
+    /// owner already used the key with the same amount, asset and rules, the
+    /// commitment created by the first call is returned and nothing else
+    /// happens; different parameters fail with `IdempotencyConflict`. Without
+    /// a key this behaves exactly like `create_commitment`.
+    pub fn create_commitment_idempotent(
+        e: Env,
+        owner: Address,
+        amount: i128,
+        asset_address: Address,
+        rules: CommitmentRules,
+        idempotency_key: Option<BytesN<32>>,
+    ) -> String {
+        let Some(key) = idempotency_key else {
+            return Self::create_commitment(e, owner, amount, asset_address, rules);
+        };
+        owner.require_auth();
+        let storage_key = DataKey::IdempotencyKey(owner.clone(), key);
+        if let Some(existing) = e
+            .storage()
+            .instance()
+            .get::<_, IdempotentRequest>(&storage_key)
+        {
+            if existing.amount != amount
+                || existing.asset_address != asset_address
+                || existing.rules != rules
+            {
+                fail(
+                    &e,
+                    CommitmentError::IdempotencyConflict,
+                    "create_commitment_idempotent",
+                );
+            }
+            return existing.commitment_id;
+        }
+        let commitment_id = Self::create_commitment(
+            e.clone(),
+            owner,
+            amount,
+            asset_address.clone(),
+            rules.clone(),
+        );
+        let request = IdempotentRequest {
+            commitment_id: commitment_id.clone(),
+            amount,
+            asset_address,
+            rules,
+        };
+        e.storage().instance().set(&storage_key, &request);
+        commitment_id
+    }
+
+    /// Commitment id recorded for an owner's idempotency key, if it has been used.
+    pub fn get_idempotent_commitment(
+        e: Env,
+        owner: Address,
+        idempotency_key: BytesN<32>,
+    ) -> Option<String> {
+        e.storage()
+            .instance()
+            .get::<_, IdempotentRequest>(&DataKey::IdempotencyKey(owner, idempotency_key))
+            .map(|request| request.commitment_id)
+    }
+
     /// Get commitment details
     pub fn get_commitment(e: Env, commitment_id: String) -> Commitment {
         read_commitment(&e, &commitment_id)
             .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "get_commitment"))
     }
 
+    /// Get the commitments for a list of ids in one call.
+    ///
+    /// Unknown ids are skipped rather than failing, so the result holds the
+    /// known commitments in the order their ids were given. The list is
+    /// capped by the batch limits: an oversized list emits `BatchRejected`
+    /// and returns an empty result, as an empty list does.
+    pub fn get_commitments(e: Env, ids: Vec<String>) -> Vec<Commitment> {
+        let mut commitments = Vec::new(&e);
+        if ids.is_empty() {
+            return commitments;
+        }
+        let batch_size = ids.len();
+        let contract_name = String::from_str(&e, "commitment_core");
+        if let Err(error_code) =
+            BatchProcessor::enforce_batch_limits(&e, batch_size, Some(contract_name.clone()))
+        {
+            // Returned rather than failed so the rejection event is kept
+            BatchProcessor::emit_batch_rejected(&e, batch_size, Some(contract_name), error_code);
+            return commitments;
+        }
+        for id in ids.iter() {
+            if let Some(commitment) = read_commitment(&e, &id) {
+                commitments.push_back(commitment);
+            }
+        }
+        commitments
+    }
+
     /// List all commitment IDs owned by the given address.
     ///
     /// This is a convenience wrapper around `get_owner_commitments` with a
@@ -569,10 +1462,89 @@ impl CommitmentCoreContract {
             .unwrap_or(0)
     }
 
-    /// Get commitment IDs created between two timestamps (inclusive).
-    /// For analytics/dashboards. Gas cost is O(n) in total commitments; consider pagination for large n.
-    pub fn get_commitments_created_between(
-        e: Env,
+    /// Get the aggregate exposure of open commitments in `asset`.
+    ///
+    /// Maintained incrementally on create, value update, allocation, partial
+    /// exit, settlement and early exit, so the query does not scan commitments.
+    /// Deployments upgraded from a version without the aggregate must run
+    /// `seed_asset_risk` for each asset first.
+    pub fn get_asset_risk(e: Env, asset: Address) -> AssetRisk {
+        let (total_committed, total_current) = e
+            .storage()
+            .instance()
+            .get::<_, (i128, i128)>(&DataKey::AssetRisk(asset))
+            .unwrap_or((0, 0));
+        let aggregate_drawdown_bps = if total_committed > 0 && total_current < total_committed {
+            ((total_committed - total_current) * 10000 / total_committed) as u32
+        } else {
+            0
+        };
+        AssetRisk {
+            total_committed,
+            total_current,
+            aggregate_drawdown_bps,
+        }
+    }
+
+    /// Recompute the risk aggregate of `asset` from stored commitments (admin only).
+    ///
+    /// Commitments created before the aggregate existed are missing from it,
+    /// so upgraded deployments call this once per asset after `migrate` has
+    /// finished. Safe to repeat. Gas cost is O(n) in total commitments.
+    pub fn seed_asset_risk(e: Env, admin: Address, asset: Address) -> AssetRisk {
+        require_admin(&e, &admin);
+        let (total_committed, total_current) = scan_open_exposure(&e, &asset);
+        e.storage().instance().set(
+            &DataKey::AssetRisk(asset.clone()),
+            &(total_committed, total_current),
+        );
+        e.events().publish(
+            (Symbol::new(&e, "AssetRiskSeeded"), asset.clone()),
+            (total_committed, total_current, e.ledger().timestamp()),
+        );
+        Self::get_asset_risk(e, asset)
+    }
+
+    /// Record the display precision of `asset` for frontends rendering
+    /// commitment values and drawdowns. Admin only.
+    ///
+    /// Kept per asset rather than on each commitment so stored commitments
+    /// keep their layout.
+    pub fn set_asset_decimals(e: Env, admin: Address, asset: Address, decimals: u32) {
+        require_admin(&e, &admin);
+        e.storage()
+            .instance()
+            .set(&DataKey::AssetDecimals(asset.clone()), &decimals);
+        e.events().publish(
+            (Symbol::new(&e, "AssetDecimalsSet"), asset),
+            (decimals, e.ledger().timestamp()),
+        );
+    }
+
+    /// Get the display precision recorded for `asset`, if any.
+    pub fn get_asset_decimals(e: Env, asset: Address) -> Option<u32> {
+        e.storage().instance().get(&DataKey::AssetDecimals(asset))
+    }
+
+    /// Get the most recent `limit` TVL points, oldest first.
+    ///
+    /// A point is recorded on every TVL-changing operation (create, value
+    /// update, settle, early exit); at most `MAX_TVL_HISTORY` are kept.
+    pub fn get_tvl_history(e: Env, limit: u32) -> Vec<TvlPoint> {
+        let history = e
+            .storage()
+            .instance()
+            .get::<_, Vec<TvlPoint>>(&DataKey::TvlHistory)
+            .unwrap_or_else(|| Vec::new(&e));
+        let len = history.len();
+        let start = len.saturating_sub(limit);
+        history.slice(start..len)
+    }
+
+    /// Get commitment IDs created between two timestamps (inclusive).
+    /// For analytics/dashboards. Gas cost is O(n) in total commitments; consider pagination for large n.
+    pub fn get_commitments_created_between(
+        e: Env,
         from_ts: u64,
         to_ts: u64,
     ) -> Vec<String> {
@@ -592,6 +1564,80 @@ impl CommitmentCoreContract {
         out
     }
 
+    /// Get active commitment IDs expiring between now and `now + within_seconds`
+    /// (inclusive), in creation order, for keepers scheduling settlement.
+    ///
+    /// `offset` skips that many matches and `limit` is capped at `MAX_PAGE_SIZE`.
+    /// Gas cost is O(n) in total commitments.
+    pub fn get_expiring_soon(e: Env, within_seconds: u64, offset: u32, limit: u32) -> Vec<String> {
+        let now = e.ledger().timestamp();
+        let until = now.saturating_add(within_seconds);
+        let limit = limit.min(MAX_PAGE_SIZE);
+        let active_status = String::from_str(&e, "active");
+        let all_ids = e
+            .storage()
+            .instance()
+            .get::<_, Vec<String>>(&DataKey::AllCommitmentIds)
+            .unwrap_or(Vec::new(&e));
+
+        let mut out = Vec::new(&e);
+        let mut skipped: u32 = 0;
+        for id in all_ids.iter() {
+            if out.len() >= limit {
+                break;
+            }
+            let Some(c) = read_commitment(&e, &id) else {
+                continue;
+            };
+            if c.status != active_status || c.expires_at < now || c.expires_at > until {
+                continue;
+            }
+            if skipped < offset {
+                skipped += 1;
+                continue;
+            }
+            out.push_back(id);
+        }
+        out
+    }
+
+    /// Get the number of open (not settled or exited) commitments per type,
+    /// in `COMMITMENT_TYPES` order.
+    pub fn get_type_distribution(e: Env) -> Vec<(String, u32)> {
+        let mut distribution = Vec::new(&e);
+        for commitment_type in COMMITMENT_TYPES.iter() {
+            let commitment_type = String::from_str(&e, commitment_type);
+            let count = e
+                .storage()
+                .instance()
+                .get::<_, u32>(&DataKey::TypeCount(commitment_type.clone()))
+                .unwrap_or(0);
+            distribution.push_back((commitment_type, count));
+        }
+        distribution
+    }
+
+    /// Check whether `initialize` has run (the admin is set, or was set and
+    /// has since been relinquished); a readiness probe for deployment tooling.
+    pub fn is_initialized(e: Env) -> bool {
+        e.storage().instance().has(&DataKey::Admin)
+            || e.storage().instance().has(&DataKey::AdminRelinquished)
+    }
+
+    /// Report whether the NFT contract is configured and initialized; a
+    /// readiness signal for deployment tooling.
+    pub fn link_status(e: Env) -> LinkStatus {
+        let nft = e
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::NftContract);
+        LinkStatus {
+            core_set: true,
+            nft_set: nft.is_some(),
+            reachable: nft.is_some_and(|nft| ping_initialized(&e, &nft)),
+        }
+    }
+
     /// Get admin address
     pub fn get_admin(e: Env) -> Address {
         e.storage()
@@ -600,6 +1646,278 @@ impl CommitmentCoreContract {
             .unwrap_or_else(|| fail(&e, CommitmentError::NotInitialized, "get_admin"))
     }
 
+    /// Check whether the reentrancy guard is currently set.
+    pub fn is_locked(e: Env) -> bool {
+        e.storage()
+            .instance()
+            .get::<_, bool>(&DataKey::ReentrancyGuard)
+            .unwrap_or(false)
+    }
+
+    /// Clear a reentrancy guard left set by an aborted operation (admin-only).
+    pub fn force_unlock(e: Env, admin: Address) {
+        require_admin(&e, &admin);
+        set_reentrancy_guard(&e, false);
+        e.events().publish(
+            (Symbol::new(&e, "GuardForceCleared"),),
+            (admin, e.ledger().timestamp()),
+        );
+    }
+
+    /// Schedule the admin to be relinquished at `effective_at` (admin only).
+    ///
+    /// Rescheduling replaces any pending time. Until `finalize_relinquish`
+    /// runs, the admin keeps full control and may `cancel_relinquish`.
+    pub fn schedule_relinquish(e: Env, admin: Address, effective_at: u64) {
+        require_admin(&e, &admin);
+        if effective_at <= e.ledger().timestamp() {
+            fail(
+                &e,
+                CommitmentError::InvalidEffectiveTime,
+                "schedule_relinquish",
+            );
+        }
+        e.storage()
+            .instance()
+            .set(&DataKey::RelinquishAt, &effective_at);
+        e.events().publish(
+            (Symbol::new(&e, "RelinquishScheduled"),),
+            (admin, effective_at, e.ledger().timestamp()),
+        );
+    }
+
+    /// Cancel a pending admin relinquishment (admin only).
+    pub fn cancel_relinquish(e: Env, admin: Address) {
+        require_admin(&e, &admin);
+        if !e.storage().instance().has(&DataKey::RelinquishAt) {
+            fail(&e, CommitmentError::RelinquishNotReady, "cancel_relinquish");
+        }
+        e.storage().instance().remove(&DataKey::RelinquishAt);
+        e.events().publish(
+            (Symbol::new(&e, "RelinquishCancelled"),),
+            (admin, e.ledger().timestamp()),
+        );
+    }
+
+    /// Clear the admin once the scheduled relinquish time has passed.
+    ///
+    /// Callable by anyone. Afterwards every admin-only function fails with
+    /// `NotInitialized` and the contract cannot be initialized again.
+    pub fn finalize_relinquish(e: Env) {
+        let effective_at = e
+            .storage()
+            .instance()
+            .get::<_, u64>(&DataKey::RelinquishAt)
+            .unwrap_or_else(|| {
+                fail(
+                    &e,
+                    CommitmentError::RelinquishNotReady,
+                    "finalize_relinquish",
+                )
+            });
+        if e.ledger().timestamp() < effective_at {
+            fail(
+                &e,
+                CommitmentError::RelinquishNotReady,
+                "finalize_relinquish",
+            );
+        }
+        let admin = e
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::Admin)
+            .unwrap_or_else(|| fail(&e, CommitmentError::NotInitialized, "finalize_relinquish"));
+        e.storage().instance().remove(&DataKey::Admin);
+        e.storage().instance().remove(&DataKey::RelinquishAt);
+        e.storage()
+            .instance()
+            .set(&DataKey::AdminRelinquished, &true);
+        e.events().publish(
+            (Symbol::new(&e, "AdminRelinquished"),),
+            (admin, e.ledger().timestamp()),
+        );
+    }
+
+    /// Get the scheduled admin relinquish time, if any.
+    pub fn get_relinquish_time(e: Env) -> Option<u64> {
+        e.storage().instance().get(&DataKey::RelinquishAt)
+    }
+
+    /// Set the grace period (in days) given to commitments created with
+    /// `grace_period_days == 0`. Admin only; zero disables the default.
+    pub fn set_default_grace_period(e: Env, admin: Address, days: u32) {
+        require_admin(&e, &admin);
+        e.storage()
+            .instance()
+            .set(&DataKey::DefaultGracePeriod, &days);
+        e.events()
+            .publish((symbol_short!("GraceSet"),), (days, e.ledger().timestamp()));
+    }
+
+    /// Get the default grace period in days (0 when unset).
+    pub fn get_default_grace_period(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get::<_, u32>(&DataKey::DefaultGracePeriod)
+            .unwrap_or(0)
+    }
+
+    /// Set the min_fee_threshold a commitment of `commitment_type` inherits
+    /// when created with a threshold of zero. Admin only.
+    pub fn set_type_fee_threshold(
+        e: Env,
+        admin: Address,
+        commitment_type: String,
+        threshold: i128,
+    ) {
+        require_admin(&e, &admin);
+        Validation::require_valid_commitment_type(&e, &commitment_type, &COMMITMENT_TYPES);
+        Validation::require_non_negative(threshold);
+        e.storage().instance().set(
+            &DataKey::TypeFeeThreshold(commitment_type.clone()),
+            &threshold,
+        );
+        e.events().publish(
+            (Symbol::new(&e, "TypeFeeThresholdSet"), commitment_type),
+            (threshold, e.ledger().timestamp()),
+        );
+    }
+
+    /// Get the default min_fee_threshold for a commitment type (0 when unset).
+    pub fn get_type_fee_threshold(e: Env, commitment_type: String) -> i128 {
+        e.storage()
+            .instance()
+            .get::<_, i128>(&DataKey::TypeFeeThreshold(commitment_type))
+            .unwrap_or(0)
+    }
+
+    /// Set the longest commitment duration (in days) accepted at creation.
+    /// Admin only; zero removes the limit.
+    pub fn set_max_duration_days(e: Env, admin: Address, max: u32) {
+        require_admin(&e, &admin);
+        e.storage().instance().set(&DataKey::MaxDurationDays, &max);
+        e.events()
+            .publish((symbol_short!("MaxDurSet"),), (max, e.ledger().timestamp()));
+    }
+
+    /// Get the maximum commitment duration in days (0 when unlimited).
+    pub fn get_max_duration_days(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get::<_, u32>(&DataKey::MaxDurationDays)
+            .unwrap_or(0)
+    }
+
+    /// Set the smallest amount `create_commitment` accepts.
+    /// Admin only; zero removes the minimum.
+    pub fn set_min_amount(e: Env, admin: Address, min: i128) {
+        require_admin(&e, &admin);
+        if min < 0 {
+            fail(&e, CommitmentError::InvalidAmount, "set_min_amount");
+        }
+        e.storage().instance().set(&DataKey::MinAmount, &min);
+        e.events()
+            .publish((symbol_short!("MinAmtSet"),), (min, e.ledger().timestamp()));
+    }
+
+    /// Get the minimum commitment amount (0 when unset).
+    pub fn get_min_amount(e: Env) -> i128 {
+        e.storage()
+            .instance()
+            .get::<_, i128>(&DataKey::MinAmount)
+            .unwrap_or(0)
+    }
+
+    /// Charge settlements made after the grace period `bps_per_day` of the
+    /// settled value per full day late, up to `max_bps`. Admin only; zero
+    /// for either disables the penalty (the default). The penalty stays in
+    /// the contract and is reported in a `LatePen` event on settlement.
+    pub fn set_late_penalty(e: Env, admin: Address, bps_per_day: u32, max_bps: u32) {
+        require_admin(&e, &admin);
+        if max_bps > 10000 {
+            fail(&e, CommitmentError::InvalidLatePenalty, "set_late_penalty");
+        }
+        e.storage()
+            .instance()
+            .set(&DataKey::LatePenalty, &(bps_per_day, max_bps));
+        e.events().publish(
+            (symbol_short!("LateSet"),),
+            (bps_per_day, max_bps, e.ledger().timestamp()),
+        );
+    }
+
+    /// Get the late-settlement penalty as (bps_per_day, max_bps); (0, 0) when unset.
+    pub fn get_late_penalty(e: Env) -> (u32, u32) {
+        e.storage()
+            .instance()
+            .get(&DataKey::LatePenalty)
+            .unwrap_or((0, 0))
+    }
+
+    /// Set the attestation engine whose compliance scores drive the
+    /// early-exit penalty waiver. Admin only.
+    pub fn set_attestation_engine(e: Env, admin: Address, engine: Address) {
+        require_admin(&e, &admin);
+        e.storage()
+            .instance()
+            .set(&DataKey::AttestationEngine, &engine);
+        e.events().publish(
+            (Symbol::new(&e, "AttestationEngineSet"),),
+            (engine, e.ledger().timestamp()),
+        );
+    }
+
+    /// Get the configured attestation engine, if any.
+    pub fn get_attestation_engine(e: Env) -> Option<Address> {
+        e.storage().instance().get(&DataKey::AttestationEngine)
+    }
+
+    /// Reduce early-exit penalties by `waiver_bps` for commitments whose latest
+    /// compliance score in the attestation engine is at least `min_score`.
+    /// Admin only; a `waiver_bps` of zero disables the waiver.
+    ///
+    /// If the engine is unset, unreachable or has no score for a commitment,
+    /// the full penalty applies.
+    pub fn set_penalty_waiver(e: Env, admin: Address, min_score: u32, waiver_bps: u32) {
+        require_admin(&e, &admin);
+        if min_score > 100 || waiver_bps > 10000 {
+            fail(
+                &e,
+                CommitmentError::InvalidPenaltyWaiver,
+                "set_penalty_waiver",
+            );
+        }
+        e.storage()
+            .instance()
+            .set(&DataKey::PenaltyWaiver, &(min_score, waiver_bps));
+        e.events().publish(
+            (Symbol::new(&e, "PenaltyWaiverSet"),),
+            (min_score, waiver_bps, e.ledger().timestamp()),
+        );
+    }
+
+    /// Get the penalty waiver as (min_score, waiver_bps); (0, 0) when unset.
+    pub fn get_penalty_waiver(e: Env) -> (u32, u32) {
+        e.storage()
+            .instance()
+            .get(&DataKey::PenaltyWaiver)
+            .unwrap_or((0, 0))
+    }
+
+    /// Set how loss percentages and early-exit penalties are rounded.
+    /// Admin only; defaults to `Floor`.
+    pub fn set_rounding_mode(e: Env, admin: Address, mode: RoundingMode) {
+        require_admin(&e, &admin);
+        e.storage().instance().set(&DataKey::RoundingMode, &mode);
+        e.events()
+            .publish((symbol_short!("RoundSet"),), (mode, e.ledger().timestamp()));
+    }
+
+    /// Get the rounding mode for loss and penalty math.
+    pub fn get_rounding_mode(e: Env) -> RoundingMode {
+        rounding_mode(&e)
+    }
+
     /// Get NFT contract address
     pub fn get_nft_contract(e: Env) -> Address {
         e.storage()
@@ -608,8 +1926,251 @@ impl CommitmentCoreContract {
             .unwrap_or_else(|| fail(&e, CommitmentError::NotInitialized, "get_nft_contract"))
     }
 
+    /// Set the oracle/keeper allowed to report values for a commitment.
+    ///
+    /// Callable by the commitment owner or the admin; the admin can always
+    /// replace a reporter chosen by the owner.
+    pub fn set_value_reporter(e: Env, commitment_id: String, reporter: Address, caller: Address) {
+        caller.require_auth();
+        let commitment = read_commitment(&e, &commitment_id).unwrap_or_else(|| {
+            fail(
+                &e,
+                CommitmentError::CommitmentNotFound,
+                "set_value_reporter",
+            )
+        });
+        let admin = e
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::Admin)
+            .unwrap_or_else(|| fail(&e, CommitmentError::NotInitialized, "set_value_reporter"));
+        if caller != commitment.owner && caller != admin {
+            fail(&e, CommitmentError::Unauthorized, "set_value_reporter");
+        }
+
+        e.storage()
+            .instance()
+            .set(&DataKey::ValueReporter(commitment_id.clone()), &reporter);
+        e.events().publish(
+            (symbol_short!("RptSet"), commitment_id),
+            (reporter, caller, e.ledger().timestamp()),
+        );
+    }
+
+    /// Get the configured value reporter for a commitment, if any.
+    /// When unset, `update_value` requires the admin's authorization.
+    pub fn get_value_reporter(e: Env, commitment_id: String) -> Option<Address> {
+        e.storage()
+            .instance()
+            .get::<_, Address>(&DataKey::ValueReporter(commitment_id))
+    }
+
+    /// Point a commitment at a new asset contract, e.g. after the underlying
+    /// token was upgraded or wrapped (admin only).
+    ///
+    /// Amounts and status are unchanged. While the commitment is open its
+    /// exposure moves from the old asset's risk aggregate to the new one, and
+    /// its `current_value` (at most the old asset's custody) moves from the
+    /// old asset's custody to the new one. The tokens themselves must be
+    /// migrated separately; old tokens no longer in custody become rescuable.
+    pub fn replace_asset(e: Env, admin: Address, commitment_id: String, new_asset: Address) {
+        require_admin(&e, &admin);
+        let mut commitment = read_commitment(&e, &commitment_id)
+            .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "replace_asset"));
+        let old_asset = commitment.asset_address.clone();
+        if new_asset == e.current_contract_address() || new_asset == old_asset {
+            fail(&e, CommitmentError::InvalidAsset, "replace_asset");
+        }
+
+        let closed = commitment.status == String::from_str(&e, "settled")
+            || commitment.status == String::from_str(&e, "early_exit");
+        if !closed {
+            adjust_asset_risk(
+                &e,
+                &old_asset,
+                -commitment.amount,
+                -commitment.current_value,
+            );
+            adjust_asset_risk(&e, &new_asset, commitment.amount, commitment.current_value);
+
+            let old_custody = e
+                .storage()
+                .instance()
+                .get::<_, i128>(&DataKey::AssetCustody(old_asset.clone()))
+                .unwrap_or(0);
+            let moved_custody = commitment.current_value.min(old_custody).max(0);
+            adjust_custody(&e, &old_asset, -moved_custody);
+            adjust_custody(&e, &new_asset, moved_custody);
+        }
+        commitment.asset_address = new_asset.clone();
+        set_commitment(&e, &commitment);
+
+        e.events().publish(
+            (Symbol::new(&e, "AssetReplaced"), commitment_id),
+            (old_asset, new_asset, admin, e.ledger().timestamp()),
+        );
+    }
+
+    /// Attach or replace the strategy descriptor of a commitment.
+    ///
+    /// Callable by the commitment owner or the admin.
+    pub fn set_strategy(e: Env, commitment_id: String, info: StrategyInfo, caller: Address) {
+        caller.require_auth();
+        let commitment = read_commitment(&e, &commitment_id)
+            .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "set_strategy"));
+        let admin = e
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::Admin)
+            .unwrap_or_else(|| fail(&e, CommitmentError::NotInitialized, "set_strategy"));
+        if caller != commitment.owner && caller != admin {
+            fail(&e, CommitmentError::Unauthorized, "set_strategy");
+        }
+        if info.name.len() > MAX_STRATEGY_NAME_LEN
+            || info.description.len() > MAX_STRATEGY_DESCRIPTION_LEN
+        {
+            fail(&e, CommitmentError::InvalidStrategy, "set_strategy");
+        }
+
+        e.storage()
+            .instance()
+            .set(&DataKey::Strategy(commitment_id.clone()), &info);
+        e.events().publish(
+            (Symbol::new(&e, "StrategySet"), commitment_id),
+            (info.name, caller, e.ledger().timestamp()),
+        );
+    }
+
+    /// Get the strategy descriptor of a commitment, if one was attached.
+    pub fn get_strategy(e: Env, commitment_id: String) -> Option<StrategyInfo> {
+        e.storage()
+            .instance()
+            .get::<_, StrategyInfo>(&DataKey::Strategy(commitment_id))
+    }
+
+    /// Export a commitment as its XDR encoding, for off-chain backup.
+    ///
+    /// The encoding is deterministic, so equal commitments always produce
+    /// equal bytes. Restore with `deserialize_commitment`.
+    pub fn serialize_commitment(e: Env, commitment_id: String) -> Bytes {
+        read_commitment(&e, &commitment_id)
+            .unwrap_or_else(|| {
+                fail(
+                    &e,
+                    CommitmentError::CommitmentNotFound,
+                    "serialize_commitment",
+                )
+            })
+            .to_xdr(&e)
+    }
+
+    /// Restore a commitment from `serialize_commitment` output (admin-only).
+    ///
+    /// XDR that does not decode to a valid commitment fails with
+    /// `InvalidCommitmentData`; bytes that are not XDR at all are rejected by
+    /// the host. The decoded record is validated, then stored under its own id,
+    /// replacing any existing record; the id and owner indexes are updated.
+    /// Funds, TVL and counters are not touched, so restoring is for
+    /// rebuilding records whose assets are already held by the contract.
+    /// Returns the restored commitment id.
+    pub fn deserialize_commitment(e: Env, admin: Address, bytes: Bytes) -> String {
+        require_admin(&e, &admin);
+        let commitment = Commitment::from_xdr(&e, &bytes).unwrap_or_else(|_| {
+            fail(
+                &e,
+                CommitmentError::InvalidCommitmentData,
+                "deserialize_commitment",
+            )
+        });
+
+        let valid_status = COMMITMENT_STATUSES
+            .iter()
+            .any(|status| commitment.status == String::from_str(&e, status));
+        if commitment.commitment_id.is_empty()
+            || commitment.amount <= 0
+            || commitment.current_value < 0
+            || commitment.expires_at < commitment.created_at
+            || !valid_status
+        {
+            fail(
+                &e,
+                CommitmentError::InvalidCommitmentData,
+                "deserialize_commitment",
+            );
+        }
+        Self::validate_rules(&e, &commitment.rules);
+
+        let commitment_id = commitment.commitment_id.clone();
+        match read_commitment(&e, &commitment_id) {
+            Some(existing) => {
+                if existing.owner != commitment.owner {
+                    remove_owner_commitment(&e, &existing.owner, &commitment_id);
+                    add_owner_commitment(&e, &commitment.owner, &commitment_id);
+                }
+            }
+            None => {
+                add_owner_commitment(&e, &commitment.owner, &commitment_id);
+                let mut all_ids = e
+                    .storage()
+                    .instance()
+                    .get::<_, Vec<String>>(&DataKey::AllCommitmentIds)
+                    .unwrap_or(Vec::new(&e));
+                all_ids.push_back(commitment_id.clone());
+                e.storage()
+                    .instance()
+                    .set(&DataKey::AllCommitmentIds, &all_ids);
+            }
+        }
+        set_commitment(&e, &commitment);
+
+        e.events().publish(
+            (symbol_short!("Restored"), commitment_id.clone()),
+            (admin, e.ledger().timestamp()),
+        );
+        commitment_id
+    }
+
+    /// Move a commitment to `new_owner` after its NFT changed hands.
+    ///
+    /// Only the configured NFT contract may call this; it does so from the
+    /// NFT transfer path so the commitment owner follows the token.
+    pub fn transfer_ownership(e: Env, commitment_id: String, new_owner: Address) {
+        let nft_contract = e
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::NftContract)
+            .unwrap_or_else(|| fail(&e, CommitmentError::NotInitialized, "transfer_ownership"));
+        nft_contract.require_auth();
+
+        let mut commitment = read_commitment(&e, &commitment_id).unwrap_or_else(|| {
+            fail(
+                &e,
+                CommitmentError::CommitmentNotFound,
+                "transfer_ownership",
+            )
+        });
+        let previous_owner = commitment.owner.clone();
+        if previous_owner == new_owner {
+            return;
+        }
+        commitment.owner = new_owner.clone();
+        set_commitment(&e, &commitment);
+
+        // Move the id between the owners' commitment lists
+        remove_owner_commitment(&e, &previous_owner, &commitment_id);
+        add_owner_commitment(&e, &new_owner, &commitment_id);
+
+        e.events().publish(
+            (symbol_short!("OwnerXfer"), commitment_id),
+            (previous_owner, new_owner, e.ledger().timestamp()),
+        );
+    }
+
     /// Update commitment value (called by allocation logic or oracle-fed keeper).
     /// Persists new_value to commitment.current_value and updates TotalValueLocked.
+    ///
+    /// Requires authorization from the commitment's value reporter, or from the
+    /// admin when no reporter is configured.
     pub fn update_value(e: Env, commitment_id: String, new_value: i128) {
         // Global per-function rate limit (per contract instance)
         let fn_symbol = symbol_short!("upd_val");
@@ -621,6 +2182,9 @@ impl CommitmentCoreContract {
         let mut commitment = read_commitment(&e, &commitment_id)
             .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "update_value"));
 
+        let reporter = value_reporter(&e, &commitment_id, "update_value");
+        reporter.require_auth();
+
         let active_status = String::from_str(&e, "active");
         if commitment.status != active_status {
             fail(&e, CommitmentError::NotActive, "update_value");
@@ -630,23 +2194,29 @@ impl CommitmentCoreContract {
         commitment.current_value = new_value;
 
         // Check for violations after updating value
-        let loss_percent = if commitment.amount > 0 {
-            SafeMath::loss_percent(commitment.amount, commitment.current_value)
-        } else {
-            0
-        };
+        let loss_percent = commitment_loss_percent(&e, &commitment);
         let max_loss = commitment.rules.max_loss_percent as i128;
         let loss_violated = loss_percent > max_loss;
 
         if loss_violated {
             commitment.status = String::from_str(&e, "violated");
-            set_commitment(&e, &commitment);
+        }
+        set_commitment(&e, &commitment);
+        emit_status_changed(&e, &commitment, active_status);
+        adjust_asset_risk(&e, &commitment.asset_address, 0, new_value - old_value);
+
+        // Emitted on every update so indexers can keep a value history;
+        // payload is (old_value, new_value, drawdown_percent, timestamp)
+        e.events().publish(
+            (Symbol::new(&e, "ValueUpdated"), commitment_id.clone()),
+            (old_value, new_value, loss_percent, e.ledger().timestamp()),
+        );
+        if loss_violated {
             e.events().publish(
-                (symbol_short!("Violated"), commitment_id.clone()),
+                (symbol_short!("Violated"), commitment_id),
                 (symbol_short!("RuleViol"), e.ledger().timestamp()),
             );
         } else {
-            set_commitment(&e, &commitment);
             e.events().publish(
                 (symbol_short!("ValUpd"), commitment_id),
                 (new_value, e.ledger().timestamp()),
@@ -659,10 +2229,104 @@ impl CommitmentCoreContract {
             .instance()
             .get::<_, i128>(&DataKey::TotalValueLocked)
             .unwrap_or(0);
-        let new_tvl = current_tvl - old_value + new_value;
+        let new_tvl = checked_add(&e, current_tvl, new_value - old_value, "update_value");
+        set_tvl(&e, new_tvl);
+    }
+
+    /// Overwrite a commitment's current value to undo a bad value report.
+    ///
+    /// Admin only, for active or violated commitments. Unlike `update_value`
+    /// the status is not re-evaluated; the previous value and `reason` are
+    /// appended to the commitment's correction log (at most
+    /// `MAX_VALUE_CORRECTIONS` entries) and `ValueCorrected` is emitted.
+    pub fn admin_correct_value(
+        e: Env,
+        admin: Address,
+        commitment_id: String,
+        corrected_value: i128,
+        reason: String,
+    ) {
+        require_admin(&e, &admin);
+        Validation::require_non_negative(corrected_value);
+        if reason.is_empty() || reason.len() > MAX_CORRECTION_REASON_LEN {
+            fail(&e, CommitmentError::InvalidReason, "admin_correct_value");
+        }
+        let mut commitment = read_commitment(&e, &commitment_id).unwrap_or_else(|| {
+            fail(
+                &e,
+                CommitmentError::CommitmentNotFound,
+                "admin_correct_value",
+            )
+        });
+        if commitment.status != String::from_str(&e, "active")
+            && commitment.status != String::from_str(&e, "violated")
+        {
+            fail(&e, CommitmentError::NotActive, "admin_correct_value");
+        }
+
+        let previous_value = commitment.current_value;
+        commitment.current_value = corrected_value;
+        set_commitment(&e, &commitment);
+        adjust_asset_risk(
+            &e,
+            &commitment.asset_address,
+            0,
+            corrected_value - previous_value,
+        );
+        let current_tvl = e
+            .storage()
+            .instance()
+            .get::<_, i128>(&DataKey::TotalValueLocked)
+            .unwrap_or(0);
+        let new_tvl = checked_add(
+            &e,
+            current_tvl,
+            corrected_value - previous_value,
+            "admin_correct_value",
+        );
+        set_tvl(&e, new_tvl);
+
+        let key = DataKey::ValueCorrections(commitment_id.clone());
+        let mut log = e
+            .storage()
+            .instance()
+            .get::<_, Vec<ValueCorrection>>(&key)
+            .unwrap_or_else(|| Vec::new(&e));
+        log.push_back(ValueCorrection {
+            previous_value,
+            corrected_value,
+            reason: reason.clone(),
+            admin: admin.clone(),
+            timestamp: e.ledger().timestamp(),
+        });
+        while log.len() > MAX_VALUE_CORRECTIONS {
+            log.pop_front();
+        }
+        e.storage().instance().set(&key, &log);
+
+        e.events().publish(
+            (Symbol::new(&e, "ValueCorrected"), commitment_id),
+            (
+                previous_value,
+                corrected_value,
+                reason,
+                admin,
+                e.ledger().timestamp(),
+            ),
+        );
+    }
+
+    /// Get the admin value corrections of a commitment, oldest first.
+    pub fn get_value_corrections(e: Env, commitment_id: String) -> Vec<ValueCorrection> {
         e.storage()
             .instance()
-            .set(&DataKey::TotalValueLocked, &new_tvl);
+            .get::<_, Vec<ValueCorrection>>(&DataKey::ValueCorrections(commitment_id))
+            .unwrap_or_else(|| Vec::new(&e))
+    }
+
+    /// Check whether a commitment with this ID exists
+    pub fn commitment_exists(e: Env, commitment_id: String) -> bool {
+        read_commitment(&e, &commitment_id).is_some()
     }
 
     /// Check if commitment rules are violated
@@ -694,15 +2358,8 @@ impl CommitmentCoreContract {
 
         let current_time = e.ledger().timestamp();
 
-        // Check loss limit violation
-        // Calculate loss percentage using shared utilities, but handle zero-amount
-        // commitments gracefully to avoid panics. A zero-amount commitment cannot
-        // meaningfully violate a loss limit, so we treat its loss percent as 0.
-        let loss_percent = if commitment.amount > 0 {
-            SafeMath::loss_percent(commitment.amount, commitment.current_value)
-        } else {
-            0
-        };
+        // Check loss limit violation
+        let loss_percent = commitment_loss_percent(&e, &commitment);
 
         // Convert max_loss_percent (u32) to i128 for comparison
         let max_loss = commitment.rules.max_loss_percent as i128;
@@ -739,12 +2396,7 @@ impl CommitmentCoreContract {
         let current_time = e.ledger().timestamp();
 
         // Calculate loss percentage
-        let loss_amount = commitment.amount - commitment.current_value;
-        let loss_percent = if commitment.amount > 0 {
-            (loss_amount * 100) / commitment.amount
-        } else {
-            0
-        };
+        let loss_percent = commitment_loss_percent(&e, &commitment);
 
         // Check loss limit violation
         let max_loss = commitment.rules.max_loss_percent as i128;
@@ -776,6 +2428,78 @@ impl CommitmentCoreContract {
     /// # Reentrancy Protection
     /// Uses checks-effects-interactions pattern with reentrancy guard.
     pub fn settle(e: Env, commitment_id: String) {
+        settle_commitment(&e, commitment_id, None, false, false);
+    }
+
+    /// Settle a matured commitment and burn its NFT in one call (owner-only).
+    ///
+    /// Behaves like `settle`, then has the NFT contract settle and burn the
+    /// token, so the owner also authorizes the NFT's `settle_and_burn`.
+    pub fn settle_and_burn(e: Env, commitment_id: String) {
+        let commitment = read_commitment(&e, &commitment_id)
+            .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "settle_and_burn"));
+        commitment.owner.require_auth();
+        settle_commitment(&e, commitment_id, None, false, true);
+    }
+
+    /// Record the closing value of a commitment and settle it in one step.
+    ///
+    /// `caller` must be the commitment's value reporter (the admin when none is
+    /// set), so no `update_value` can land between the final mark and the
+    /// payout. Emits `SettleVal` with the previous and final values before the
+    /// usual `Settled` event.
+    pub fn settle_with_value(e: Env, commitment_id: String, final_value: i128, caller: Address) {
+        caller.require_auth();
+        Validation::require_non_negative(final_value);
+        if caller != value_reporter(&e, &commitment_id, "settle_with_value") {
+            fail(&e, CommitmentError::Unauthorized, "settle_with_value");
+        }
+        settle_commitment(&e, commitment_id, Some(final_value), false, false);
+    }
+
+    /// Settle a stuck commitment at `final_value` (admin-only escape hatch).
+    ///
+    /// Skips the expiry and dispute checks of `settle`, so it also closes
+    /// commitments whose value reporter stopped reporting or whose dispute is
+    /// deadlocked. The commitment must be active or disputed. The final value
+    /// may not exceed the commitment's current value, so the payout stays
+    /// within its custody share; it is recorded as the commitment's value and
+    /// paid to the owner. Emits `SettleVal` and `Settled`, then `ForceSettled`
+    /// for auditability.
+    pub fn force_settle(e: Env, admin: Address, commitment_id: String, final_value: i128) {
+        require_admin(&e, &admin);
+        Validation::require_non_negative(final_value);
+        let previous_value = read_commitment(&e, &commitment_id)
+            .map(|c| c.current_value)
+            .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "force_settle"));
+        if final_value > previous_value {
+            fail(&e, CommitmentError::FinalValueTooHigh, "force_settle");
+        }
+        settle_commitment(&e, commitment_id.clone(), Some(final_value), true, false);
+        e.events().publish(
+            (Symbol::new(&e, "ForceSettled"), commitment_id),
+            (admin, previous_value, final_value, e.ledger().timestamp()),
+        );
+    }
+
+    /// Check whether `settle` would currently succeed for a commitment: it is
+    /// active (so not disputed), expired, and the contract is not paused.
+    ///
+    /// `grace_period_days` does not delay settlement, so it is not waited out.
+    pub fn can_settle(e: Env, commitment_id: String) -> bool {
+        if Pausable::is_paused(&e) {
+            return false;
+        }
+        match read_commitment(&e, &commitment_id) {
+            Some(commitment) => {
+                commitment.status == String::from_str(&e, "active")
+                    && e.ledger().timestamp() >= commitment.expires_at
+            }
+            None => false,
+        }
+    }
+
+    pub fn early_exit(e: Env, commitment_id: String, caller: Address) {
         // Reentrancy protection
         require_no_reentrancy(&e);
         set_reentrancy_guard(&e, true);
@@ -784,72 +2508,226 @@ impl CommitmentCoreContract {
         Pausable::require_not_paused(&e);
 
         // CHECKS: Get and validate commitment
-        let mut commitment = read_commitment(&e, &commitment_id).unwrap_or_else(|| {
+        let commitment = read_commitment(&e, &commitment_id).unwrap_or_else(|| {
             set_reentrancy_guard(&e, false);
-            fail(&e, CommitmentError::CommitmentNotFound, "settle")
+            fail(&e, CommitmentError::CommitmentNotFound, "early_exit")
         });
 
-        // Verify commitment is expired
-        let current_time = e.ledger().timestamp();
-        if current_time < commitment.expires_at {
+        // Verify caller is owner
+        caller.require_auth();
+        if commitment.owner != caller {
             set_reentrancy_guard(&e, false);
-            fail(&e, CommitmentError::NotExpired, "settle");
+            fail(&e, CommitmentError::Unauthorized, "early_exit");
         }
 
         // Verify commitment is active
+        require_not_disputed(&e, &commitment, "early_exit");
         let active_status = String::from_str(&e, "active");
         if commitment.status != active_status {
             set_reentrancy_guard(&e, false);
-            fail(&e, CommitmentError::NotActive, "settle");
+            fail(&e, CommitmentError::NotActive, "early_exit");
         }
 
-        // EFFECTS: Update state before external calls
-        let settlement_amount = commitment.current_value;
-        commitment.status = String::from_str(&e, "settled");
-        set_commitment(&e, &commitment);
+        let (penalty_amount, returned_amount) = exit_with_penalty(&e, commitment, "early_exit");
 
-        // Decrease total value locked
-        let current_tvl = e
-            .storage()
-            .instance()
-            .get::<_, i128>(&DataKey::TotalValueLocked)
-            .unwrap_or(0);
-        let new_tvl = current_tvl - settlement_amount;
+        // Clear reentrancy guard
+        set_reentrancy_guard(&e, false);
+
+        // Emit early exit event with detailed information
+        e.events().publish(
+            (
+                symbol_short!("EarlyExt"),
+                commitment_id.clone(),
+                caller.clone(),
+            ),
+            (penalty_amount, returned_amount, e.ledger().timestamp()),
+        );
+    }
+
+    /// Set a stop-loss on a commitment (owner only).
+    ///
+    /// Once its drawdown exceeds `stop_loss_bps`, anyone may call
+    /// `trigger_stop` to early-exit it on the owner's behalf. Zero removes
+    /// the stop.
+    pub fn set_auto_stop(e: Env, commitment_id: String, stop_loss_bps: u32, caller: Address) {
+        caller.require_auth();
+        let commitment = read_commitment(&e, &commitment_id)
+            .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "set_auto_stop"));
+        if commitment.owner != caller {
+            fail(&e, CommitmentError::Unauthorized, "set_auto_stop");
+        }
+        if stop_loss_bps > 10000 {
+            fail(&e, CommitmentError::InvalidStopLoss, "set_auto_stop");
+        }
+
+        let key = DataKey::AutoStop(commitment_id.clone());
+        if stop_loss_bps == 0 {
+            e.storage().instance().remove(&key);
+        } else {
+            e.storage().instance().set(&key, &stop_loss_bps);
+        }
+        e.events().publish(
+            (symbol_short!("AutoStop"), commitment_id),
+            (stop_loss_bps, e.ledger().timestamp()),
+        );
+    }
+
+    /// Get the stop-loss of a commitment in basis points (0 when unset).
+    pub fn get_auto_stop(e: Env, commitment_id: String) -> u32 {
         e.storage()
             .instance()
-            .set(&DataKey::TotalValueLocked, &new_tvl);
+            .get::<_, u32>(&DataKey::AutoStop(commitment_id))
+            .unwrap_or(0)
+    }
 
-        // INTERACTIONS: External calls (token transfer, NFT settlement)
-        // Transfer assets back to owner
-        let contract_address = e.current_contract_address();
-        let token_client = token::Client::new(&e, &commitment.asset_address);
-        token_client.transfer(&contract_address, &commitment.owner, &settlement_amount);
+    /// Early-exit a commitment whose drawdown exceeds its stop-loss (keeper entrypoint).
+    ///
+    /// Permissionless. Returns `true` after performing the exit with the
+    /// standard early-exit penalty and emitting `StopTriggered`; returns
+    /// `false` without changes when no stop is set or it has not been breached.
+    pub fn trigger_stop(e: Env, commitment_id: String) -> bool {
+        // Reentrancy protection
+        require_no_reentrancy(&e);
+        set_reentrancy_guard(&e, true);
 
-        // Call NFT contract to mark NFT as settled
-        let nft_contract = e
-            .storage()
-            .instance()
-            .get::<_, Address>(&DataKey::NftContract)
-            .unwrap_or_else(|| {
-                set_reentrancy_guard(&e, false);
-                fail(&e, CommitmentError::NotInitialized, "settle")
-            });
+        // Check if contract is paused
+        Pausable::require_not_paused(&e);
+
+        let commitment = read_commitment(&e, &commitment_id).unwrap_or_else(|| {
+            set_reentrancy_guard(&e, false);
+            fail(&e, CommitmentError::CommitmentNotFound, "trigger_stop")
+        });
+        require_not_disputed(&e, &commitment, "trigger_stop");
+        if commitment.status != String::from_str(&e, "active") {
+            set_reentrancy_guard(&e, false);
+            fail(&e, CommitmentError::NotActive, "trigger_stop");
+        }
+
+        let stop_loss_bps = Self::get_auto_stop(e.clone(), commitment_id.clone());
+        let drawdown_bps = if commitment.amount > 0 && commitment.current_value < commitment.amount
+        {
+            (commitment.amount - commitment.current_value) * 10000 / commitment.amount
+        } else {
+            0
+        };
+        if stop_loss_bps == 0 || drawdown_bps <= stop_loss_bps as i128 {
+            set_reentrancy_guard(&e, false);
+            return false;
+        }
 
-        let mut args = Vec::new(&e);
-        args.push_back(commitment.nft_token_id.into_val(&e));
-        e.invoke_contract::<()>(&nft_contract, &Symbol::new(&e, "settle"), args);
+        let owner = commitment.owner.clone();
+        let (penalty_amount, returned_amount) = exit_with_penalty(&e, commitment, "trigger_stop");
+        e.storage()
+            .instance()
+            .remove(&DataKey::AutoStop(commitment_id.clone()));
 
         // Clear reentrancy guard
         set_reentrancy_guard(&e, false);
 
-        // Emit settlement event
         e.events().publish(
-            (symbol_short!("Settled"), commitment_id),
-            (settlement_amount, e.ledger().timestamp()),
+            (Symbol::new(&e, "StopTriggered"), commitment_id, owner),
+            (
+                drawdown_bps as u32,
+                stop_loss_bps,
+                penalty_amount,
+                returned_amount,
+                e.ledger().timestamp(),
+            ),
         );
+        true
     }
 
-    pub fn early_exit(e: Env, commitment_id: String, caller: Address) {
+    /// Early-exit several of `owner`'s commitments with a single authorization.
+    ///
+    /// Each exit applies the commitment's early-exit penalty and `results`
+    /// holds the amount returned to the owner for every exited commitment,
+    /// in batch order. Eligibility is checked for the whole batch first: in
+    /// `Atomic` mode any unknown, non-owned, disputed or inactive commitment
+    /// aborts before anything is exited; in `BestEffort` mode such entries
+    /// are reported in `errors` and the rest are exited.
+    pub fn batch_early_exit(
+        e: Env,
+        owner: Address,
+        commitment_ids: Vec<String>,
+        mode: BatchMode,
+    ) -> BatchResultI128 {
+        // Reentrancy protection
+        require_no_reentrancy(&e);
+        set_reentrancy_guard(&e, true);
+
+        // Check if contract is paused
+        Pausable::require_not_paused(&e);
+
+        owner.require_auth();
+
+        // Validate batch size
+        let batch_size = commitment_ids.len();
+        let contract_name = String::from_str(&e, "commitment_core");
+        if let Err(error_code) =
+            BatchProcessor::enforce_batch_limits(&e, batch_size, Some(contract_name.clone()))
+        {
+            set_reentrancy_guard(&e, false);
+            BatchProcessor::emit_batch_rejected(&e, batch_size, Some(contract_name), error_code);
+            let mut errors = Vec::new(&e);
+            errors.push_back(BatchError {
+                index: 0,
+                error_code,
+                context: String::from_str(&e, "batch_size_validation"),
+            });
+            return BatchResultI128::failure(&e, errors).with_stats(batch_size, 0);
+        }
+
+        // CHECKS: validate every entry before any funds move
+        let mut errors = Vec::new(&e);
+        let mut eligible: Vec<Commitment> = Vec::new(&e);
+        let mut claimed: Vec<String> = Vec::new(&e);
+        for i in 0..batch_size {
+            let commitment_id = commitment_ids.get(i).unwrap();
+            match batch_exit_candidate(&e, &commitment_id, &owner, &claimed) {
+                Ok(commitment) => {
+                    claimed.push_back(commitment_id);
+                    eligible.push_back(commitment);
+                }
+                Err((err, context)) => {
+                    errors.push_back(BatchError {
+                        index: i,
+                        error_code: err as u32,
+                        context: String::from_str(&e, context),
+                    });
+                    if mode == BatchMode::Atomic {
+                        set_reentrancy_guard(&e, false);
+                        return BatchResultI128::failure(&e, errors).with_stats(batch_size, i + 1);
+                    }
+                }
+            }
+        }
+
+        // EFFECTS + INTERACTIONS: exit each eligible commitment
+        let mut results = Vec::new(&e);
+        for commitment in eligible.iter() {
+            let commitment_id = commitment.commitment_id.clone();
+            let (penalty_amount, returned_amount) =
+                exit_with_penalty(&e, commitment, "batch_early_exit");
+            results.push_back(returned_amount);
+
+            e.events().publish(
+                (symbol_short!("EarlyExt"), commitment_id, owner.clone()),
+                (penalty_amount, returned_amount, e.ledger().timestamp()),
+            );
+        }
+
+        // Clear reentrancy guard
+        set_reentrancy_guard(&e, false);
+
+        BatchResultI128::partial(results, errors)
+    }
+
+    /// Exit `fraction_bps` (1-9999) of a commitment before maturity.
+    ///
+    /// Principal and current value shrink by the fraction; the early-exit
+    /// penalty applies to the exited value only and the commitment stays
+    /// active with the remainder. Use `early_exit` to leave entirely.
+    pub fn partial_exit(e: Env, commitment_id: String, fraction_bps: u32, caller: Address) {
         // Reentrancy protection
         require_no_reentrancy(&e);
         set_reentrancy_guard(&e, true);
@@ -857,85 +2735,145 @@ impl CommitmentCoreContract {
         // Check if contract is paused
         Pausable::require_not_paused(&e);
 
+        if fraction_bps == 0 || fraction_bps >= 10000 {
+            set_reentrancy_guard(&e, false);
+            fail(&e, CommitmentError::InvalidFraction, "partial_exit");
+        }
+
         // CHECKS: Get and validate commitment
         let mut commitment = read_commitment(&e, &commitment_id).unwrap_or_else(|| {
             set_reentrancy_guard(&e, false);
-            fail(&e, CommitmentError::CommitmentNotFound, "early_exit")
+            fail(&e, CommitmentError::CommitmentNotFound, "partial_exit")
         });
 
         // Verify caller is owner
         caller.require_auth();
         if commitment.owner != caller {
             set_reentrancy_guard(&e, false);
-            fail(&e, CommitmentError::Unauthorized, "early_exit");
+            fail(&e, CommitmentError::Unauthorized, "partial_exit");
         }
 
         // Verify commitment is active
+        require_not_disputed(&e, &commitment, "partial_exit");
         let active_status = String::from_str(&e, "active");
         if commitment.status != active_status {
             set_reentrancy_guard(&e, false);
-            fail(&e, CommitmentError::NotActive, "early_exit");
+            fail(&e, CommitmentError::NotActive, "partial_exit");
         }
 
-        // EFFECTS: Calculate penalty using shared utilities
-        let penalty_amount = SafeMath::penalty_amount(
-            commitment.current_value,
-            commitment.rules.early_exit_penalty,
-        );
-        let returned_amount = SafeMath::sub(commitment.current_value, penalty_amount);
-        let original_value = commitment.current_value;
+        // EFFECTS: Shrink the commitment by the exited fraction
+        let fraction = fraction_bps as i128;
+        let exited_amount = SafeMath::div(SafeMath::mul(commitment.amount, fraction), 10000);
+        let exited_value = SafeMath::div(SafeMath::mul(commitment.current_value, fraction), 10000);
+        let (penalty_amount, returned_amount) =
+            calculate_exit_payout(&e, &commitment, exited_value);
 
-        // Update commitment status to early_exit
-        commitment.status = String::from_str(&e, "early_exit");
-        commitment.current_value = 0; // All value has been distributed
+        commitment.amount = SafeMath::sub(commitment.amount, exited_amount);
+        commitment.current_value = SafeMath::sub(commitment.current_value, exited_value);
         set_commitment(&e, &commitment);
+        adjust_asset_risk(&e, &commitment.asset_address, -exited_amount, -exited_value);
 
-        // Decrease total value locked by full current value (no longer locked)
         let current_tvl = e
             .storage()
             .instance()
             .get::<_, i128>(&DataKey::TotalValueLocked)
             .unwrap_or(0);
-        let new_tvl = current_tvl - original_value;
-        e.storage()
-            .instance()
-            .set(&DataKey::TotalValueLocked, &new_tvl);
-
-        // INTERACTIONS: External calls (token transfer)
-        // Transfer remaining amount (after penalty) to owner
-        let contract_address = e.current_contract_address();
-        let token_client = token::Client::new(&e, &commitment.asset_address);
+        let new_tvl = checked_sub(&e, current_tvl, exited_value, "partial_exit");
+        set_tvl(&e, new_tvl);
 
+        // INTERACTIONS: Transfer the exited value (after penalty) to owner
         if returned_amount > 0 {
-            token_client.transfer(&contract_address, &commitment.owner, &returned_amount);
+            pay_owner(&e, &commitment, returned_amount, "partial_exit");
         }
 
-        // Call NFT contract to mark as inactive (early exited, not settled)
-        let nft_contract = e
+        // Clear reentrancy guard
+        set_reentrancy_guard(&e, false);
+
+        // Emit partial exit receipt
+        e.events().publish(
+            (symbol_short!("PartExit"), commitment_id, caller),
+            (
+                fraction_bps,
+                exited_amount,
+                penalty_amount,
+                returned_amount,
+                e.ledger().timestamp(),
+            ),
+        );
+    }
+
+    /// Put an active commitment under dispute, blocking `settle`,
+    /// `early_exit` and `partial_exit` until the admin resolves it.
+    ///
+    /// Callable by the commitment owner, its value reporter or the admin.
+    pub fn open_dispute(e: Env, commitment_id: String, caller: Address) {
+        caller.require_auth();
+        let mut commitment = read_commitment(&e, &commitment_id)
+            .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "open_dispute"));
+
+        let admin = e
             .storage()
             .instance()
-            .get::<_, Address>(&DataKey::NftContract)
-            .unwrap_or_else(|| {
-                set_reentrancy_guard(&e, false);
-                fail(&e, CommitmentError::NotInitialized, "early_exit")
-            });
-        
-        // Call mark_inactive on NFT instead of settle (since not expired)
-        let mut args = Vec::new(&e);
-        args.push_back(commitment.nft_token_id.into_val(&e));
-        e.invoke_contract::<()>(&nft_contract, &Symbol::new(&e, "mark_inactive"), args);
+            .get::<_, Address>(&DataKey::Admin)
+            .unwrap_or_else(|| fail(&e, CommitmentError::NotInitialized, "open_dispute"));
+        let reporter = e
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::ValueReporter(commitment_id.clone()));
+        if caller != commitment.owner && caller != admin && Some(caller.clone()) != reporter {
+            fail(&e, CommitmentError::Unauthorized, "open_dispute");
+        }
+        if commitment.status != String::from_str(&e, "active") {
+            fail(&e, CommitmentError::NotActive, "open_dispute");
+        }
 
-        // Clear reentrancy guard
-        set_reentrancy_guard(&e, false);
+        commitment.status = String::from_str(&e, "disputed");
+        set_commitment(&e, &commitment);
+        emit_status_changed(&e, &commitment, String::from_str(&e, "active"));
+        e.events().publish(
+            (symbol_short!("Disputed"), commitment_id, caller),
+            e.ledger().timestamp(),
+        );
+    }
 
-        // Emit early exit event with detailed information
+    /// Resolve an open dispute (admin only).
+    ///
+    /// With `uphold` the dispute stands and the commitment is closed as an
+    /// early exit, penalty applied; otherwise it returns to `active`.
+    pub fn resolve_dispute(e: Env, admin: Address, commitment_id: String, uphold: bool) {
+        require_admin(&e, &admin);
+
+        // Reentrancy protection
+        require_no_reentrancy(&e);
+        set_reentrancy_guard(&e, true);
+
+        let mut commitment = read_commitment(&e, &commitment_id).unwrap_or_else(|| {
+            set_reentrancy_guard(&e, false);
+            fail(&e, CommitmentError::CommitmentNotFound, "resolve_dispute")
+        });
+        if commitment.status != String::from_str(&e, "disputed") {
+            set_reentrancy_guard(&e, false);
+            fail(&e, CommitmentError::InvalidStatus, "resolve_dispute");
+        }
+
+        let (penalty_amount, returned_amount) = if uphold {
+            exit_with_penalty(&e, commitment, "resolve_dispute")
+        } else {
+            commitment.status = String::from_str(&e, "active");
+            set_commitment(&e, &commitment);
+            emit_status_changed(&e, &commitment, String::from_str(&e, "disputed"));
+            (0, 0)
+        };
+
+        set_reentrancy_guard(&e, false);
         e.events().publish(
+            (symbol_short!("DispRes"), commitment_id),
             (
-                symbol_short!("EarlyExt"),
-                commitment_id.clone(),
-                caller.clone(),
+                uphold,
+                penalty_amount,
+                returned_amount,
+                e.ledger().timestamp(),
             ),
-            (penalty_amount, returned_amount, e.ledger().timestamp()),
         );
     }
 
@@ -981,14 +2919,17 @@ impl CommitmentCoreContract {
 
         // EFFECTS: Update commitment value before external call
         let mut updated_commitment = commitment;
-        updated_commitment.current_value = updated_commitment.current_value - amount;
+        updated_commitment.current_value =
+            checked_sub(&e, updated_commitment.current_value, amount, "allocate");
         set_commitment(&e, &updated_commitment);
+        adjust_asset_risk(&e, &updated_commitment.asset_address, 0, -amount);
 
         // INTERACTIONS: External call (token transfer)
         // Transfer assets to target pool
         let contract_address = e.current_contract_address();
         let token_client = token::Client::new(&e, &updated_commitment.asset_address);
         token_client.transfer(&contract_address, &target_pool, &amount);
+        adjust_custody(&e, &updated_commitment.asset_address, -amount);
 
         // Clear reentrancy guard
         set_reentrancy_guard(&e, false);
@@ -1000,6 +2941,144 @@ impl CommitmentCoreContract {
         );
     }
 
+    /// Recover tokens sent directly to the contract (admin-only).
+    ///
+    /// Only the balance above what the contract holds through commitment flows
+    /// (deposits, minus payouts and allocations; early-exit penalties stay in
+    /// custody) can be moved, so committed assets are never touched.
+    /// Deployments upgraded from a version without custody tracking must run
+    /// `seed_custody` for the asset first.
+    pub fn rescue_tokens(
+        e: Env,
+        admin: Address,
+        token_contract: Address,
+        to: Address,
+        amount: i128,
+    ) {
+        require_admin(&e, &admin);
+        if amount <= 0 {
+            fail(&e, CommitmentError::InvalidAmount, "rescue_tokens");
+        }
+        let custody_known = e.storage().instance().has(&DataKey::CustodyTracked)
+            || e.storage()
+                .instance()
+                .has(&DataKey::CustodySeeded(token_contract.clone()));
+        if !custody_known {
+            fail(&e, CommitmentError::CustodyNotSeeded, "rescue_tokens");
+        }
+        require_no_reentrancy(&e);
+        set_reentrancy_guard(&e, true);
+
+        let contract_address = e.current_contract_address();
+        let token_client = token::Client::new(&e, &token_contract);
+        let custody = e
+            .storage()
+            .instance()
+            .get::<_, i128>(&DataKey::AssetCustody(token_contract.clone()))
+            .unwrap_or(0);
+        let rescuable = checked_sub(
+            &e,
+            token_client.balance(&contract_address),
+            custody,
+            "rescue_tokens",
+        );
+        if amount > rescuable {
+            set_reentrancy_guard(&e, false);
+            fail(&e, CommitmentError::InsufficientBalance, "rescue_tokens");
+        }
+
+        token_client.transfer(&contract_address, &to, &amount);
+        set_reentrancy_guard(&e, false);
+
+        e.events().publish(
+            (symbol_short!("Rescued"), token_contract),
+            (to, amount, e.ledger().timestamp()),
+        );
+    }
+
+    /// Recompute the custody of `asset` from stored commitments (admin only).
+    ///
+    /// Deposits made before custody tracking existed are missing from it, so
+    /// `rescue_tokens` refuses an upgraded deployment's assets until this has
+    /// run once per asset after `migrate` has finished. Custody is set to the
+    /// current value of the asset's open commitments; penalties retained
+    /// before seeding are not counted. Gas cost is O(n) in total
+    /// commitments.
+    pub fn seed_custody(e: Env, admin: Address, asset: Address) -> i128 {
+        require_admin(&e, &admin);
+        let (_, custody) = scan_open_exposure(&e, &asset);
+        e.storage()
+            .instance()
+            .set(&DataKey::AssetCustody(asset.clone()), &custody);
+        e.storage()
+            .instance()
+            .set(&DataKey::CustodySeeded(asset.clone()), &true);
+        e.events().publish(
+            (Symbol::new(&e, "CustodySeeded"), asset),
+            (custody, e.ledger().timestamp()),
+        );
+        custody
+    }
+
+    /// Rewrite stored commitments into the current layout.
+    ///
+    /// Walks `AllCommitmentIds` starting at the stored cursor and processes at most
+    /// `MIGRATION_BATCH_SIZE` records per call, so large stores can be migrated across
+    /// multiple transactions. Records already in the current layout are left untouched,
+    /// making repeated calls safe.
+    ///
+    /// # Returns
+    /// Number of records upgraded by this call.
+    pub fn migrate(e: Env, admin: Address) -> u32 {
+        require_admin(&e, &admin);
+
+        let all_ids = e
+            .storage()
+            .instance()
+            .get::<_, Vec<String>>(&DataKey::AllCommitmentIds)
+            .unwrap_or(Vec::new(&e));
+        let total = all_ids.len();
+        let start = e
+            .storage()
+            .instance()
+            .get::<_, u32>(&DataKey::MigrationCursor)
+            .unwrap_or(0)
+            .min(total);
+        let end = start.saturating_add(MIGRATION_BATCH_SIZE).min(total);
+
+        let mut migrated: u32 = 0;
+        for i in start..end {
+            let commitment_id = all_ids.get(i).unwrap();
+            let key = DataKey::Commitment(commitment_id);
+            let Some(raw) = e.storage().instance().get::<_, Map<Symbol, Val>>(&key) else {
+                continue;
+            };
+            if !is_legacy_layout(&e, &raw) {
+                continue;
+            }
+            if let Ok(legacy) = LegacyCommitment::try_from_val(&e, &raw.to_val()) {
+                set_commitment(&e, &legacy.upgrade());
+                migrated += 1;
+            }
+        }
+
+        e.storage().instance().set(&DataKey::MigrationCursor, &end);
+        e.events().publish(
+            (symbol_short!("Migrated"),),
+            (migrated, end, total, e.ledger().timestamp()),
+        );
+        migrated
+    }
+
+    /// Get the index at which the next `migrate` call resumes.
+    /// Migration is complete when this equals the number of commitments.
+    pub fn get_migration_cursor(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get::<_, u32>(&DataKey::MigrationCursor)
+            .unwrap_or(0)
+    }
+
     /// Configure rate limits for this contract's functions.
     ///
     /// This function is restricted to the contract admin.
@@ -5,8 +5,8 @@ use shared_utils::TimeUtils;
 use soroban_sdk::{
     contract, contractimpl, symbol_short,
     testutils::{Address as _, Events, Ledger},
-    token::StellarAssetClient,
-    vec, Address, Env, IntoVal, String,
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, Env, IntoVal, String, TryFromVal,
 };
 
 #[contract]
@@ -16,6 +16,7 @@ struct MockNftContract;
 impl MockNftContract {
     pub fn mint(
         _e: Env,
+        _caller: Address,
         _owner: Address,
         _commitment_id: String,
         _duration_days: u32,
@@ -27,6 +28,10 @@ impl MockNftContract {
     ) -> u32 {
         1
     }
+
+    pub fn settle(_e: Env, _token_id: u32) {}
+
+    pub fn mark_inactive(_e: Env, _token_id: u32) {}
 }
 
 fn test_rules(e: &Env) -> CommitmentRules {
@@ -40,6 +45,26 @@ fn test_rules(e: &Env) -> CommitmentRules {
     }
 }
 
+/// Core wired to a mock NFT contract, with a fresh asset of which `balance`
+/// is minted to a new owner. Returns (client, admin, owner, asset).
+fn setup_core(
+    e: &Env,
+    balance: i128,
+) -> (CommitmentCoreContractClient<'_>, Address, Address, Address) {
+    e.mock_all_auths_allowing_non_root_auth();
+    let contract_id = e.register_contract(None, CommitmentCoreContract);
+    let client = CommitmentCoreContractClient::new(e, &contract_id);
+    let nft_contract = e.register_contract(None, MockNftContract);
+    let admin = Address::generate(e);
+    let owner = Address::generate(e);
+    let asset = e
+        .register_stellar_asset_contract_v2(Address::generate(e))
+        .address();
+    client.initialize(&admin, &nft_contract);
+    StellarAssetClient::new(e, &asset).mint(&owner, &balance);
+    (client, admin, owner, asset)
+}
+
 // Helper function to create a test commitment
 // ===============================
 // Boundary Tests for i128 Amounts
@@ -738,6 +763,17 @@ fn test_get_admin() {
     assert_eq!(retrieved_admin, admin);
 }
 
+#[test]
+fn test_is_initialized_reflects_initialize() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, CommitmentCoreContract);
+    let client = CommitmentCoreContractClient::new(&e, &contract_id);
+
+    assert!(!client.is_initialized());
+    client.initialize(&Address::generate(&e), &Address::generate(&e));
+    assert!(client.is_initialized());
+}
+
 #[test]
 fn test_get_nft_contract() {
     let e = Env::default();
@@ -2176,6 +2212,70 @@ fn test_update_value_triggers_violation() {
     assert!(has_violation, "ViolationDetected event should be emitted");
 }
 
+/// Find the `ValueUpdated` event for a commitment and decode its payload.
+fn value_updated_payload(e: &Env, commitment_id: &str) -> Option<(i128, i128, i128, u64)> {
+    let topics: Vec<Val> = (
+        Symbol::new(e, "ValueUpdated"),
+        String::from_str(e, commitment_id),
+    )
+        .into_val(e);
+    e.events()
+        .all()
+        .iter()
+        .find(|ev| ev.1 == topics)
+        .map(|ev| <(i128, i128, i128, u64)>::try_from_val(e, &ev.2).unwrap())
+}
+
+#[test]
+fn test_update_value_emits_value_updated_payload() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|l| l.timestamp = 5000);
+    let contract_id = e.register_contract(None, CommitmentCoreContract);
+    let owner = Address::generate(&e);
+    e.as_contract(&contract_id, || {
+        CommitmentCoreContract::initialize(e.clone(), Address::generate(&e), Address::generate(&e));
+        let commitment = create_test_commitment(&e, "test_id", &owner, 1000, 1000, 10, 30, 1000);
+        set_commitment(&e, &commitment);
+    });
+    let client = CommitmentCoreContractClient::new(&e, &contract_id);
+    client.update_value(&String::from_str(&e, "test_id"), &950);
+
+    assert_eq!(
+        value_updated_payload(&e, "test_id"),
+        Some((1000, 950, 5, 5000))
+    );
+    // The legacy ValUpd payload stays (new_value, timestamp)
+    let topics: Vec<Val> = (symbol_short!("ValUpd"), String::from_str(&e, "test_id")).into_val(&e);
+    let legacy = e
+        .events()
+        .all()
+        .iter()
+        .find(|ev| ev.1 == topics)
+        .map(|ev| <(i128, u64)>::try_from_val(&e, &ev.2).unwrap());
+    assert_eq!(legacy, Some((950, 5000)));
+}
+
+#[test]
+fn test_update_value_emits_value_updated_on_violation() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_id = e.register_contract(None, CommitmentCoreContract);
+    let owner = Address::generate(&e);
+    e.as_contract(&contract_id, || {
+        CommitmentCoreContract::initialize(e.clone(), Address::generate(&e), Address::generate(&e));
+        let commitment = create_test_commitment(&e, "test_id", &owner, 1000, 1000, 10, 30, 1000);
+        set_commitment(&e, &commitment);
+    });
+    let client = CommitmentCoreContractClient::new(&e, &contract_id);
+    client.update_value(&String::from_str(&e, "test_id"), &850);
+
+    let (old_value, new_value, drawdown, _) = value_updated_payload(&e, "test_id").unwrap();
+    assert_eq!((old_value, new_value, drawdown), (1000, 850, 15));
+    let commitment = client.get_commitment(&String::from_str(&e, "test_id"));
+    assert_eq!(commitment.status, String::from_str(&e, "violated"));
+}
+
 #[test]
 fn test_check_violations_after_update_value() {
     let e = Env::default();
@@ -2344,3 +2444,1824 @@ fn test_owner_multiple_commitments_settle_one() {
     let c3 = client.get_commitment(&String::from_str(&e, "commit_003"));
     assert_eq!(c3.status, String::from_str(&e, "active"));
 }
+
+// ============================================
+// Migration Tests
+// ============================================
+
+/// Seed a commitment stored in the pre-`grace_period_days` layout.
+fn store_legacy_commitment(e: &Env, contract_id: &Address, commitment_id: &String) {
+    let legacy = LegacyCommitment {
+        commitment_id: commitment_id.clone(),
+        owner: Address::generate(e),
+        nft_token_id: 1,
+        rules: LegacyCommitmentRules {
+            duration_days: 30,
+            max_loss_percent: 10,
+            commitment_type: String::from_str(e, "balanced"),
+            early_exit_penalty: 5,
+            min_fee_threshold: 100,
+        },
+        amount: 1000,
+        asset_address: Address::generate(e),
+        created_at: 0,
+        expires_at: 30 * 86400,
+        current_value: 1000,
+        status: String::from_str(e, "active"),
+    };
+    e.as_contract(contract_id, || {
+        e.storage()
+            .instance()
+            .set(&DataKey::Commitment(commitment_id.clone()), &legacy);
+        let mut all_ids = e
+            .storage()
+            .instance()
+            .get::<_, Vec<String>>(&DataKey::AllCommitmentIds)
+            .unwrap_or(Vec::new(e));
+        all_ids.push_back(commitment_id.clone());
+        e.storage()
+            .instance()
+            .set(&DataKey::AllCommitmentIds, &all_ids);
+    });
+}
+
+#[test]
+fn test_migrate_upgrades_legacy_commitment() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_id = e.register_contract(None, CommitmentCoreContract);
+    let client = CommitmentCoreContractClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    client.initialize(&admin, &Address::generate(&e));
+
+    let legacy_id = String::from_str(&e, "c_legacy");
+    store_legacy_commitment(&e, &contract_id, &legacy_id);
+    let current = create_test_commitment(&e, "c_current", &admin, 500, 500, 10, 30, 0);
+    store_commitment(&e, &contract_id, &current);
+    e.as_contract(&contract_id, || {
+        let mut all_ids: Vec<String> = e
+            .storage()
+            .instance()
+            .get(&DataKey::AllCommitmentIds)
+            .unwrap();
+        all_ids.push_back(current.commitment_id.clone());
+        e.storage()
+            .instance()
+            .set(&DataKey::AllCommitmentIds, &all_ids);
+    });
+
+    assert_eq!(client.migrate(&admin), 1);
+    assert_eq!(client.get_migration_cursor(), 2);
+
+    let upgraded = client.get_commitment(&legacy_id);
+    assert_eq!(upgraded.rules.grace_period_days, 0);
+    assert_eq!(upgraded.rules.max_loss_percent, 10);
+    assert_eq!(upgraded.amount, 1000);
+    assert_eq!(client.get_commitment(&current.commitment_id), current);
+
+    // Idempotent: a second run has nothing left to upgrade
+    assert_eq!(client.migrate(&admin), 0);
+}
+
+#[test]
+fn test_migrate_resumes_from_cursor() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_id = e.register_contract(None, CommitmentCoreContract);
+    let client = CommitmentCoreContractClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    client.initialize(&admin, &Address::generate(&e));
+
+    let total = MIGRATION_BATCH_SIZE + 3;
+    for i in 0..total {
+        let id = CommitmentCoreContract::generate_commitment_id(&e, i as u64);
+        store_legacy_commitment(&e, &contract_id, &id);
+    }
+
+    // Each migrate call runs in its own transaction budget
+    e.budget().reset_default();
+    assert_eq!(client.migrate(&admin), MIGRATION_BATCH_SIZE);
+    assert_eq!(client.get_migration_cursor(), MIGRATION_BATCH_SIZE);
+    e.budget().reset_default();
+    assert_eq!(client.migrate(&admin), 3);
+    assert_eq!(client.get_migration_cursor(), total);
+
+    let last = CommitmentCoreContract::generate_commitment_id(&e, (total - 1) as u64);
+    assert_eq!(client.get_commitment(&last).rules.grace_period_days, 0);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller not allowed")]
+fn test_migrate_non_admin_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_id = e.register_contract(None, CommitmentCoreContract);
+    let client = CommitmentCoreContractClient::new(&e, &contract_id);
+    client.initialize(&Address::generate(&e), &Address::generate(&e));
+
+    client.migrate(&Address::generate(&e));
+}
+
+// ============================================
+// Reentrancy Guard Diagnostics Tests
+// ============================================
+
+#[test]
+fn test_force_unlock_clears_stuck_guard() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_id = e.register_contract(None, CommitmentCoreContract);
+    let client = CommitmentCoreContractClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    client.initialize(&admin, &Address::generate(&e));
+    assert!(!client.is_locked());
+
+    e.as_contract(&contract_id, || set_reentrancy_guard(&e, true));
+    assert!(client.is_locked());
+
+    client.force_unlock(&admin);
+    assert!(!client.is_locked());
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller not allowed")]
+fn test_force_unlock_non_admin_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_id = e.register_contract(None, CommitmentCoreContract);
+    let client = CommitmentCoreContractClient::new(&e, &contract_id);
+    client.initialize(&Address::generate(&e), &Address::generate(&e));
+    e.as_contract(&contract_id, || set_reentrancy_guard(&e, true));
+
+    client.force_unlock(&Address::generate(&e));
+}
+
+/// Initialize a core contract and schedule its admin to relinquish at `effective_at`.
+fn setup_relinquish(e: &Env, effective_at: u64) -> (CommitmentCoreContractClient<'_>, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register_contract(None, CommitmentCoreContract);
+    let client = CommitmentCoreContractClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin, &Address::generate(e));
+    client.schedule_relinquish(&admin, &effective_at);
+    (client, admin)
+}
+
+#[test]
+#[should_panic(expected = "Admin relinquish not scheduled or not yet effective")]
+fn test_finalize_relinquish_before_effective_time_fails() {
+    let e = Env::default();
+    e.ledger().with_mut(|l| l.timestamp = 1000);
+    let (client, _admin) = setup_relinquish(&e, 2000);
+    e.ledger().with_mut(|l| l.timestamp = 1999);
+
+    client.finalize_relinquish();
+}
+
+#[test]
+#[should_panic(expected = "Admin relinquish not scheduled or not yet effective")]
+fn test_cancel_relinquish_prevents_finalize() {
+    let e = Env::default();
+    e.ledger().with_mut(|l| l.timestamp = 1000);
+    let (client, admin) = setup_relinquish(&e, 2000);
+    assert_eq!(client.get_relinquish_time(), Some(2000));
+
+    client.cancel_relinquish(&admin);
+    assert_eq!(client.get_relinquish_time(), None);
+    assert_eq!(client.get_admin(), admin);
+
+    e.ledger().with_mut(|l| l.timestamp = 3000);
+    client.finalize_relinquish();
+}
+
+#[test]
+#[should_panic(expected = "Contract not initialized")]
+fn test_finalize_relinquish_disables_admin_functions() {
+    let e = Env::default();
+    e.ledger().with_mut(|l| l.timestamp = 1000);
+    let (client, admin) = setup_relinquish(&e, 2000);
+    e.ledger().with_mut(|l| l.timestamp = 2000);
+
+    client.finalize_relinquish();
+    assert!(client.is_initialized());
+    assert_eq!(client.get_relinquish_time(), None);
+
+    client.set_min_amount(&admin, &10);
+}
+
+#[test]
+#[should_panic(expected = "Contract already initialized")]
+fn test_relinquished_contract_cannot_be_reinitialized() {
+    let e = Env::default();
+    e.ledger().with_mut(|l| l.timestamp = 1000);
+    let (client, _admin) = setup_relinquish(&e, 2000);
+    e.ledger().with_mut(|l| l.timestamp = 2000);
+    client.finalize_relinquish();
+
+    client.initialize(&Address::generate(&e), &Address::generate(&e));
+}
+
+#[test]
+#[should_panic(expected = "Effective time must be in the future")]
+fn test_schedule_relinquish_in_past_fails() {
+    let e = Env::default();
+    e.ledger().with_mut(|l| l.timestamp = 1000);
+    setup_relinquish(&e, 1000);
+}
+
+// ============================================
+// Type Distribution Tests
+// ============================================
+
+fn rules_of_type(e: &Env, commitment_type: &str) -> CommitmentRules {
+    let mut rules = test_rules(e);
+    rules.commitment_type = String::from_str(e, commitment_type);
+    rules
+}
+
+fn type_count(distribution: &Vec<(String, u32)>, e: &Env, commitment_type: &str) -> u32 {
+    let commitment_type = String::from_str(e, commitment_type);
+    distribution
+        .iter()
+        .find(|(t, _)| *t == commitment_type)
+        .map(|(_, count)| count)
+        .unwrap()
+}
+
+#[test]
+fn test_type_distribution_tracks_create_settle_and_exit() {
+    let e = Env::default();
+    let (client, _admin, owner, asset_address) = setup_core(&e, 10_000);
+
+    let empty = client.get_type_distribution();
+    assert_eq!(empty.len(), 3);
+    assert_eq!(type_count(&empty, &e, "safe"), 0);
+
+    let safe_id =
+        client.create_commitment(&owner, &1000, &asset_address, &rules_of_type(&e, "safe"));
+    client.create_commitment(&owner, &1000, &asset_address, &rules_of_type(&e, "safe"));
+    client.create_commitment(
+        &owner,
+        &1000,
+        &asset_address,
+        &rules_of_type(&e, "balanced"),
+    );
+    let aggressive_id = client.create_commitment(
+        &owner,
+        &1000,
+        &asset_address,
+        &rules_of_type(&e, "aggressive"),
+    );
+
+    let distribution = client.get_type_distribution();
+    assert_eq!(type_count(&distribution, &e, "safe"), 2);
+    assert_eq!(type_count(&distribution, &e, "balanced"), 1);
+    assert_eq!(type_count(&distribution, &e, "aggressive"), 1);
+
+    // Terminal commitments are excluded
+    client.early_exit(&safe_id, &owner);
+    e.ledger().with_mut(|l| l.timestamp += 31 * 86400);
+    client.settle(&aggressive_id);
+
+    let distribution = client.get_type_distribution();
+    assert_eq!(type_count(&distribution, &e, "safe"), 1);
+    assert_eq!(type_count(&distribution, &e, "balanced"), 1);
+    assert_eq!(type_count(&distribution, &e, "aggressive"), 0);
+}
+
+#[test]
+fn test_create_commitment_full_returns_stored_commitment() {
+    let e = Env::default();
+    e.ledger().with_mut(|l| l.timestamp = 5_000);
+    let (client, _admin, owner, asset_address) = setup_core(&e, 5_000);
+
+    let rules = test_rules(&e);
+    let created = client.create_commitment_full(&owner, &1_000, &asset_address, &rules);
+
+    assert_eq!(created.created_at, 5_000);
+    assert_eq!(
+        created.expires_at,
+        5_000 + rules.duration_days as u64 * 86400
+    );
+    assert_eq!(created.current_value, 1_000);
+    assert_eq!(created.nft_token_id, 1);
+    assert_eq!(created.status, String::from_str(&e, "active"));
+    assert_eq!(client.get_commitment(&created.commitment_id), created);
+}
+
+fn setup_idempotency_test(e: &Env) -> (CommitmentCoreContractClient<'_>, Address, Address) {
+    let (client, _admin, owner, asset_address) = setup_core(e, 5_000);
+    (client, owner, asset_address)
+}
+
+#[test]
+fn test_create_commitment_idempotent_same_key_creates_once() {
+    let e = Env::default();
+    let (client, owner, asset_address) = setup_idempotency_test(&e);
+    let rules = test_rules(&e);
+    let key = BytesN::from_array(&e, &[7u8; 32]);
+
+    let first = client.create_commitment_idempotent(
+        &owner,
+        &1_000,
+        &asset_address,
+        &rules,
+        &Some(key.clone()),
+    );
+    let retry = client.create_commitment_idempotent(
+        &owner,
+        &1_000,
+        &asset_address,
+        &rules,
+        &Some(key.clone()),
+    );
+
+    // The replay still needs the owner's authorization
+    assert_eq!(e.auths()[0].0, owner);
+    assert_eq!(first, retry);
+    assert_eq!(client.get_total_commitments(), 1);
+    assert_eq!(client.get_total_value_locked(), 1_000);
+    assert_eq!(TokenClient::new(&e, &asset_address).balance(&owner), 4_000);
+    assert_eq!(client.get_idempotent_commitment(&owner, &key), Some(first));
+}
+
+#[test]
+fn test_create_commitment_idempotent_distinct_keys_create_two() {
+    let e = Env::default();
+    let (client, owner, asset_address) = setup_idempotency_test(&e);
+    let rules = test_rules(&e);
+
+    let first = client.create_commitment_idempotent(
+        &owner,
+        &1_000,
+        &asset_address,
+        &rules,
+        &Some(BytesN::from_array(&e, &[1u8; 32])),
+    );
+    let second = client.create_commitment_idempotent(
+        &owner,
+        &1_000,
+        &asset_address,
+        &rules,
+        &Some(BytesN::from_array(&e, &[2u8; 32])),
+    );
+    let unkeyed =
+        client.create_commitment_idempotent(&owner, &1_000, &asset_address, &rules, &None);
+
+    assert_ne!(first, second);
+    assert_ne!(second, unkeyed);
+    assert_eq!(client.get_total_commitments(), 3);
+    assert_eq!(
+        client.get_idempotent_commitment(&owner, &BytesN::from_array(&e, &[3u8; 32])),
+        None
+    );
+}
+
+#[test]
+fn test_create_commitment_idempotent_keys_are_scoped_to_owner() {
+    let e = Env::default();
+    let (client, owner, asset_address) = setup_idempotency_test(&e);
+    let attacker = Address::generate(&e);
+    StellarAssetClient::new(&e, &asset_address).mint(&attacker, &5_000);
+    let rules = test_rules(&e);
+    let key = BytesN::from_array(&e, &[9u8; 32]);
+
+    // Using the victim's key first does not capture the victim's call
+    let squatted = client.create_commitment_idempotent(
+        &attacker,
+        &1_000,
+        &asset_address,
+        &rules,
+        &Some(key.clone()),
+    );
+    let victim = client.create_commitment_idempotent(
+        &owner,
+        &2_000,
+        &asset_address,
+        &rules,
+        &Some(key.clone()),
+    );
+
+    assert_ne!(squatted, victim);
+    assert_eq!(client.get_commitment(&victim).owner, owner);
+    assert_eq!(client.get_idempotent_commitment(&owner, &key), Some(victim));
+    assert_eq!(
+        client.get_idempotent_commitment(&attacker, &key),
+        Some(squatted)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Idempotency key already used with different parameters")]
+fn test_create_commitment_idempotent_conflicting_replay_fails() {
+    let e = Env::default();
+    let (client, owner, asset_address) = setup_idempotency_test(&e);
+    let rules = test_rules(&e);
+    let key = BytesN::from_array(&e, &[7u8; 32]);
+
+    client.create_commitment_idempotent(&owner, &1_000, &asset_address, &rules, &Some(key.clone()));
+    client.create_commitment_idempotent(&owner, &2_000, &asset_address, &rules, &Some(key));
+}
+
+#[test]
+fn test_tvl_history_rises_then_falls() {
+    let e = Env::default();
+    let (client, _admin, owner, asset_address) = setup_core(&e, 10_000);
+    assert_eq!(client.get_tvl_history(&10).len(), 0);
+
+    let mut ids = Vec::new(&e);
+    for _ in 0..3 {
+        e.ledger().with_mut(|l| l.timestamp += 100);
+        ids.push_back(client.create_commitment(
+            &owner,
+            &1000,
+            &asset_address,
+            &rules_of_type(&e, "safe"),
+        ));
+    }
+    e.ledger().with_mut(|l| l.timestamp += 31 * 86400);
+    client.settle(&ids.get(0).unwrap());
+    client.settle(&ids.get(1).unwrap());
+
+    let history = client.get_tvl_history(&10);
+    let mut tvls = Vec::new(&e);
+    for point in history.iter() {
+        tvls.push_back(point.tvl);
+    }
+    assert_eq!(tvls, vec![&e, 1000i128, 2000, 3000, 2000, 1000]);
+    assert!(history.get(0).unwrap().timestamp < history.get(3).unwrap().timestamp);
+
+    // `limit` returns the most recent points
+    let recent = client.get_tvl_history(&2);
+    assert_eq!(recent.len(), 2);
+    assert_eq!(recent.get(0).unwrap().tvl, 2000);
+    assert_eq!(recent.get(1).unwrap().tvl, 1000);
+}
+
+#[test]
+fn test_tvl_history_evicts_oldest() {
+    let e = Env::default();
+    let (client, _admin, owner, asset_address) = setup_core(&e, 10_000);
+
+    let id = client.create_commitment(&owner, &1000, &asset_address, &rules_of_type(&e, "safe"));
+    for i in 1..=MAX_TVL_HISTORY as i128 {
+        e.budget().reset_default();
+        client.update_value(&id, &(1000 + i));
+    }
+
+    let history = client.get_tvl_history(&(MAX_TVL_HISTORY + 10));
+    assert_eq!(history.len(), MAX_TVL_HISTORY);
+    // The creation point was evicted
+    assert_eq!(history.get(0).unwrap().tvl, 1001);
+    assert_eq!(history.last().unwrap().tvl, 1000 + MAX_TVL_HISTORY as i128);
+}
+
+#[test]
+fn test_asset_risk_tracks_value_updates_per_asset() {
+    let e = Env::default();
+    let (client, _admin, owner, asset) = setup_core(&e, 10_000);
+    let other_asset = e
+        .register_stellar_asset_contract_v2(Address::generate(&e))
+        .address();
+    StellarAssetClient::new(&e, &other_asset).mint(&owner, &10_000);
+
+    let first = client.create_commitment(&owner, &1000, &asset, &rules_of_type(&e, "safe"));
+    let second = client.create_commitment(&owner, &1000, &asset, &rules_of_type(&e, "safe"));
+    client.create_commitment(&owner, &500, &other_asset, &rules_of_type(&e, "safe"));
+    let risk = client.get_asset_risk(&asset);
+    assert_eq!(
+        risk,
+        AssetRisk {
+            total_committed: 2000,
+            total_current: 2000,
+            aggregate_drawdown_bps: 0,
+        }
+    );
+
+    client.update_value(&first, &950);
+    client.update_value(&second, &850);
+    let risk = client.get_asset_risk(&asset);
+    assert_eq!(risk.total_current, 1800);
+    assert_eq!(risk.aggregate_drawdown_bps, 1000);
+
+    // A gain on one commitment offsets the other's loss
+    client.update_value(&first, &1100);
+    assert_eq!(client.get_asset_risk(&asset).aggregate_drawdown_bps, 250);
+
+    // Other assets are unaffected
+    let other = client.get_asset_risk(&other_asset);
+    assert_eq!(other.total_committed, 500);
+    assert_eq!(other.total_current, 500);
+
+    // Settling removes the commitment from its asset's aggregate
+    e.ledger().with_mut(|l| l.timestamp += 31 * 86400);
+    client.settle(&first);
+    let risk = client.get_asset_risk(&asset);
+    assert_eq!(risk.total_committed, 1000);
+    assert_eq!(risk.total_current, 850);
+    assert_eq!(risk.aggregate_drawdown_bps, 1500);
+}
+
+#[test]
+fn test_seed_asset_risk_counts_commitments_missing_from_aggregate() {
+    let e = Env::default();
+    let (client, admin, owner, asset) = setup_core(&e, 10_000);
+
+    let first = client.create_commitment(&owner, &1000, &asset, &rules_of_type(&e, "safe"));
+    let second = client.create_commitment(&owner, &500, &asset, &rules_of_type(&e, "safe"));
+    client.update_value(&first, &900);
+    client.early_exit(&second, &owner);
+
+    // Simulate commitments created before the aggregate existed
+    e.as_contract(&client.address, || {
+        e.storage()
+            .instance()
+            .remove(&DataKey::AssetRisk(asset.clone()));
+    });
+    assert_eq!(client.get_asset_risk(&asset).total_committed, 0);
+
+    let expected = AssetRisk {
+        total_committed: 1000,
+        total_current: 900,
+        aggregate_drawdown_bps: 1000,
+    };
+    assert_eq!(client.seed_asset_risk(&admin, &asset), expected);
+    assert_eq!(client.get_asset_risk(&asset), expected);
+    // Reseeding does not double count
+    assert_eq!(client.seed_asset_risk(&admin, &asset), expected);
+}
+
+#[test]
+fn test_replace_asset_moves_asset_risk() {
+    let e = Env::default();
+    let (client, admin, owner, old_asset) = setup_core(&e, 10_000);
+    let new_asset = Address::generate(&e);
+
+    let moved = client.create_commitment(&owner, &1000, &old_asset, &rules_of_type(&e, "safe"));
+    let kept = client.create_commitment(&owner, &500, &old_asset, &rules_of_type(&e, "safe"));
+    client.update_value(&moved, &950);
+    let before = client.get_commitment(&moved);
+
+    client.replace_asset(&admin, &moved, &new_asset);
+
+    let after = client.get_commitment(&moved);
+    assert_eq!(after.asset_address, new_asset);
+    assert_eq!(after.amount, before.amount);
+    assert_eq!(after.current_value, before.current_value);
+    assert_eq!(after.status, before.status);
+    assert_eq!(client.get_commitment(&kept).asset_address, old_asset);
+
+    let old_risk = client.get_asset_risk(&old_asset);
+    assert_eq!(
+        (old_risk.total_committed, old_risk.total_current),
+        (500, 500)
+    );
+    let new_risk = client.get_asset_risk(&new_asset);
+    assert_eq!(
+        (new_risk.total_committed, new_risk.total_current),
+        (1000, 950)
+    );
+    assert_eq!(new_risk.aggregate_drawdown_bps, 500);
+    assert_eq!(client.get_total_value_locked(), 1450);
+
+    // Custody of the moved commitment left the old asset with it
+    client.rescue_tokens(&admin, &old_asset, &admin, &950);
+    assert!(client
+        .try_rescue_tokens(&admin, &old_asset, &admin, &1)
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "Invalid asset address")]
+fn test_replace_asset_rejects_self_address() {
+    let e = Env::default();
+    let (client, admin, _owner, commitment_id) = setup_value_reporter_test(&e);
+
+    client.replace_asset(&admin, &commitment_id, &client.address);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller not allowed")]
+fn test_replace_asset_non_admin_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, commitment_id) = setup_value_reporter_test(&e);
+
+    client.replace_asset(&owner, &commitment_id, &Address::generate(&e));
+}
+
+/// Register core with one active "test_id" commitment for value reporter tests.
+/// Returns (client, admin, owner, commitment_id).
+fn setup_value_reporter_test(
+    e: &Env,
+) -> (CommitmentCoreContractClient<'_>, Address, Address, String) {
+    let (client, admin, owner, _asset) = setup_core(e, 0);
+    e.as_contract(&client.address, || {
+        let commitment = create_test_commitment(e, "test_id", &owner, 1000, 1000, 10, 30, 1000);
+        set_commitment(e, &commitment);
+    });
+    (client, admin, owner, String::from_str(e, "test_id"))
+}
+
+#[test]
+fn test_value_reporter_can_update_value() {
+    let e = Env::default();
+    let (client, _admin, owner, commitment_id) = setup_value_reporter_test(&e);
+    let reporter = Address::generate(&e);
+
+    client.set_value_reporter(&commitment_id, &reporter, &owner);
+    assert_eq!(
+        client.get_value_reporter(&commitment_id),
+        Some(reporter.clone())
+    );
+
+    client.update_value(&commitment_id, &950);
+    assert_eq!(e.auths()[0].0, reporter);
+    assert_eq!(client.get_commitment(&commitment_id).current_value, 950);
+}
+
+#[test]
+fn test_update_value_without_reporter_requires_admin() {
+    let e = Env::default();
+    let (client, admin, _owner, commitment_id) = setup_value_reporter_test(&e);
+
+    assert_eq!(client.get_value_reporter(&commitment_id), None);
+    client.update_value(&commitment_id, &950);
+    assert_eq!(e.auths()[0].0, admin);
+}
+
+#[test]
+fn test_update_value_rejects_unauthorized_address() {
+    let e = Env::default();
+    let (client, _admin, owner, commitment_id) = setup_value_reporter_test(&e);
+    let reporter = Address::generate(&e);
+    client.set_value_reporter(&commitment_id, &reporter, &owner);
+
+    // No signatures: only the reporter's auth would be accepted
+    e.set_auths(&[]);
+    assert!(client.try_update_value(&commitment_id, &950).is_err());
+    assert_eq!(client.get_commitment(&commitment_id).current_value, 1000);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_set_value_reporter_by_stranger_fails() {
+    let e = Env::default();
+    let (client, _admin, _owner, commitment_id) = setup_value_reporter_test(&e);
+    let stranger = Address::generate(&e);
+
+    client.set_value_reporter(&commitment_id, &stranger, &stranger);
+}
+
+#[test]
+fn test_admin_overrides_value_reporter() {
+    let e = Env::default();
+    let (client, admin, owner, commitment_id) = setup_value_reporter_test(&e);
+    let reporter = Address::generate(&e);
+    client.set_value_reporter(&commitment_id, &reporter, &owner);
+
+    client.set_value_reporter(&commitment_id, &admin, &admin);
+    client.update_value(&commitment_id, &980);
+    assert_eq!(e.auths()[0].0, admin);
+    assert_eq!(client.get_commitment(&commitment_id).current_value, 980);
+}
+
+fn sample_strategy(e: &Env, name: &str) -> StrategyInfo {
+    StrategyInfo {
+        name: String::from_str(e, name),
+        target_apy_bps: 850,
+        rebalance_cadence_secs: 7 * 86400,
+        description: String::from_str(e, "Weekly rebalanced stablecoin yield"),
+    }
+}
+
+#[test]
+fn test_strategy_round_trips_and_defaults_to_none() {
+    let e = Env::default();
+    let (client, admin, owner, commitment_id) = setup_value_reporter_test(&e);
+    assert_eq!(client.get_strategy(&commitment_id), None);
+
+    let info = sample_strategy(&e, "stable-yield");
+    client.set_strategy(&commitment_id, &info, &owner);
+    assert_eq!(client.get_strategy(&commitment_id), Some(info));
+
+    // The admin can replace the owner's descriptor
+    let replaced = sample_strategy(&e, "stable-yield-v2");
+    client.set_strategy(&commitment_id, &replaced, &admin);
+    assert_eq!(client.get_strategy(&commitment_id), Some(replaced));
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized: caller not allowed")]
+fn test_set_strategy_rejects_other_caller() {
+    let e = Env::default();
+    let (client, _admin, _owner, commitment_id) = setup_value_reporter_test(&e);
+
+    client.set_strategy(
+        &commitment_id,
+        &sample_strategy(&e, "stable-yield"),
+        &Address::generate(&e),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Invalid strategy: name or description too long")]
+fn test_set_strategy_rejects_overlong_name() {
+    let e = Env::default();
+    let (client, _admin, owner, commitment_id) = setup_value_reporter_test(&e);
+
+    let info = sample_strategy(&e, "a-strategy-name-well-beyond-32-bytes");
+    client.set_strategy(&commitment_id, &info, &owner);
+}
+
+/// Core with a 1000-unit commitment in `asset`, plus 500 of `asset` and 300 of
+/// an unrelated token sent straight to the contract.
+/// Returns (client, admin, asset, stray token).
+fn setup_rescue_test(e: &Env) -> (CommitmentCoreContractClient<'_>, Address, Address, Address) {
+    let (client, admin, owner, asset) = setup_core(e, 1000);
+    let stray = e
+        .register_stellar_asset_contract_v2(Address::generate(e))
+        .address();
+
+    client.create_commitment(&owner, &1000, &asset, &rules_of_type(e, "safe"));
+    StellarAssetClient::new(e, &asset).mint(&client.address, &500);
+    StellarAssetClient::new(e, &stray).mint(&client.address, &300);
+    (client, admin, asset, stray)
+}
+
+#[test]
+fn test_rescue_tokens_recovers_stray_assets() {
+    let e = Env::default();
+    let (client, admin, asset, stray) = setup_rescue_test(&e);
+    let recipient = Address::generate(&e);
+
+    client.rescue_tokens(&admin, &stray, &recipient, &300);
+    client.rescue_tokens(&admin, &asset, &recipient, &500);
+
+    assert_eq!(TokenClient::new(&e, &stray).balance(&recipient), 300);
+    assert_eq!(TokenClient::new(&e, &asset).balance(&recipient), 500);
+    // Committed funds stay in custody
+    assert_eq!(TokenClient::new(&e, &asset).balance(&client.address), 1000);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient balance")]
+fn test_rescue_tokens_cannot_touch_committed_assets() {
+    let e = Env::default();
+    let (client, admin, asset, _stray) = setup_rescue_test(&e);
+
+    client.rescue_tokens(&admin, &asset, &admin, &501);
+}
+
+#[test]
+fn test_rescue_tokens_waits_for_custody_seed_after_upgrade() {
+    let e = Env::default();
+    let (client, admin, asset, _stray) = setup_rescue_test(&e);
+
+    // Simulate a deployment upgraded from before custody tracking
+    e.as_contract(&client.address, || {
+        e.storage().instance().remove(&DataKey::CustodyTracked);
+        e.storage()
+            .instance()
+            .remove(&DataKey::AssetCustody(asset.clone()));
+    });
+    assert!(client
+        .try_rescue_tokens(&admin, &asset, &admin, &1)
+        .is_err());
+
+    assert_eq!(client.seed_custody(&admin, &asset), 1000);
+    assert!(client.try_rescue_tokens(&admin, &asset, &admin, &501).is_err());
+    client.rescue_tokens(&admin, &asset, &admin, &500);
+    assert_eq!(TokenClient::new(&e, &asset).balance(&client.address), 1000);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_rescue_tokens_non_admin_fails() {
+    let e = Env::default();
+    let (client, _admin, _asset, stray) = setup_rescue_test(&e);
+    let attacker = Address::generate(&e);
+
+    client.rescue_tokens(&attacker, &stray, &attacker, &300);
+}
+
+/// Core with one expired 1000-unit commitment and a configured value reporter.
+/// Returns (client, owner, reporter, asset, commitment_id).
+fn setup_settle_with_value_test(
+    e: &Env,
+) -> (
+    CommitmentCoreContractClient<'_>,
+    Address,
+    Address,
+    Address,
+    String,
+) {
+    let (client, _admin, owner, asset) = setup_core(e, 1000);
+    let reporter = Address::generate(e);
+    let commitment_id = client.create_commitment(&owner, &1000, &asset, &rules_of_type(e, "safe"));
+    client.set_value_reporter(&commitment_id, &reporter, &owner);
+    e.ledger().with_mut(|l| l.timestamp += 31 * 86400);
+    (client, owner, reporter, asset, commitment_id)
+}
+
+#[test]
+fn test_settle_with_value_captures_final_value() {
+    let e = Env::default();
+    let (client, owner, reporter, asset, commitment_id) = setup_settle_with_value_test(&e);
+
+    client.settle_with_value(&commitment_id, &950, &reporter);
+    assert_eq!(e.auths()[0].0, reporter);
+
+    let commitment = client.get_commitment(&commitment_id);
+    assert_eq!(commitment.status, String::from_str(&e, "settled"));
+    assert_eq!(commitment.current_value, 950);
+    assert_eq!(TokenClient::new(&e, &asset).balance(&owner), 950);
+    assert_eq!(client.get_total_value_locked(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_settle_with_value_rejects_non_reporter() {
+    let e = Env::default();
+    let (client, owner, _reporter, _asset, commitment_id) = setup_settle_with_value_test(&e);
+
+    client.settle_with_value(&commitment_id, &1200, &owner);
+}
+
+/// Initialized core with a funded owner and a default grace period of 7 days.
+/// Returns (client, owner, asset).
+fn setup_default_grace_test(e: &Env) -> (CommitmentCoreContractClient<'_>, Address, Address) {
+    let (client, admin, owner, asset) = setup_core(e, 10_000);
+    assert_eq!(client.get_default_grace_period(), 0);
+    client.set_default_grace_period(&admin, &7);
+    assert_eq!(client.get_default_grace_period(), 7);
+    (client, owner, asset)
+}
+
+#[test]
+fn test_create_commitment_inherits_default_grace_period() {
+    let e = Env::default();
+    let (client, owner, asset) = setup_default_grace_test(&e);
+
+    let commitment_id = client.create_commitment(&owner, &1000, &asset, &test_rules(&e));
+    let commitment = client.get_commitment(&commitment_id);
+    assert_eq!(commitment.rules.grace_period_days, 7);
+}
+
+#[test]
+fn test_explicit_grace_period_overrides_default() {
+    let e = Env::default();
+    let (client, owner, asset) = setup_default_grace_test(&e);
+
+    let mut rules = test_rules(&e);
+    rules.grace_period_days = 2;
+    let commitment_id = client.create_commitment(&owner, &1000, &asset, &rules);
+    let commitment = client.get_commitment(&commitment_id);
+    assert_eq!(commitment.rules.grace_period_days, 2);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_set_default_grace_period_non_admin_fails() {
+    let e = Env::default();
+    let (client, owner, _asset) = setup_default_grace_test(&e);
+
+    client.set_default_grace_period(&owner, &30);
+}
+
+#[test]
+fn test_aggressive_commitment_inherits_type_fee_threshold() {
+    let e = Env::default();
+    let (client, owner, asset) = setup_default_grace_test(&e);
+    let admin = client.get_admin();
+    let aggressive = String::from_str(&e, "aggressive");
+    assert_eq!(client.get_type_fee_threshold(&aggressive), 0);
+    client.set_type_fee_threshold(&admin, &aggressive, &500);
+    client.set_type_fee_threshold(&admin, &String::from_str(&e, "safe"), &50);
+    assert_eq!(client.get_type_fee_threshold(&aggressive), 500);
+
+    let mut rules = test_rules(&e);
+    rules.commitment_type = aggressive;
+    rules.min_fee_threshold = 0;
+    let commitment_id = client.create_commitment(&owner, &1000, &asset, &rules);
+    let commitment = client.get_commitment(&commitment_id);
+    assert_eq!(commitment.rules.min_fee_threshold, 500);
+
+    // A type without a default keeps zero
+    let mut rules = test_rules(&e);
+    rules.min_fee_threshold = 0;
+    let commitment_id = client.create_commitment(&owner, &1000, &asset, &rules);
+    let commitment = client.get_commitment(&commitment_id);
+    assert_eq!(commitment.rules.min_fee_threshold, 0);
+}
+
+#[test]
+fn test_explicit_fee_threshold_overrides_type_default() {
+    let e = Env::default();
+    let (client, owner, asset) = setup_default_grace_test(&e);
+    let aggressive = String::from_str(&e, "aggressive");
+    client.set_type_fee_threshold(&client.get_admin(), &aggressive, &500);
+
+    let mut rules = test_rules(&e);
+    rules.commitment_type = aggressive;
+    rules.min_fee_threshold = 75;
+    let commitment_id = client.create_commitment(&owner, &1000, &asset, &rules);
+    let commitment = client.get_commitment(&commitment_id);
+    assert_eq!(commitment.rules.min_fee_threshold, 75);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_set_type_fee_threshold_non_admin_fails() {
+    let e = Env::default();
+    let (client, owner, _asset) = setup_default_grace_test(&e);
+
+    client.set_type_fee_threshold(&owner, &String::from_str(&e, "safe"), &50);
+}
+
+#[test]
+fn test_penalty_waiver_without_engine_charges_full_penalty() {
+    let e = Env::default();
+    let (client, owner, asset) = setup_default_grace_test(&e);
+    assert_eq!(client.get_penalty_waiver(), (0, 0));
+    client.set_penalty_waiver(&client.get_admin(), &80, &5000);
+    assert_eq!(client.get_penalty_waiver(), (80, 5000));
+    assert_eq!(client.get_attestation_engine(), None);
+
+    let commitment_id = client.create_commitment(&owner, &1000, &asset, &test_rules(&e));
+    client.early_exit(&commitment_id, &owner);
+    assert_eq!(TokenClient::new(&e, &asset).balance(&owner), 9950);
+}
+
+#[test]
+#[should_panic(expected = "Invalid penalty waiver")]
+fn test_set_penalty_waiver_rejects_bps_over_10000() {
+    let e = Env::default();
+    let (client, _owner, _asset) = setup_default_grace_test(&e);
+
+    client.set_penalty_waiver(&client.get_admin(), &80, &10001);
+}
+
+#[test]
+fn test_admin_correct_value_logs_previous_value_and_reason() {
+    let e = Env::default();
+    let (client, owner, asset) = setup_default_grace_test(&e);
+    let admin = client.get_admin();
+    let commitment_id = client.create_commitment(&owner, &1000, &asset, &test_rules(&e));
+    client.update_value(&commitment_id, &1);
+
+    let reason = String::from_str(&e, "oracle misreport");
+    client.admin_correct_value(&admin, &commitment_id, &950, &reason);
+
+    assert_eq!(client.get_commitment(&commitment_id).current_value, 950);
+    assert_eq!(client.get_total_value_locked(), 950);
+    let corrections = client.get_value_corrections(&commitment_id);
+    assert_eq!(corrections.len(), 1);
+    let correction = corrections.get(0).unwrap();
+    assert_eq!(correction.previous_value, 1);
+    assert_eq!(correction.corrected_value, 950);
+    assert_eq!(correction.reason, reason);
+    assert_eq!(correction.admin, admin);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_admin_correct_value_non_admin_fails() {
+    let e = Env::default();
+    let (client, owner, asset) = setup_default_grace_test(&e);
+    let commitment_id = client.create_commitment(&owner, &1000, &asset, &test_rules(&e));
+
+    client.admin_correct_value(
+        &owner,
+        &commitment_id,
+        &950,
+        &String::from_str(&e, "not mine to fix"),
+    );
+}
+
+/// Core with one active 1000-unit commitment (5% early-exit penalty).
+/// Returns (client, owner, asset, commitment_id).
+fn setup_partial_exit_test(
+    e: &Env,
+) -> (CommitmentCoreContractClient<'_>, Address, Address, String) {
+    let (client, _admin, owner, asset) = setup_core(e, 1000);
+    let commitment_id = client.create_commitment(&owner, &1000, &asset, &test_rules(e));
+    (client, owner, asset, commitment_id)
+}
+
+#[test]
+fn test_partial_exit_keeps_remainder_active() {
+    let e = Env::default();
+    let (client, owner, asset, commitment_id) = setup_partial_exit_test(&e);
+
+    // 30% of 1000 exits: 300 minus a 5% penalty (15)
+    client.partial_exit(&commitment_id, &3000, &owner);
+
+    let commitment = client.get_commitment(&commitment_id);
+    assert_eq!(commitment.status, String::from_str(&e, "active"));
+    assert_eq!(commitment.amount, 700);
+    assert_eq!(commitment.current_value, 700);
+    assert_eq!(TokenClient::new(&e, &asset).balance(&owner), 285);
+    assert_eq!(TokenClient::new(&e, &asset).balance(&client.address), 715);
+    assert_eq!(client.get_total_value_locked(), 700);
+}
+
+#[test]
+#[should_panic(expected = "Invalid fraction")]
+fn test_partial_exit_rejects_full_fraction() {
+    let e = Env::default();
+    let (client, owner, _asset, commitment_id) = setup_partial_exit_test(&e);
+
+    client.partial_exit(&commitment_id, &10000, &owner);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_partial_exit_non_owner_fails() {
+    let e = Env::default();
+    let (client, _owner, _asset, commitment_id) = setup_partial_exit_test(&e);
+    let stranger = Address::generate(&e);
+
+    client.partial_exit(&commitment_id, &3000, &stranger);
+}
+
+#[test]
+fn test_trigger_stop_is_noop_until_stop_breached() {
+    let e = Env::default();
+    let (client, owner, asset, commitment_id) = setup_partial_exit_test(&e);
+    client.set_auto_stop(&commitment_id, &500, &owner);
+    assert_eq!(client.get_auto_stop(&commitment_id), 500);
+
+    // 4% drawdown is within the 5% stop
+    client.update_value(&commitment_id, &960);
+    assert!(!client.trigger_stop(&commitment_id));
+    let commitment = client.get_commitment(&commitment_id);
+    assert_eq!(commitment.status, String::from_str(&e, "active"));
+    assert_eq!(TokenClient::new(&e, &asset).balance(&owner), 0);
+
+    // 7% drawdown breaches it: early exit with the standard 5% penalty
+    client.update_value(&commitment_id, &930);
+    assert!(client.trigger_stop(&commitment_id));
+    let commitment = client.get_commitment(&commitment_id);
+    assert_eq!(commitment.status, String::from_str(&e, "early_exit"));
+    assert_eq!(TokenClient::new(&e, &asset).balance(&owner), 884);
+    assert_eq!(client.get_auto_stop(&commitment_id), 0);
+}
+
+#[test]
+fn test_trigger_stop_without_stop_is_noop() {
+    let e = Env::default();
+    let (client, _owner, _asset, commitment_id) = setup_partial_exit_test(&e);
+    client.update_value(&commitment_id, &930);
+
+    assert!(!client.trigger_stop(&commitment_id));
+    let commitment = client.get_commitment(&commitment_id);
+    assert_eq!(commitment.status, String::from_str(&e, "active"));
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_set_auto_stop_non_owner_fails() {
+    let e = Env::default();
+    let (client, _owner, _asset, commitment_id) = setup_partial_exit_test(&e);
+
+    client.set_auto_stop(&commitment_id, &500, &Address::generate(&e));
+}
+
+/// (old_status, new_status, current_value) of the most recent `StatusChanged` event.
+fn last_status_change(e: &Env) -> (String, String, i128) {
+    let topic = Symbol::new(e, "StatusChanged");
+    let mut last = None;
+    for (_, topics, data) in e.events().all().iter() {
+        let is_status_change = topics
+            .get(0)
+            .and_then(|t| Symbol::try_from_val(e, &t).ok())
+            .is_some_and(|t| t == topic);
+        if is_status_change {
+            let (old, new, value, _ts): (String, String, i128, u64) =
+                TryFromVal::try_from_val(e, &data).unwrap();
+            last = Some((old, new, value));
+        }
+    }
+    last.expect("StatusChanged event")
+}
+
+#[test]
+fn test_status_changed_on_create_and_update() {
+    let e = Env::default();
+    let (client, owner, asset) = setup_default_grace_test(&e);
+    let s = |v: &str| String::from_str(&e, v);
+
+    let commitment_id = client.create_commitment(&owner, &1000, &asset, &test_rules(&e));
+    assert_eq!(last_status_change(&e), (s(""), s("active"), 1000));
+
+    client.update_value(&commitment_id, &950);
+    assert_eq!(last_status_change(&e), (s("active"), s("active"), 950));
+
+    // A 20% loss breaches the 10% limit
+    client.update_value(&commitment_id, &800);
+    assert_eq!(last_status_change(&e), (s("active"), s("violated"), 800));
+}
+
+#[test]
+fn test_status_changed_on_settle_and_early_exit() {
+    let e = Env::default();
+    let (client, owner, asset) = setup_default_grace_test(&e);
+    let s = |v: &str| String::from_str(&e, v);
+    let exited_id = client.create_commitment(&owner, &1000, &asset, &test_rules(&e));
+    let settled_id = client.create_commitment(&owner, &1000, &asset, &test_rules(&e));
+
+    client.early_exit(&exited_id, &owner);
+    assert_eq!(last_status_change(&e), (s("active"), s("early_exit"), 0));
+
+    e.ledger().with_mut(|l| l.timestamp += 31 * 86400);
+    client.settle(&settled_id);
+    assert_eq!(last_status_change(&e), (s("active"), s("settled"), 1000));
+}
+
+#[test]
+fn test_status_changed_on_dispute_transitions() {
+    let e = Env::default();
+    let (client, owner, asset) = setup_default_grace_test(&e);
+    let admin = client.get_admin();
+    let s = |v: &str| String::from_str(&e, v);
+    let commitment_id = client.create_commitment(&owner, &1000, &asset, &test_rules(&e));
+
+    client.open_dispute(&commitment_id, &owner);
+    assert_eq!(last_status_change(&e), (s("active"), s("disputed"), 1000));
+
+    client.resolve_dispute(&admin, &commitment_id, &false);
+    assert_eq!(last_status_change(&e), (s("disputed"), s("active"), 1000));
+
+    client.open_dispute(&commitment_id, &owner);
+    client.resolve_dispute(&admin, &commitment_id, &true);
+    assert_eq!(last_status_change(&e), (s("disputed"), s("early_exit"), 0));
+}
+
+#[test]
+fn test_settle_pays_owner_current_value() {
+    let e = Env::default();
+    let (client, owner, asset, commitment_id) = setup_partial_exit_test(&e);
+    client.update_value(&commitment_id, &950);
+    e.ledger().with_mut(|l| l.timestamp += 31 * 86400);
+
+    client.settle(&commitment_id);
+
+    // The owner receives the reported current value; the shortfall stays held
+    assert_eq!(TokenClient::new(&e, &asset).balance(&owner), 950);
+    assert_eq!(TokenClient::new(&e, &asset).balance(&client.address), 50);
+}
+
+#[test]
+#[should_panic(expected = "Payout transfer to owner failed")]
+fn test_settle_fails_with_payout_failed_when_custody_short() {
+    let e = Env::default();
+    let (client, _owner, asset, commitment_id) = setup_partial_exit_test(&e);
+    // Move the held balance out so the payout transfer cannot succeed
+    TokenClient::new(&e, &asset).transfer(&client.address, &Address::generate(&e), &1000);
+    e.ledger().with_mut(|l| l.timestamp += 31 * 86400);
+
+    client.settle(&commitment_id);
+}
+
+#[test]
+fn test_asset_decimals_recorded_per_asset() {
+    let e = Env::default();
+    let (client, _owner, asset, commitment_id) = setup_partial_exit_test(&e);
+    assert_eq!(client.get_asset_decimals(&asset), None);
+
+    client.set_asset_decimals(&client.get_admin(), &asset, &7);
+
+    assert_eq!(client.get_asset_decimals(&asset), Some(7));
+    assert_eq!(client.get_asset_decimals(&Address::generate(&e)), None);
+    // The commitment record itself is unchanged
+    assert_eq!(client.get_commitment(&commitment_id).asset_address, asset);
+}
+
+#[test]
+fn test_settle_at_maturity_incurs_no_late_penalty() {
+    let e = Env::default();
+    let (client, owner, asset, commitment_id) = setup_partial_exit_test(&e);
+    assert_eq!(client.get_late_penalty(), (0, 0));
+    client.set_late_penalty(&client.get_admin(), &100, &500);
+    e.ledger().with_mut(|l| l.timestamp = 30 * 86400);
+
+    client.settle(&commitment_id);
+
+    assert_eq!(TokenClient::new(&e, &asset).balance(&owner), 1000);
+}
+
+#[test]
+fn test_settle_days_late_applies_capped_late_penalty() {
+    let e = Env::default();
+    let (client, owner, asset, commitment_id) = setup_partial_exit_test(&e);
+    // 1% per day late, at most 5%
+    client.set_late_penalty(&client.get_admin(), &100, &500);
+    e.ledger().with_mut(|l| l.timestamp = 38 * 86400 + 3600);
+
+    client.settle(&commitment_id);
+
+    // 8 full days late accrue 8%, capped at 5% of 1000
+    assert_eq!(TokenClient::new(&e, &asset).balance(&owner), 950);
+    assert_eq!(TokenClient::new(&e, &asset).balance(&client.address), 50);
+    let late_event = e.events().all().iter().find(|(_, topics, _)| {
+        topics.get(0).map(|t| Symbol::try_from_val(&e, &t).ok())
+            == Some(Some(symbol_short!("LatePen")))
+    });
+    let (_, _, data) = late_event.expect("LatePen event");
+    let (days_late, bps, penalty, _ts): (u64, u32, i128, u64) =
+        TryFromVal::try_from_val(&e, &data).unwrap();
+    assert_eq!((days_late, bps, penalty), (8, 500, 50));
+}
+
+#[test]
+#[should_panic(expected = "Invalid duration")]
+fn test_create_commitment_rejects_duration_above_max() {
+    let e = Env::default();
+    let (client, owner, asset) = setup_default_grace_test(&e);
+    client.set_max_duration_days(&client.get_admin(), &365);
+    assert_eq!(client.get_max_duration_days(), 365);
+
+    let mut rules = test_rules(&e);
+    rules.duration_days = 366;
+    client.create_commitment(&owner, &1000, &asset, &rules);
+}
+
+#[test]
+fn test_create_commitment_allows_duration_within_max() {
+    let e = Env::default();
+    let (client, owner, asset) = setup_default_grace_test(&e);
+    client.set_max_duration_days(&client.get_admin(), &365);
+
+    let mut rules = test_rules(&e);
+    rules.duration_days = 365;
+    let commitment_id = client.create_commitment(&owner, &1000, &asset, &rules);
+    assert_eq!(
+        client.get_commitment(&commitment_id).rules.duration_days,
+        365
+    );
+
+    // Zero lifts the cap again
+    client.set_max_duration_days(&client.get_admin(), &0);
+    rules.duration_days = 10_000;
+    client.create_commitment(&owner, &1000, &asset, &rules);
+}
+
+#[test]
+#[should_panic(expected = "Commitment is under dispute")]
+fn test_disputed_commitment_cannot_settle() {
+    let e = Env::default();
+    let (client, owner, _asset, commitment_id) = setup_partial_exit_test(&e);
+
+    client.open_dispute(&commitment_id, &owner);
+    assert_eq!(
+        client.get_commitment(&commitment_id).status,
+        String::from_str(&e, "disputed")
+    );
+    e.ledger().with_mut(|l| l.timestamp += 31 * 86400);
+    client.settle(&commitment_id);
+}
+
+#[test]
+#[should_panic(expected = "Commitment is under dispute")]
+fn test_disputed_commitment_cannot_exit_early() {
+    let e = Env::default();
+    let (client, owner, _asset, commitment_id) = setup_partial_exit_test(&e);
+
+    client.open_dispute(&commitment_id, &client.get_admin());
+    client.early_exit(&commitment_id, &owner);
+}
+
+#[test]
+fn test_rejected_dispute_reopens_settlement() {
+    let e = Env::default();
+    let (client, owner, asset, commitment_id) = setup_partial_exit_test(&e);
+    client.open_dispute(&commitment_id, &owner);
+
+    client.resolve_dispute(&client.get_admin(), &commitment_id, &false);
+    assert_eq!(
+        client.get_commitment(&commitment_id).status,
+        String::from_str(&e, "active")
+    );
+
+    e.ledger().with_mut(|l| l.timestamp += 31 * 86400);
+    client.settle(&commitment_id);
+    assert_eq!(
+        client.get_commitment(&commitment_id).status,
+        String::from_str(&e, "settled")
+    );
+    assert_eq!(TokenClient::new(&e, &asset).balance(&owner), 1000);
+}
+
+#[test]
+fn test_upheld_dispute_closes_with_penalty() {
+    let e = Env::default();
+    let (client, owner, asset, commitment_id) = setup_partial_exit_test(&e);
+    client.open_dispute(&commitment_id, &owner);
+
+    client.resolve_dispute(&client.get_admin(), &commitment_id, &true);
+    let commitment = client.get_commitment(&commitment_id);
+    assert_eq!(commitment.status, String::from_str(&e, "early_exit"));
+    assert_eq!(commitment.current_value, 0);
+    // 5% early-exit penalty withheld
+    assert_eq!(TokenClient::new(&e, &asset).balance(&owner), 950);
+    assert_eq!(client.get_total_value_locked(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_open_dispute_by_stranger_fails() {
+    let e = Env::default();
+    let (client, _owner, _asset, commitment_id) = setup_partial_exit_test(&e);
+    let stranger = Address::generate(&e);
+
+    client.open_dispute(&commitment_id, &stranger);
+}
+
+#[test]
+#[should_panic(expected = "Arithmetic overflow")]
+fn test_create_commitment_tvl_overflow_fails_cleanly() {
+    let e = Env::default();
+    let (client, owner, asset) = setup_default_grace_test(&e);
+    let other = Address::generate(&e);
+    StellarAssetClient::new(&e, &asset).mint(&owner, &(i128::MAX - 10_000));
+    StellarAssetClient::new(&e, &asset).mint(&other, &10_001);
+
+    client.create_commitment(&owner, &(i128::MAX - 10_000), &asset, &test_rules(&e));
+    // TVL is now i128::MAX - 10_000; one more large commitment cannot fit
+    client.create_commitment(&other, &10_001, &asset, &test_rules(&e));
+}
+
+#[test]
+fn test_can_settle_tracks_commitment_state() {
+    let e = Env::default();
+    let (client, owner, _asset, commitment_id) = setup_partial_exit_test(&e);
+    assert!(!client.can_settle(&String::from_str(&e, "missing")));
+
+    // Active but not yet expired
+    assert!(!client.can_settle(&commitment_id));
+
+    e.ledger().with_mut(|l| l.timestamp += 31 * 86400);
+    assert!(client.can_settle(&commitment_id));
+
+    // Disputed commitments are blocked until resolved
+    client.open_dispute(&commitment_id, &owner);
+    assert!(!client.can_settle(&commitment_id));
+    client.resolve_dispute(&client.get_admin(), &commitment_id, &false);
+    assert!(client.can_settle(&commitment_id));
+
+    client.settle(&commitment_id);
+    assert!(!client.can_settle(&commitment_id));
+}
+
+/// Store a commitment with a 9.6% drawdown (1000 -> 904) against a 9% loss
+/// limit and apply `mode`. Returns (loss_percent, loss_violated).
+fn drawdown_under_rounding(e: &Env, mode: RoundingMode) -> (i128, bool) {
+    e.mock_all_auths();
+    let contract_id = e.register_contract(None, CommitmentCoreContract);
+    let client = CommitmentCoreContractClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin, &Address::generate(e));
+    client.set_rounding_mode(&admin, &mode);
+
+    let owner = Address::generate(e);
+    let commitment = create_test_commitment(e, "rounding", &owner, 1000, 904, 9, 30, 0);
+    store_commitment(e, &contract_id, &commitment);
+
+    let (_, loss_violated, _, loss_percent, _) =
+        client.get_violation_details(&String::from_str(e, "rounding"));
+    (loss_percent, loss_violated)
+}
+
+#[test]
+fn test_rounding_mode_defaults_to_floor() {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, CommitmentCoreContract);
+    let client = CommitmentCoreContractClient::new(&e, &contract_id);
+    assert_eq!(client.get_rounding_mode(), RoundingMode::Floor);
+}
+
+#[test]
+fn test_rounding_mode_floor_truncates_drawdown() {
+    let e = Env::default();
+    assert_eq!(drawdown_under_rounding(&e, RoundingMode::Floor), (9, false));
+}
+
+#[test]
+fn test_rounding_mode_ceil_rounds_drawdown_up() {
+    let e = Env::default();
+    assert_eq!(drawdown_under_rounding(&e, RoundingMode::Ceil), (10, true));
+}
+
+#[test]
+fn test_rounding_mode_nearest_rounds_drawdown() {
+    let e = Env::default();
+    assert_eq!(
+        drawdown_under_rounding(&e, RoundingMode::Nearest),
+        (10, true)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_set_rounding_mode_non_admin_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_id = e.register_contract(None, CommitmentCoreContract);
+    let client = CommitmentCoreContractClient::new(&e, &contract_id);
+    client.initialize(&Address::generate(&e), &Address::generate(&e));
+    client.set_rounding_mode(&Address::generate(&e), &RoundingMode::Ceil);
+}
+
+#[test]
+#[should_panic(expected = "Amount below minimum commitment amount")]
+fn test_create_commitment_rejects_amount_below_min() {
+    let e = Env::default();
+    let (client, owner, asset) = setup_default_grace_test(&e);
+    client.set_min_amount(&client.get_admin(), &500);
+    assert_eq!(client.get_min_amount(), 500);
+
+    client.create_commitment(&owner, &499, &asset, &test_rules(&e));
+}
+
+#[test]
+fn test_create_commitment_allows_amount_at_or_above_min() {
+    let e = Env::default();
+    let (client, owner, asset) = setup_default_grace_test(&e);
+    assert_eq!(client.get_min_amount(), 0);
+    client.set_min_amount(&client.get_admin(), &500);
+
+    let at_min = client.create_commitment(&owner, &500, &asset, &test_rules(&e));
+    assert_eq!(client.get_commitment(&at_min).amount, 500);
+    client.create_commitment(&owner, &501, &asset, &test_rules(&e));
+
+    // Zero removes the minimum again
+    client.set_min_amount(&client.get_admin(), &0);
+    let dust = client.create_commitment(&owner, &1, &asset, &test_rules(&e));
+    assert_eq!(client.get_commitment(&dust).amount, 1);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_set_min_amount_non_admin_fails() {
+    let e = Env::default();
+    let (client, owner, _asset) = setup_default_grace_test(&e);
+    client.set_min_amount(&owner, &500);
+}
+
+/// Adds a second 1000-unit commitment for `owner` and one for a stranger on
+/// top of `setup_partial_exit_test`. Returns (second_id, stranger_id).
+fn add_batch_exit_commitments(
+    e: &Env,
+    client: &CommitmentCoreContractClient<'_>,
+    owner: &Address,
+    asset: &Address,
+) -> (String, String) {
+    let stranger = Address::generate(e);
+    StellarAssetClient::new(e, asset).mint(owner, &1000);
+    StellarAssetClient::new(e, asset).mint(&stranger, &1000);
+    let second = client.create_commitment(owner, &1000, asset, &test_rules(e));
+    let foreign = client.create_commitment(&stranger, &1000, asset, &test_rules(e));
+    (second, foreign)
+}
+
+#[test]
+fn test_get_commitments_skips_unknown_ids_in_order() {
+    let e = Env::default();
+    let (client, owner, asset, first) = setup_partial_exit_test(&e);
+    let (second, foreign) = add_batch_exit_commitments(&e, &client, &owner, &asset);
+    let missing = String::from_str(&e, "missing");
+
+    let ids = vec![
+        &e,
+        second.clone(),
+        missing.clone(),
+        first.clone(),
+        foreign.clone(),
+        missing,
+    ];
+    let commitments = client.get_commitments(&ids);
+
+    assert_eq!(commitments.len(), 3);
+    assert_eq!(commitments.get(0).unwrap(), client.get_commitment(&second));
+    assert_eq!(commitments.get(1).unwrap(), client.get_commitment(&first));
+    assert_eq!(commitments.get(2).unwrap(), client.get_commitment(&foreign));
+    assert_eq!(client.get_commitments(&Vec::new(&e)).len(), 0);
+}
+
+#[test]
+fn test_get_commitments_rejects_oversized_list() {
+    let e = Env::default();
+    let (client, _owner, _asset, first) = setup_partial_exit_test(&e);
+    let mut ids = Vec::new(&e);
+    for _ in 0..51 {
+        ids.push_back(first.clone());
+    }
+
+    assert!(client.get_commitments(&ids).is_empty());
+
+    // The rejection is reported rather than rolled back with a panic
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(topics, (Symbol::new(&e, "BatchRejected"),).into_val(&e));
+    let (size, limit, code): (u32, u32, u32) = data.into_val(&e);
+    assert_eq!((size, limit, code), (51, 50, 2));
+}
+
+#[test]
+fn test_batch_early_exit_best_effort_skips_ineligible() {
+    let e = Env::default();
+    let (client, owner, asset, first) = setup_partial_exit_test(&e);
+    let (second, foreign) = add_batch_exit_commitments(&e, &client, &owner, &asset);
+    let missing = String::from_str(&e, "missing");
+
+    let ids = vec![&e, first.clone(), foreign.clone(), second.clone(), missing];
+    let result = client.batch_early_exit(&owner, &ids, &BatchMode::BestEffort);
+
+    // Each exit returns 1000 minus the 5% penalty
+    assert!(!result.success);
+    assert_eq!(result.results, vec![&e, 950i128, 950i128]);
+    assert_eq!(result.errors.len(), 2);
+    let not_owned = result.errors.get(0).unwrap();
+    assert_eq!(not_owned.index, 1);
+    assert_eq!(not_owned.error_code, CommitmentError::Unauthorized as u32);
+    let not_found = result.errors.get(1).unwrap();
+    assert_eq!(not_found.index, 3);
+    assert_eq!(
+        not_found.error_code,
+        CommitmentError::CommitmentNotFound as u32
+    );
+    assert_eq!(result.stats.processed, 4);
+
+    let early_exit = String::from_str(&e, "early_exit");
+    assert_eq!(client.get_commitment(&first).status, early_exit);
+    assert_eq!(client.get_commitment(&second).status, early_exit);
+    assert_eq!(
+        client.get_commitment(&foreign).status,
+        String::from_str(&e, "active")
+    );
+    assert_eq!(TokenClient::new(&e, &asset).balance(&owner), 1900);
+    assert_eq!(client.get_total_value_locked(), 1000);
+}
+
+#[test]
+fn test_batch_early_exit_atomic_aborts_on_inactive() {
+    let e = Env::default();
+    let (client, owner, asset, first) = setup_partial_exit_test(&e);
+    let (second, _foreign) = add_batch_exit_commitments(&e, &client, &owner, &asset);
+    client.early_exit(&second, &owner);
+    let balance_before = TokenClient::new(&e, &asset).balance(&owner);
+
+    let ids = vec![&e, first.clone(), second];
+    let result = client.batch_early_exit(&owner, &ids, &BatchMode::Atomic);
+
+    assert!(!result.success);
+    assert!(result.results.is_empty());
+    assert_eq!(result.errors.len(), 1);
+    let error = result.errors.get(0).unwrap();
+    assert_eq!(error.index, 1);
+    assert_eq!(error.error_code, CommitmentError::NotActive as u32);
+
+    // Nothing was exited, including the eligible first entry
+    assert_eq!(
+        client.get_commitment(&first).status,
+        String::from_str(&e, "active")
+    );
+    assert_eq!(TokenClient::new(&e, &asset).balance(&owner), balance_before);
+}
+
+#[test]
+fn test_batch_early_exit_atomic_rejects_duplicate_ids() {
+    let e = Env::default();
+    let (client, owner, _asset, first) = setup_partial_exit_test(&e);
+
+    let ids = vec![&e, first.clone(), first.clone()];
+    let result = client.batch_early_exit(&owner, &ids, &BatchMode::Atomic);
+
+    assert!(!result.success);
+    assert_eq!(
+        result.errors.get(0).unwrap().error_code,
+        CommitmentError::NotActive as u32
+    );
+    assert_eq!(
+        client.get_commitment(&first).status,
+        String::from_str(&e, "active")
+    );
+}
+
+#[test]
+fn test_batch_early_exit_all_eligible_succeeds() {
+    let e = Env::default();
+    let (client, owner, asset, first) = setup_partial_exit_test(&e);
+    let (second, _foreign) = add_batch_exit_commitments(&e, &client, &owner, &asset);
+
+    let ids = vec![&e, first, second];
+    let result = client.batch_early_exit(&owner, &ids, &BatchMode::Atomic);
+
+    assert!(result.success);
+    assert_eq!(result.results, vec![&e, 950i128, 950i128]);
+    assert_eq!(result.stats.total, 2);
+    assert_eq!(result.stats.skipped, 0);
+}
+
+#[test]
+fn test_transfer_ownership_moves_commitment_to_new_owner() {
+    let e = Env::default();
+    let (client, owner, _asset, commitment_id) = setup_partial_exit_test(&e);
+    let new_owner = Address::generate(&e);
+
+    client.transfer_ownership(&commitment_id, &new_owner);
+
+    // Only the NFT contract's authorization is required
+    let auths = e.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, client.get_nft_contract());
+
+    assert_eq!(client.get_commitment(&commitment_id).owner, new_owner);
+    assert!(client.list_commitments_by_owner(&owner).is_empty());
+    assert_eq!(
+        client.list_commitments_by_owner(&new_owner),
+        vec![&e, commitment_id]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Commitment not found")]
+fn test_transfer_ownership_unknown_commitment_fails() {
+    let e = Env::default();
+    let (client, owner, _asset, _commitment_id) = setup_partial_exit_test(&e);
+
+    client.transfer_ownership(&String::from_str(&e, "missing"), &owner);
+}
+
+#[test]
+fn test_get_expiring_soon_returns_only_window() {
+    let e = Env::default();
+    let (client, owner, asset, _first) = setup_partial_exit_test(&e);
+    StellarAssetClient::new(&e, &asset).mint(&owner, &4000);
+    let mut ids: Vec<String> = Vec::new(&e);
+    for days in [1u32, 3, 5, 10] {
+        let mut rules = test_rules(&e);
+        rules.duration_days = days;
+        ids.push_back(client.create_commitment(&owner, &1000, &asset, &rules));
+    }
+    // Exited commitments are no longer scheduled
+    client.early_exit(&ids.get(1).unwrap(), &owner);
+
+    // The 30-day commitment from setup is outside every window below
+    let window = 5 * 86400;
+    assert_eq!(
+        client.get_expiring_soon(&window, &0, &10),
+        vec![&e, ids.get(0).unwrap(), ids.get(2).unwrap()]
+    );
+    assert_eq!(
+        client.get_expiring_soon(&window, &1, &10),
+        vec![&e, ids.get(2).unwrap()]
+    );
+    assert_eq!(
+        client.get_expiring_soon(&window, &0, &1),
+        vec![&e, ids.get(0).unwrap()]
+    );
+
+    // Once past expiry a commitment drops out of the window
+    e.ledger().with_mut(|l| l.timestamp += 2 * 86400);
+    assert_eq!(
+        client.get_expiring_soon(&window, &0, &10),
+        vec![&e, ids.get(2).unwrap()]
+    );
+    assert!(client.get_expiring_soon(&0, &0, &10).is_empty());
+}
+
+#[test]
+fn test_force_settle_before_expiry_records_final_value() {
+    let e = Env::default();
+    let (client, owner, asset, commitment_id) = setup_partial_exit_test(&e);
+    let admin = client.get_admin();
+
+    client.force_settle(&admin, &commitment_id, &900);
+
+    let commitment = client.get_commitment(&commitment_id);
+    assert_eq!(commitment.status, String::from_str(&e, "settled"));
+    assert_eq!(commitment.current_value, 900);
+    assert_eq!(TokenClient::new(&e, &asset).balance(&owner), 900);
+    assert_eq!(client.get_total_value_locked(), 0);
+
+    let topics: Vec<Val> = (Symbol::new(&e, "ForceSettled"), commitment_id).into_val(&e);
+    let payload = e
+        .events()
+        .all()
+        .iter()
+        .find(|ev| ev.1 == topics)
+        .map(|ev| <(Address, i128, i128, u64)>::try_from_val(&e, &ev.2).unwrap());
+    assert_eq!(payload, Some((admin, 1000, 900, e.ledger().timestamp())));
+}
+
+#[test]
+fn test_force_settle_closes_disputed_commitment() {
+    let e = Env::default();
+    let (client, owner, asset, commitment_id) = setup_partial_exit_test(&e);
+    client.open_dispute(&commitment_id, &owner);
+
+    client.force_settle(&client.get_admin(), &commitment_id, &1000);
+
+    let commitment = client.get_commitment(&commitment_id);
+    assert_eq!(commitment.status, String::from_str(&e, "settled"));
+    assert_eq!(TokenClient::new(&e, &asset).balance(&owner), 1000);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_force_settle_non_admin_fails() {
+    let e = Env::default();
+    let (client, owner, _asset, commitment_id) = setup_partial_exit_test(&e);
+
+    client.force_settle(&owner, &commitment_id, &1000);
+}
+
+#[test]
+#[should_panic(expected = "Final value exceeds the commitment's current value")]
+fn test_force_settle_above_current_value_fails() {
+    let e = Env::default();
+    let (client, _owner, _asset, commitment_id) = setup_partial_exit_test(&e);
+
+    client.force_settle(&client.get_admin(), &commitment_id, &1001);
+}
+
+#[test]
+#[should_panic(expected = "Commitment is not active")]
+fn test_force_settle_already_settled_fails() {
+    let e = Env::default();
+    let (client, _owner, _asset, commitment_id) = setup_partial_exit_test(&e);
+    let admin = client.get_admin();
+    client.force_settle(&admin, &commitment_id, &1000);
+
+    client.force_settle(&admin, &commitment_id, &1000);
+}
+
+#[test]
+fn test_commitment_round_trips_through_serialization() {
+    let e = Env::default();
+    let (client, owner, _asset, commitment_id) = setup_partial_exit_test(&e);
+    let original = client.get_commitment(&commitment_id);
+    let bytes = client.serialize_commitment(&commitment_id);
+    assert_eq!(bytes, client.serialize_commitment(&commitment_id));
+
+    // Restore into a fresh deployment
+    let restored_id = e.register_contract(None, CommitmentCoreContract);
+    let restored = CommitmentCoreContractClient::new(&e, &restored_id);
+    let admin = Address::generate(&e);
+    restored.initialize(&admin, &Address::generate(&e));
+    assert_eq!(
+        restored.deserialize_commitment(&admin, &bytes),
+        commitment_id
+    );
+
+    assert_eq!(restored.get_commitment(&commitment_id), original);
+    assert_eq!(
+        restored.list_commitments_by_owner(&owner),
+        vec![&e, commitment_id.clone()]
+    );
+    assert_eq!(restored.serialize_commitment(&commitment_id), bytes);
+}
+
+#[test]
+fn test_deserialize_commitment_ignores_max_duration() {
+    let e = Env::default();
+    let (client, _owner, _asset, commitment_id) = setup_partial_exit_test(&e);
+    let original = client.get_commitment(&commitment_id);
+    let bytes = client.serialize_commitment(&commitment_id);
+
+    // A cap set after creation must not block restoring the record
+    let admin = client.get_admin();
+    client.set_max_duration_days(&admin, &(original.rules.duration_days - 1));
+    assert_eq!(client.deserialize_commitment(&admin, &bytes), commitment_id);
+    assert_eq!(client.get_commitment(&commitment_id), original);
+}
+
+#[test]
+#[should_panic(expected = "Invalid serialized commitment data")]
+fn test_deserialize_commitment_rejects_other_values() {
+    let e = Env::default();
+    let (client, _owner, _asset, commitment_id) = setup_partial_exit_test(&e);
+
+    client.deserialize_commitment(&client.get_admin(), &commitment_id.to_xdr(&e));
+}
+
+#[test]
+#[should_panic(expected = "Invalid serialized commitment data")]
+fn test_deserialize_commitment_rejects_invalid_record() {
+    let e = Env::default();
+    let (client, _owner, _asset, commitment_id) = setup_partial_exit_test(&e);
+    let mut commitment = client.get_commitment(&commitment_id);
+    commitment.status = String::from_str(&e, "unknown");
+
+    client.deserialize_commitment(&client.get_admin(), &commitment.to_xdr(&e));
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_deserialize_commitment_non_admin_fails() {
+    let e = Env::default();
+    let (client, owner, _asset, commitment_id) = setup_partial_exit_test(&e);
+    let bytes = client.serialize_commitment(&commitment_id);
+
+    client.deserialize_commitment(&owner, &bytes);
+}
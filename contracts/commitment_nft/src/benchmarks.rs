@@ -80,6 +80,7 @@ fn benchmark_mint() {
         let start = e.ledger().sequence();
         let _ = CommitmentNFTContract::mint(
             e.clone(),
+            CommitmentNFTContract::get_admin(e.clone()).unwrap(),
             owner.clone(),
             String::from_str(&e, "commitment_1"),
             30,
@@ -106,6 +107,7 @@ fn benchmark_get_metadata() {
     let token_id = e.as_contract(&contract_id, || {
         CommitmentNFTContract::mint(
             e.clone(),
+            CommitmentNFTContract::get_admin(e.clone()).unwrap(),
             owner.clone(),
             String::from_str(&e, "commitment_1"),
             30,
@@ -139,6 +141,7 @@ fn benchmark_owner_of() {
     let token_id = e.as_contract(&contract_id, || {
         CommitmentNFTContract::mint(
             e.clone(),
+            CommitmentNFTContract::get_admin(e.clone()).unwrap(),
             owner.clone(),
             String::from_str(&e, "commitment_1"),
             30,
@@ -172,6 +175,7 @@ fn benchmark_balance_of() {
     e.as_contract(&contract_id, || {
         CommitmentNFTContract::mint(
             e.clone(),
+            CommitmentNFTContract::get_admin(e.clone()).unwrap(),
             owner.clone(),
             String::from_str(&e, "commitment_1"),
             30,
@@ -221,6 +225,7 @@ fn benchmark_batch_mint() {
         e.as_contract(&contract_id, || {
             let _ = CommitmentNFTContract::mint(
                 e.clone(),
+                CommitmentNFTContract::get_admin(e.clone()).unwrap(),
                 owner.clone(),
                 String::from_str(&e, commitment_id),
                 30,
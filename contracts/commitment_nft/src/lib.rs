@@ -1,8 +1,11 @@
 #![no_std]
-use shared_utils::{EmergencyControl, Pausable};
+use shared_utils::{
+    BatchError, BatchMode, BatchProcessor, BatchResultVoid, EmergencyControl, Pausable,
+};
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, Address, BytesN, Env,
-    String, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short,
+    xdr::{ScErrorCode, ScErrorType},
+    Address, BytesN, Env, IntoVal, String, Symbol, Vec,
 };
 
 // Current storage version for migration checks.
@@ -11,6 +14,21 @@ const CURRENT_VERSION: u32 = 1;
 // Issue #139: String parameter constraints
 const MAX_COMMITMENT_ID_LENGTH: u32 = 256;
 
+/// Maximum number of NFTs returned per page by paginated queries.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Maximum number of NFTs moved by a single `transfer_all` call.
+pub const MAX_TRANSFER_ALL: u32 = 50;
+
+/// Maximum number of lifecycle entries kept per token (oldest are dropped).
+pub const MAX_TOKEN_LOG: u32 = 20;
+
+/// Maximum length in bytes of a `transfer_with_memo` memo.
+pub const MAX_MEMO_LENGTH: u32 = 128;
+
+/// Width of an expiry bucket: tokens are indexed by `expires_at / SECONDS_PER_DAY`.
+pub const SECONDS_PER_DAY: u64 = 86400;
+
 // ============================================================================
 // Error Types
 // ============================================================================
@@ -62,6 +80,36 @@ pub enum ContractError {
     ExpirationOverflow = 20,
     /// Invalid commitment_id (must be non-empty and <= 256 chars)
     InvalidCommitmentId = 21,
+    /// NFT is not under review and cannot be reactivated
+    NotUnderReview = 22,
+    /// Explicit timestamps are invalid (expires_at must be after created_at)
+    InvalidTimestamps = 23,
+    /// Minting is paused; transfers and settlement remain available
+    MintingPaused = 24,
+    /// Metadata is locked by the owner and cannot be edited
+    MetadataLocked = 25,
+    /// Contract has been permanently terminated; only reads are allowed
+    ContractTerminated = 26,
+    /// Batch is empty, too large, or batch operations are disabled
+    InvalidBatch = 27,
+    /// Transfer allowlist is enabled and the recipient is not on it
+    RecipientNotAllowed = 28,
+    /// A counter or balance would exceed its numeric range
+    Overflow = 29,
+    /// A counter or balance would drop below zero
+    Underflow = 30,
+    /// The minter has used up its mint quota
+    QuotaExceeded = 31,
+    /// The core contract has no commitment with this ID
+    CommitmentNotFound = 32,
+    /// The core contract rejected the commitment ownership update
+    CorePropagationFailed = 33,
+    /// Transfer memo exceeds MAX_MEMO_LENGTH
+    MemoTooLong = 34,
+    /// The owner has self-locked the token against transfers until a later time
+    SelfLocked = 35,
+    /// Unlock time is not in the future or would shorten an existing self-lock
+    InvalidUnlockTime = 36,
 }
 
 // ============================================================================
@@ -93,6 +141,70 @@ pub struct CommitmentNFT {
     pub early_exit_penalty: u32,
 }
 
+/// Lifecycle status of an NFT
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenStatus {
+    /// Commitment is live
+    Active,
+    /// Temporarily deactivated by the admin; can be reactivated
+    UnderReview,
+    /// Settled or exited; terminal
+    Settled,
+}
+
+/// Consolidated view of a token returned by `get_nft_state`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NftState {
+    pub owner: Address,
+    pub is_active: bool,
+    pub is_expired: bool,
+    pub metadata: CommitmentMetadata,
+}
+
+/// Lifecycle step of a token recorded in its log
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenLogEntry {
+    /// `mint`, `transfer` or `settle`
+    pub event_type: Symbol,
+    pub timestamp: u64,
+    /// Owner after the step (the recipient, for transfers)
+    pub counterparty: Address,
+}
+
+/// A transfer made with `transfer_with_memo`, kept in the token's memo log
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferMemo {
+    pub from: Address,
+    pub to: Address,
+    pub memo: String,
+    pub timestamp: u64,
+}
+
+/// Cross-contract wiring reported by `link_status`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LinkStatus {
+    /// A commitment_core reference is configured (always true on the core itself)
+    pub core_set: bool,
+    /// A commitment_nft reference is configured (always true on the NFT itself)
+    pub nft_set: bool,
+    /// Every configured reference answered a readiness call
+    pub reachable: bool,
+}
+
+/// A chunk of `export_state`, resumable from `next_cursor`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StateChunk {
+    pub nfts: Vec<CommitmentNFT>,
+    /// Cursor for the next call; `None` once every minted id has been visited.
+    pub next_cursor: Option<u32>,
+}
+
 /// Parameters for batch NFT transfer operations
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -127,6 +239,44 @@ pub enum DataKey {
     ReentrancyGuard,
     /// Contract version
     Version,
+    /// Token temporarily deactivated for review (token_id -> bool)
+    UnderReview(u32),
+    /// Owner-held lock against admin metadata edits (token_id -> bool)
+    MetadataLock(u32),
+    /// Irreversible kill-switch flag (bool)
+    Terminated,
+    /// Address that minted the token (token_id -> Address)
+    Minter(u32),
+    /// Whether transfers are restricted to allowlisted recipients (bool)
+    TransferAllowlistEnabled,
+    /// Recipient allowed while the transfer allowlist is enabled (Address -> bool)
+    Allowlisted(Address),
+    /// Recent lifecycle entries for a token (token_id -> Vec<TokenLogEntry>)
+    TokenLog(u32),
+    /// Mints left for a minter; absent means unlimited (Address -> u32)
+    MinterQuota(Address),
+    /// Enumerable list of authorized minters, kept in sync with AuthorizedMinter (Vec<Address>)
+    Minters,
+    /// Whether `mint` checks the commitment exists in the core contract (bool)
+    RequireCoreCommitment,
+    /// Whether transfers update the commitment owner in the core contract (bool)
+    PropagateOwnership,
+    /// Address approved to transfer a token; cleared on transfer (u32 -> Address)
+    TokenApproval(u32),
+    /// Number of active NFTs (u32)
+    ActiveSupply,
+    /// Number of settled NFTs, including ones closed by `mark_inactive` (u32)
+    SettledCount,
+    /// Recent memo transfers of a token (token_id -> Vec<TransferMemo>)
+    TransferMemos(u32),
+    /// Owner-imposed transfer lock, until a timestamp (token_id -> u64)
+    SelfLock(u32),
+    /// Tokens expiring on a day, in mint order (expires_at / SECONDS_PER_DAY -> Vec<u32>)
+    ExpiryBucket(u64),
+    /// Number of burned NFTs; `TokenCounter - BurnedCount` tokens still exist (u32)
+    BurnedCount,
+    /// Operator allowed to transfer any of an owner's tokens ((owner, operator) -> bool)
+    OperatorApproval(Address, Address),
 }
 
 // Events
@@ -170,6 +320,9 @@ impl CommitmentNFTContract {
 
     /// Pause the contract
     pub fn pause(e: Env) {
+        if is_terminated(&e) {
+            panic!("Contract terminated");
+        }
         let admin: Address = e
             .storage()
             .instance()
@@ -181,6 +334,9 @@ impl CommitmentNFTContract {
 
     /// Unpause the contract
     pub fn unpause(e: Env) {
+        if is_terminated(&e) {
+            panic!("Contract terminated");
+        }
         let admin: Address = e
             .storage()
             .instance()
@@ -195,6 +351,36 @@ impl CommitmentNFTContract {
         Pausable::is_paused(&e)
     }
 
+    /// Pause or resume minting only (admin-only).
+    ///
+    /// Unlike `pause`, transfers and settlement of existing tokens keep working.
+    pub fn set_mint_paused(e: Env, admin: Address, paused: bool) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        require_admin(&e, &admin)?;
+        Pausable::set_mint_paused(&e, paused);
+        Ok(())
+    }
+
+    /// Check if minting is paused, either on its own or by a full pause
+    pub fn is_mint_paused(e: Env) -> bool {
+        Pausable::is_mint_paused(&e)
+    }
+
+    /// Pause or resume transfers only (admin-only).
+    ///
+    /// Unlike `pause`, minting and settlement keep working.
+    pub fn set_transfer_paused(e: Env, admin: Address, paused: bool) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        require_admin(&e, &admin)?;
+        Pausable::set_transfer_paused(&e, paused);
+        Ok(())
+    }
+
+    /// Check if transfers are paused, either on their own or by a full pause
+    pub fn is_transfer_paused(e: Env) -> bool {
+        Pausable::is_transfer_paused(&e)
+    }
+
     /// Validate commitment type
     fn is_valid_commitment_type(e: &Env, commitment_type: &String) -> bool {
         let safe = String::from_str(e, "safe");
@@ -212,6 +398,7 @@ impl CommitmentNFTContract {
     /// Set the authorized commitment_core contract address for settlement
     /// Only the admin can call this function
     pub fn set_core_contract(e: Env, core_contract: Address) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
         let admin: Address = e
             .storage()
             .instance()
@@ -238,6 +425,92 @@ impl CommitmentNFTContract {
             .ok_or(ContractError::NotInitialized)
     }
 
+    /// Report whether the core contract is configured and initialized; a
+    /// readiness signal for deployment tooling.
+    pub fn link_status(e: Env) -> LinkStatus {
+        let core = e
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::CoreContract);
+        let reachable = core.as_ref().is_some_and(|core| {
+            matches!(
+                e.try_invoke_contract::<bool, soroban_sdk::Error>(
+                    core,
+                    &Symbol::new(&e, "is_initialized"),
+                    Vec::new(&e),
+                ),
+                Ok(Ok(true))
+            )
+        });
+        LinkStatus {
+            core_set: core.is_some(),
+            nft_set: true,
+            reachable,
+        }
+    }
+
+    /// Require `mint` to confirm the commitment exists in the core contract
+    /// (admin-only). Off by default; has no effect until a core contract is set.
+    pub fn set_require_core_commitment(
+        e: Env,
+        admin: Address,
+        enabled: bool,
+    ) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        require_admin(&e, &admin)?;
+        e.storage()
+            .instance()
+            .set(&DataKey::RequireCoreCommitment, &enabled);
+        e.events().publish(
+            (Symbol::new(&e, "RequireCoreCommitment"),),
+            (enabled, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Check whether `mint` verifies the commitment exists in the core contract
+    pub fn is_core_commitment_required(e: Env) -> bool {
+        e.storage()
+            .instance()
+            .get(&DataKey::RequireCoreCommitment)
+            .unwrap_or(false)
+    }
+
+    /// Make transfers update the commitment owner in the core contract
+    /// (admin-only). Off by default; has no effect until a core contract is
+    /// set. While on, a transfer fails with `CorePropagationFailed` if the
+    /// core rejects the update.
+    pub fn set_propagate_ownership(
+        e: Env,
+        admin: Address,
+        enabled: bool,
+    ) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        require_admin(&e, &admin)?;
+        e.storage()
+            .instance()
+            .set(&DataKey::PropagateOwnership, &enabled);
+        e.events().publish(
+            (Symbol::new(&e, "PropagateOwnership"),),
+            (enabled, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Check whether transfers update the commitment owner in the core contract
+    pub fn is_ownership_propagated(e: Env) -> bool {
+        e.storage()
+            .instance()
+            .get(&DataKey::PropagateOwnership)
+            .unwrap_or(false)
+    }
+
+    /// Check whether `initialize` has run (the admin is set); a readiness
+    /// probe for deployment tooling.
+    pub fn is_initialized(e: Env) -> bool {
+        e.storage().instance().has(&DataKey::Admin)
+    }
+
     /// Get the admin address
     pub fn get_admin(e: Env) -> Result<Address, ContractError> {
         e.storage()
@@ -246,6 +519,49 @@ impl CommitmentNFTContract {
             .ok_or(ContractError::NotInitialized)
     }
 
+    /// Check whether the reentrancy guard is currently set.
+    pub fn is_locked(e: Env) -> bool {
+        e.storage()
+            .instance()
+            .get(&DataKey::ReentrancyGuard)
+            .unwrap_or(false)
+    }
+
+    /// Clear a reentrancy guard left set by an aborted operation (admin-only).
+    pub fn force_unlock(e: Env, admin: Address) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        require_admin(&e, &admin)?;
+        e.storage()
+            .instance()
+            .set(&DataKey::ReentrancyGuard, &false);
+        e.events().publish(
+            (Symbol::new(&e, "GuardForceCleared"),),
+            (admin, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Permanently disable every state-mutating entrypoint (admin-only).
+    ///
+    /// Unlike `pause`, this cannot be undone: afterwards all mutations return
+    /// `ContractTerminated` while queries keep working so holders can still
+    /// read their positions.
+    pub fn terminate(e: Env, admin: Address) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        require_admin(&e, &admin)?;
+        e.storage().instance().set(&DataKey::Terminated, &true);
+        e.events().publish(
+            (Symbol::new(&e, "Terminated"),),
+            (admin, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Check if the contract has been terminated
+    pub fn is_terminated(e: Env) -> bool {
+        is_terminated(&e)
+    }
+
     /// Get current on-chain version (0 if legacy/uninitialized).
     pub fn get_version(e: Env) -> u32 {
         read_version(&e)
@@ -253,6 +569,7 @@ impl CommitmentNFTContract {
 
     /// Update admin (admin-only).
     pub fn set_admin(e: Env, caller: Address, new_admin: Address) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
         require_admin(&e, &caller)?;
         e.storage().instance().set(&DataKey::Admin, &new_admin);
         Ok(())
@@ -264,6 +581,7 @@ impl CommitmentNFTContract {
         caller: Address,
         new_wasm_hash: BytesN<32>,
     ) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
         require_admin(&e, &caller)?;
         require_valid_wasm_hash(&e, &new_wasm_hash)?;
         e.deployer().update_current_contract_wasm(new_wasm_hash);
@@ -272,6 +590,7 @@ impl CommitmentNFTContract {
 
     /// Migrate storage from a previous version to CURRENT_VERSION (admin-only).
     pub fn migrate(e: Env, caller: Address, from_version: u32) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
         require_admin(&e, &caller)?;
 
         let stored_version = read_version(&e);
@@ -295,6 +614,7 @@ impl CommitmentNFTContract {
                 .instance()
                 .set(&DataKey::ReentrancyGuard, &false);
         }
+        seed_supply_counters(&e);
 
         e.storage()
             .instance()
@@ -309,7 +629,8 @@ impl CommitmentNFTContract {
     /// Mint a new Commitment NFT
     ///
     /// # Arguments
-    /// * `caller` - The address calling the mint function (must be authorized)
+    /// * `caller` - The admin, the core contract or an authorized minter (see
+    ///   `add_minter`); recorded as the token's minter and charged against its quota
     /// * `owner` - The address that will own the NFT
     /// * `commitment_id` - Unique identifier for the commitment
     /// * `duration_days` - Duration of the commitment in days
@@ -326,6 +647,7 @@ impl CommitmentNFTContract {
     /// and doesn't make external calls, but still protected for consistency.
     pub fn mint(
         e: Env,
+        caller: Address,
         owner: Address,
         commitment_id: String,
         duration_days: u32,
@@ -335,6 +657,9 @@ impl CommitmentNFTContract {
         asset_address: Address,
         early_exit_penalty: u32,
     ) -> Result<u32, ContractError> {
+        require_not_terminated(&e)?;
+        caller.require_auth();
+
         // Reentrancy protection
         let guard: bool = e
             .storage()
@@ -350,45 +675,46 @@ impl CommitmentNFTContract {
 
         // Check if contract is paused
         Pausable::require_not_paused(&e);
-
-        // CHECKS: Verify contract is initialized
-        if !e.storage().instance().has(&DataKey::Admin) {
+        if Pausable::is_mint_paused(&e) {
             e.storage()
                 .instance()
                 .set(&DataKey::ReentrancyGuard, &false);
-            return Err(ContractError::NotInitialized);
+            return Err(ContractError::MintingPaused);
         }
 
-        // Validate inputs
-        if duration_days == 0 {
-            e.storage()
-                .instance()
-                .set(&DataKey::ReentrancyGuard, &false);
-            return Err(ContractError::InvalidDuration);
-        }
-        if max_loss_percent > 100 {
+        // CHECKS: Verify contract is initialized
+        if !e.storage().instance().has(&DataKey::Admin) {
             e.storage()
                 .instance()
                 .set(&DataKey::ReentrancyGuard, &false);
-            return Err(ContractError::InvalidMaxLoss);
+            return Err(ContractError::NotInitialized);
         }
-        if !Self::is_valid_commitment_type(&e, &commitment_type) {
+        if !can_mint(&e, &caller) {
             e.storage()
                 .instance()
                 .set(&DataKey::ReentrancyGuard, &false);
-            return Err(ContractError::InvalidCommitmentType);
+            return Err(ContractError::NotAuthorized);
         }
-        if !Self::is_valid_commitment_id(&e, &commitment_id) {
+
+        // Validate inputs
+        if let Err(err) = Self::validate_mint_args(
+            &e,
+            &commitment_id,
+            duration_days,
+            max_loss_percent,
+            &commitment_type,
+            initial_amount,
+        ) {
             e.storage()
                 .instance()
                 .set(&DataKey::ReentrancyGuard, &false);
-            return Err(ContractError::InvalidCommitmentId);
+            return Err(err);
         }
-        if initial_amount <= 0 {
+        if !core_commitment_exists(&e, &commitment_id) {
             e.storage()
                 .instance()
                 .set(&DataKey::ReentrancyGuard, &false);
-            return Err(ContractError::InvalidAmount);
+            return Err(ContractError::CommitmentNotFound);
         }
 
         // Calculate timestamps with overflow check (duration_days * 86400 + created_at must fit in u64)
@@ -414,18 +740,6 @@ impl CommitmentNFTContract {
         };
 
         // EFFECTS: Update state
-        // Generate unique token_id
-        let token_id: u32 = e
-            .storage()
-            .instance()
-            .get(&DataKey::TokenCounter)
-            .unwrap_or(0);
-        let next_token_id = token_id + 1;
-        e.storage()
-            .instance()
-            .set(&DataKey::TokenCounter, &next_token_id);
-
-        // Create CommitmentMetadata
         let metadata = CommitmentMetadata {
             commitment_id: commitment_id.clone(),
             duration_days,
@@ -436,6 +750,87 @@ impl CommitmentNFTContract {
             initial_amount,
             asset_address,
         };
+        let token_id = Self::store_new_nft(&e, &owner, &caller, metadata, early_exit_penalty)
+            .inspect_err(|_| {
+                e.storage()
+                    .instance()
+                    .set(&DataKey::ReentrancyGuard, &false);
+            })?;
+
+        // Clear reentrancy guard
+        e.storage()
+            .instance()
+            .set(&DataKey::ReentrancyGuard, &false);
+
+        // Emit mint event
+        e.events().publish(
+            (symbol_short!("Mint"), token_id, owner.clone()),
+            (commitment_id, e.ledger().timestamp()),
+        );
+
+        Ok(token_id)
+    }
+
+    /// Validate the commitment parameters shared by all mint paths.
+    fn validate_mint_args(
+        e: &Env,
+        commitment_id: &String,
+        duration_days: u32,
+        max_loss_percent: u32,
+        commitment_type: &String,
+        initial_amount: i128,
+    ) -> Result<(), ContractError> {
+        if duration_days == 0 {
+            return Err(ContractError::InvalidDuration);
+        }
+        if max_loss_percent > 100 {
+            return Err(ContractError::InvalidMaxLoss);
+        }
+        if !Self::is_valid_commitment_type(e, commitment_type) {
+            return Err(ContractError::InvalidCommitmentType);
+        }
+        if !Self::is_valid_commitment_id(e, commitment_id) {
+            return Err(ContractError::InvalidCommitmentId);
+        }
+        if initial_amount <= 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+        Ok(())
+    }
+
+    /// Allocate the next token_id, store the NFT and update owner/token indexes.
+    fn store_new_nft(
+        e: &Env,
+        owner: &Address,
+        minter: &Address,
+        metadata: CommitmentMetadata,
+        early_exit_penalty: u32,
+    ) -> Result<u32, ContractError> {
+        // Generate unique token_id
+        let token_id: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::TokenCounter)
+            .unwrap_or(0);
+        let next_token_id = token_id.checked_add(1).ok_or(ContractError::Overflow)?;
+        let current_balance: u32 = e
+            .storage()
+            .persistent()
+            .get(&DataKey::OwnerBalance(owner.clone()))
+            .unwrap_or(0);
+        let new_balance = current_balance
+            .checked_add(1)
+            .ok_or(ContractError::Overflow)?;
+        let quota_key = DataKey::MinterQuota(minter.clone());
+        if let Some(remaining) = e.storage().persistent().get::<_, u32>(&quota_key) {
+            if remaining == 0 {
+                return Err(ContractError::QuotaExceeded);
+            }
+            e.storage().persistent().set(&quota_key, &(remaining - 1));
+        }
+        e.storage()
+            .instance()
+            .set(&DataKey::TokenCounter, &next_token_id);
 
         // Create CommitmentNFT
         let nft = CommitmentNFT {
@@ -448,24 +843,21 @@ impl CommitmentNFTContract {
 
         // Store NFT data
         e.storage().persistent().set(&DataKey::NFT(token_id), &nft);
+        e.storage()
+            .persistent()
+            .set(&DataKey::Minter(token_id), minter);
 
         // Update owner balance
-        let current_balance: u32 = e
-            .storage()
+        e.storage()
             .persistent()
-            .get(&DataKey::OwnerBalance(owner.clone()))
-            .unwrap_or(0);
-        e.storage().persistent().set(
-            &DataKey::OwnerBalance(owner.clone()),
-            &(current_balance + 1),
-        );
+            .set(&DataKey::OwnerBalance(owner.clone()), &new_balance);
 
         // Update owner tokens list
         let mut owner_tokens: Vec<u32> = e
             .storage()
             .persistent()
             .get(&DataKey::OwnerTokens(owner.clone()))
-            .unwrap_or(Vec::new(&e));
+            .unwrap_or(Vec::new(e));
         owner_tokens.push_back(token_id);
         e.storage()
             .persistent()
@@ -476,18 +868,93 @@ impl CommitmentNFTContract {
             .storage()
             .instance()
             .get(&DataKey::TokenIds)
-            .unwrap_or(Vec::new(&e));
+            .unwrap_or(Vec::new(e));
         token_ids.push_back(token_id);
         e.storage().instance().set(&DataKey::TokenIds, &token_ids);
 
-        // Clear reentrancy guard
+        // Index by expiry day for keeper scheduling
+        let bucket_key = DataKey::ExpiryBucket(nft.metadata.expires_at / SECONDS_PER_DAY);
+        let mut bucket: Vec<u32> = e
+            .storage()
+            .persistent()
+            .get(&bucket_key)
+            .unwrap_or(Vec::new(e));
+        bucket.push_back(token_id);
+        e.storage().persistent().set(&bucket_key, &bucket);
+        update_supply_counter(e, DataKey::ActiveSupply, true);
+        append_token_log(e, token_id, symbol_short!("mint"), owner);
+
+        Ok(token_id)
+    }
+
+    /// Mint an NFT with explicit timestamps, for backfilling commitments created
+    /// off-chain (admin-only).
+    ///
+    /// Unlike `mint`, `metadata.created_at` and `metadata.expires_at` are stored as
+    /// given instead of being derived from the ledger time and `duration_days`.
+    pub fn mint_backdated(
+        e: Env,
+        admin: Address,
+        owner: Address,
+        metadata: CommitmentMetadata,
+        early_exit_penalty: u32,
+    ) -> Result<u32, ContractError> {
+        require_not_terminated(&e)?;
+        require_admin(&e, &admin)?;
+
+        // Reentrancy protection
+        let guard: bool = e
+            .storage()
+            .instance()
+            .get(&DataKey::ReentrancyGuard)
+            .unwrap_or(false);
+        if guard {
+            return Err(ContractError::ReentrancyDetected);
+        }
+        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
+        EmergencyControl::require_not_emergency(&e);
+        Pausable::require_not_paused(&e);
+        if Pausable::is_mint_paused(&e) {
+            e.storage()
+                .instance()
+                .set(&DataKey::ReentrancyGuard, &false);
+            return Err(ContractError::MintingPaused);
+        }
+
+        let validation = Self::validate_mint_args(
+            &e,
+            &metadata.commitment_id,
+            metadata.duration_days,
+            metadata.max_loss_percent,
+            &metadata.commitment_type,
+            metadata.initial_amount,
+        );
+        let validation = if validation.is_ok() && metadata.expires_at <= metadata.created_at {
+            Err(ContractError::InvalidTimestamps)
+        } else {
+            validation
+        };
+        if let Err(err) = validation {
+            e.storage()
+                .instance()
+                .set(&DataKey::ReentrancyGuard, &false);
+            return Err(err);
+        }
+
+        let commitment_id = metadata.commitment_id.clone();
+        let token_id = Self::store_new_nft(&e, &owner, &admin, metadata, early_exit_penalty)
+            .inspect_err(|_| {
+                e.storage()
+                    .instance()
+                    .set(&DataKey::ReentrancyGuard, &false);
+            })?;
+
         e.storage()
             .instance()
             .set(&DataKey::ReentrancyGuard, &false);
 
-        // Emit mint event
         e.events().publish(
-            (symbol_short!("Mint"), token_id, owner.clone()),
+            (symbol_short!("Mint"), token_id, owner),
             (commitment_id, e.ledger().timestamp()),
         );
 
@@ -506,18 +973,28 @@ impl CommitmentNFTContract {
             .ok_or(ContractError::TokenNotFound)
     }
 
-    /// Get owner of NFT
-    pub fn owner_of(e: Env, token_id: u32) -> Result<Address, ContractError> {
+    /// Get the commitment id a token represents
+    pub fn get_commitment_id(e: Env, token_id: u32) -> Result<String, ContractError> {
         let nft: CommitmentNFT = e
             .storage()
             .persistent()
             .get(&DataKey::NFT(token_id))
             .ok_or(ContractError::TokenNotFound)?;
-
-        Ok(nft.owner)
+        Ok(nft.metadata.commitment_id)
     }
 
-    /// Transfer NFT to new owner
+    /// Get owner of NFT
+    pub fn owner_of(e: Env, token_id: u32) -> Result<Address, ContractError> {
+        let nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+
+        Ok(nft.owner)
+    }
+
+    /// Transfer NFT to new owner
     ///
     /// # Reentrancy Protection
     /// Uses checks-effects-interactions pattern. This function only writes to storage
@@ -527,6 +1004,439 @@ impl CommitmentNFTContract {
         from: Address,
         to: Address,
         token_id: u32,
+    ) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        // CHECKS: Require authorization from the sender
+        from.require_auth();
+        Self::transfer_token(e, from, to, token_id)
+    }
+
+    /// Transfer an NFT like `transfer`, attaching a reference such as an
+    /// invoice or ticket number.
+    ///
+    /// The memo is published in a `TransferMemo` event next to the regular
+    /// `Transfer` event and kept in the token's memo log (at most
+    /// MAX_TOKEN_LOG entries). Fails with `MemoTooLong` beyond MAX_MEMO_LENGTH.
+    pub fn transfer_with_memo(
+        e: Env,
+        from: Address,
+        to: Address,
+        token_id: u32,
+        memo: String,
+    ) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        if memo.len() > MAX_MEMO_LENGTH {
+            return Err(ContractError::MemoTooLong);
+        }
+        from.require_auth();
+        Self::transfer_token(e.clone(), from.clone(), to.clone(), token_id)?;
+
+        let key = DataKey::TransferMemos(token_id);
+        let mut memos: Vec<TransferMemo> =
+            e.storage().persistent().get(&key).unwrap_or(Vec::new(&e));
+        memos.push_back(TransferMemo {
+            from: from.clone(),
+            to: to.clone(),
+            memo: memo.clone(),
+            timestamp: e.ledger().timestamp(),
+        });
+        while memos.len() > MAX_TOKEN_LOG {
+            memos.pop_front();
+        }
+        e.storage().persistent().set(&key, &memos);
+
+        e.events().publish(
+            (Symbol::new(&e, "TransferMemo"), from, to),
+            (token_id, memo, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Get the most recent `limit` memo transfers of a token, oldest first.
+    pub fn get_transfer_memos(e: Env, token_id: u32, limit: u32) -> Vec<TransferMemo> {
+        let memos: Vec<TransferMemo> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::TransferMemos(token_id))
+            .unwrap_or(Vec::new(&e));
+        let start = memos.len().saturating_sub(limit);
+        memos.slice(start..)
+    }
+
+    /// Transfer several NFTs atomically
+    ///
+    /// Each distinct sender is asked for authorization once, however many
+    /// tokens they move. If any entry fails, the whole batch is reverted and
+    /// that entry's error is returned. An empty or oversized batch fails with
+    /// `InvalidBatch`; since a failed call keeps no events, no
+    /// `BatchRejected` is published for it.
+    pub fn batch_transfer(e: Env, transfers: Vec<TransferParams>) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+
+        let contract_name = String::from_str(&e, "commitment_nft");
+        if BatchProcessor::enforce_batch_limits(&e, transfers.len(), Some(contract_name)).is_err() {
+            return Err(ContractError::InvalidBatch);
+        }
+
+        let mut senders: Vec<Address> = Vec::new(&e);
+        for params in transfers.iter() {
+            if !senders.contains(&params.from) {
+                params.from.require_auth();
+                senders.push_back(params.from);
+            }
+        }
+
+        for params in transfers.iter() {
+            Self::transfer_token(e.clone(), params.from, params.to, params.token_id)?;
+        }
+        Ok(())
+    }
+
+    /// Approve `spender` to transfer one of `owner`'s tokens
+    ///
+    /// Replaces any previous approval for the token; the approval is cleared
+    /// when the token changes hands.
+    pub fn approve(
+        e: Env,
+        owner: Address,
+        spender: Address,
+        token_id: u32,
+    ) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        require_token_owner(&e, &owner, token_id)?;
+
+        e.storage()
+            .persistent()
+            .set(&DataKey::TokenApproval(token_id), &spender);
+        e.events().publish(
+            (symbol_short!("Approval"), owner, spender),
+            (token_id, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Approve `spender` for several of `owner`'s tokens at once
+    ///
+    /// The owner is asked for authorization once. Each approval replaces any
+    /// previous one for that token and is cleared when the token changes
+    /// hands. In `Atomic` mode a missing or non-owned token aborts before any
+    /// approval is written; in `BestEffort` mode it is skipped and reported.
+    pub fn batch_approve(
+        e: Env,
+        owner: Address,
+        spender: Address,
+        token_ids: Vec<u32>,
+        mode: BatchMode,
+    ) -> BatchResultVoid {
+        if let Err(err) = require_not_terminated(&e) {
+            let mut errors = Vec::new(&e);
+            errors.push_back(BatchError {
+                index: 0,
+                error_code: err as u32,
+                context: String::from_str(&e, "terminated"),
+            });
+            return BatchResultVoid::failure(&e, errors).with_stats(token_ids.len(), 0);
+        }
+        owner.require_auth();
+
+        let batch_size = token_ids.len();
+        let contract_name = String::from_str(&e, "commitment_nft");
+        if let Err(error_code) =
+            BatchProcessor::enforce_batch_limits(&e, batch_size, Some(contract_name.clone()))
+        {
+            BatchProcessor::emit_batch_rejected(&e, batch_size, Some(contract_name), error_code);
+            let mut errors = Vec::new(&e);
+            errors.push_back(BatchError {
+                index: 0,
+                error_code,
+                context: String::from_str(&e, "batch_size_validation"),
+            });
+            return BatchResultVoid::failure(&e, errors).with_stats(batch_size, 0);
+        }
+
+        // CHECKS: validate every token before writing any approval
+        let mut errors = Vec::new(&e);
+        let mut approved: Vec<u32> = Vec::new(&e);
+        for i in 0..batch_size {
+            let token_id = token_ids.get(i).unwrap();
+            let nft: Option<CommitmentNFT> = e.storage().persistent().get(&DataKey::NFT(token_id));
+            let failure = match nft {
+                None => Some((ContractError::TokenNotFound, "token_not_found")),
+                Some(nft) if nft.owner != owner => Some((ContractError::NotOwner, "not_owner")),
+                Some(_) => None,
+            };
+            match failure {
+                Some((err, context)) => {
+                    errors.push_back(BatchError {
+                        index: i,
+                        error_code: err as u32,
+                        context: String::from_str(&e, context),
+                    });
+                    if mode == BatchMode::Atomic {
+                        return BatchResultVoid::failure(&e, errors).with_stats(batch_size, i + 1);
+                    }
+                }
+                None => approved.push_back(token_id),
+            }
+        }
+
+        // EFFECTS: record the approvals
+        for token_id in approved.iter() {
+            e.storage()
+                .persistent()
+                .set(&DataKey::TokenApproval(token_id), &spender);
+            e.events().publish(
+                (symbol_short!("Approval"), owner.clone(), spender.clone()),
+                (token_id, e.ledger().timestamp()),
+            );
+        }
+
+        BatchResultVoid::partial(approved.len(), errors)
+    }
+
+    /// Get the address approved to transfer a token, if any
+    pub fn get_approved(e: Env, token_id: u32) -> Option<Address> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::TokenApproval(token_id))
+    }
+
+    /// Transfer an NFT on behalf of its owner
+    ///
+    /// `spender` must authorize the call and be `from` itself, the token's
+    /// approved address or an operator approved for `from`; otherwise fails
+    /// with `NotAuthorized`. A per-token approval is consumed by the transfer.
+    pub fn transfer_from(
+        e: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        token_id: u32,
+    ) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        spender.require_auth();
+        if spender != from
+            && !Self::is_approved_for_all(e.clone(), from.clone(), spender.clone())
+            && Self::get_approved(e.clone(), token_id) != Some(spender)
+        {
+            return Err(ContractError::NotAuthorized);
+        }
+        Self::transfer_token(e, from, to, token_id)
+    }
+
+    /// Grant or revoke `operator`'s right to transfer all of `owner`'s tokens
+    pub fn set_approval_for_all(
+        e: Env,
+        owner: Address,
+        operator: Address,
+        approved: bool,
+    ) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        owner.require_auth();
+
+        let key = DataKey::OperatorApproval(owner.clone(), operator.clone());
+        if approved {
+            e.storage().persistent().set(&key, &true);
+        } else {
+            e.storage().persistent().remove(&key);
+        }
+        e.events().publish(
+            (Symbol::new(&e, "ApprovalForAll"), owner, operator),
+            (approved, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Check whether `operator` may transfer any of `owner`'s tokens
+    pub fn is_approved_for_all(e: Env, owner: Address, operator: Address) -> bool {
+        e.storage()
+            .persistent()
+            .get(&DataKey::OperatorApproval(owner, operator))
+            .unwrap_or(false)
+    }
+
+    /// Transfer an owner's transferable NFTs to a new address
+    ///
+    /// Moves up to `MAX_TRANSFER_ALL` tokens per call; locked tokens (active,
+    /// under review or self-locked) are skipped and stay with `from`. Call repeatedly until it
+    /// returns 0 to migrate a large collection.
+    ///
+    /// # Returns
+    /// The number of tokens transferred
+    pub fn transfer_all(e: Env, from: Address, to: Address) -> Result<u32, ContractError> {
+        require_not_terminated(&e)?;
+        if e.storage()
+            .instance()
+            .get(&DataKey::ReentrancyGuard)
+            .unwrap_or(false)
+        {
+            return Err(ContractError::ReentrancyDetected);
+        }
+        EmergencyControl::require_not_emergency(&e);
+        Pausable::require_transfer_not_paused(&e);
+
+        from.require_auth();
+        if to == from {
+            return Err(ContractError::TransferToZeroAddress);
+        }
+        if !is_recipient_allowed(&e, &to) {
+            return Err(ContractError::RecipientNotAllowed);
+        }
+
+        // Read both owners' indexes once and write them back once
+        let from_tokens: Vec<u32> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::OwnerTokens(from.clone()))
+            .unwrap_or(Vec::new(&e));
+        let mut to_tokens: Vec<u32> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::OwnerTokens(to.clone()))
+            .unwrap_or(Vec::new(&e));
+        let mut kept: Vec<u32> = Vec::new(&e);
+        let mut moved: u32 = 0;
+        let mut moved_commitments: Vec<String> = Vec::new(&e);
+
+        for token_id in from_tokens.iter() {
+            let nft: Option<CommitmentNFT> = e.storage().persistent().get(&DataKey::NFT(token_id));
+            let Some(mut nft) = nft else {
+                continue;
+            };
+            if moved >= MAX_TRANSFER_ALL
+                || nft.is_active
+                || is_under_review(&e, token_id)
+                || is_self_locked(&e, token_id)
+            {
+                kept.push_back(token_id);
+                continue;
+            }
+
+            nft.owner = to.clone();
+            e.storage().persistent().set(&DataKey::NFT(token_id), &nft);
+            e.storage()
+                .persistent()
+                .remove(&DataKey::TokenApproval(token_id));
+            to_tokens.push_back(token_id);
+            moved_commitments.push_back(nft.metadata.commitment_id);
+            moved += 1;
+            append_token_log(&e, token_id, symbol_short!("transfer"), &to);
+
+            e.events().publish(
+                (symbol_short!("Transfer"), from.clone(), to.clone()),
+                (token_id, e.ledger().timestamp()),
+            );
+        }
+
+        if moved > 0 {
+            let from_balance: u32 = e
+                .storage()
+                .persistent()
+                .get(&DataKey::OwnerBalance(from.clone()))
+                .unwrap_or(0);
+            let to_balance: u32 = e
+                .storage()
+                .persistent()
+                .get(&DataKey::OwnerBalance(to.clone()))
+                .unwrap_or(0);
+            let to_balance = to_balance
+                .checked_add(moved)
+                .ok_or(ContractError::Overflow)?;
+            e.storage().persistent().set(
+                &DataKey::OwnerBalance(from.clone()),
+                &from_balance.saturating_sub(moved),
+            );
+            e.storage()
+                .persistent()
+                .set(&DataKey::OwnerBalance(to.clone()), &to_balance);
+            e.storage()
+                .persistent()
+                .set(&DataKey::OwnerTokens(from), &kept);
+            e.storage()
+                .persistent()
+                .set(&DataKey::OwnerTokens(to.clone()), &to_tokens);
+        }
+
+        // INTERACTIONS: keep the core commitment owners in sync
+        for commitment_id in moved_commitments.iter() {
+            sync_core_owner(&e, &commitment_id, &to)?;
+        }
+
+        Ok(moved)
+    }
+
+    // ========================================================================
+    // Transfer Allowlist
+    // ========================================================================
+
+    /// Restrict (or reopen) transfers to allowlisted recipients (admin-only).
+    pub fn set_transfer_allowlist_enabled(
+        e: Env,
+        admin: Address,
+        enabled: bool,
+    ) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        require_admin(&e, &admin)?;
+        e.storage()
+            .instance()
+            .set(&DataKey::TransferAllowlistEnabled, &enabled);
+        e.events().publish(
+            (Symbol::new(&e, "AllowlistEnabled"),),
+            (enabled, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Check whether transfers are restricted to allowlisted recipients
+    pub fn is_transfer_allowlist_enabled(e: Env) -> bool {
+        e.storage()
+            .instance()
+            .get(&DataKey::TransferAllowlistEnabled)
+            .unwrap_or(false)
+    }
+
+    /// Allow `account` to receive transfers while the allowlist is enabled (admin-only).
+    pub fn add_to_allowlist(e: Env, admin: Address, account: Address) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        require_admin(&e, &admin)?;
+        e.storage()
+            .persistent()
+            .set(&DataKey::Allowlisted(account.clone()), &true);
+        e.events()
+            .publish((Symbol::new(&e, "Allowlisted"), account), true);
+        Ok(())
+    }
+
+    /// Remove `account` from the transfer allowlist (admin-only).
+    pub fn remove_from_allowlist(
+        e: Env,
+        admin: Address,
+        account: Address,
+    ) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        require_admin(&e, &admin)?;
+        e.storage()
+            .persistent()
+            .remove(&DataKey::Allowlisted(account.clone()));
+        e.events()
+            .publish((Symbol::new(&e, "Allowlisted"), account), false);
+        Ok(())
+    }
+
+    /// Check whether `account` is on the transfer allowlist
+    pub fn is_allowlisted(e: Env, account: Address) -> bool {
+        e.storage()
+            .persistent()
+            .get(&DataKey::Allowlisted(account))
+            .unwrap_or(false)
+    }
+
+    /// Move `token_id` from `from` to `to`; the caller must have checked `from`'s auth.
+    fn transfer_token(
+        e: Env,
+        from: Address,
+        to: Address,
+        token_id: u32,
     ) -> Result<(), ContractError> {
         // Reentrancy protection
         let guard: bool = e
@@ -541,11 +1451,8 @@ impl CommitmentNFTContract {
         e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
         EmergencyControl::require_not_emergency(&e);
 
-        // Check if contract is paused
-        Pausable::require_not_paused(&e);
-
-        // CHECKS: Require authorization from the sender
-        from.require_auth();
+        // Check if contract or transfers are paused
+        Pausable::require_transfer_not_paused(&e);
 
         // Validate 'to' address is not the same as 'from' (prevent self-transfer)
         if to == from {
@@ -555,6 +1462,13 @@ impl CommitmentNFTContract {
             return Err(ContractError::TransferToZeroAddress);
         }
 
+        if !is_recipient_allowed(&e, &to) {
+            e.storage()
+                .instance()
+                .set(&DataKey::ReentrancyGuard, &false);
+            return Err(ContractError::RecipientNotAllowed);
+        }
+
         // Get the NFT
         let mut nft: CommitmentNFT = e
             .storage()
@@ -575,18 +1489,27 @@ impl CommitmentNFTContract {
             return Err(ContractError::NotOwner);
         }
 
-        // Active (locked) commitment NFTs cannot be transferred (#145)
-        if nft.is_active {
+        // Active (locked) and under-review commitment NFTs cannot be transferred (#145)
+        if nft.is_active || is_under_review(&e, token_id) {
             e.storage()
                 .instance()
                 .set(&DataKey::ReentrancyGuard, &false);
             return Err(ContractError::NFTLocked);
         }
+        if is_self_locked(&e, token_id) {
+            e.storage()
+                .instance()
+                .set(&DataKey::ReentrancyGuard, &false);
+            return Err(ContractError::SelfLocked);
+        }
 
         // EFFECTS: Update state
-        // Update owner
+        // Update owner; an approval granted by the previous owner lapses
         nft.owner = to.clone();
         e.storage().persistent().set(&DataKey::NFT(token_id), &nft);
+        e.storage()
+            .persistent()
+            .remove(&DataKey::TokenApproval(token_id));
 
         // OPTIMIZATION: Batch read balances before updating
         let (from_balance, to_balance) = {
@@ -604,14 +1527,24 @@ impl CommitmentNFTContract {
         };
 
         // Update balance counts
-        if from_balance > 0 {
+        let Some(to_balance) = to_balance.checked_add(1) else {
             e.storage()
-                .persistent()
-                .set(&DataKey::OwnerBalance(from.clone()), &(from_balance - 1));
-        }
+                .instance()
+                .set(&DataKey::ReentrancyGuard, &false);
+            return Err(ContractError::Overflow);
+        };
+        let Some(from_balance) = from_balance.checked_sub(1) else {
+            e.storage()
+                .instance()
+                .set(&DataKey::ReentrancyGuard, &false);
+            return Err(ContractError::Underflow);
+        };
         e.storage()
             .persistent()
-            .set(&DataKey::OwnerBalance(to.clone()), &(to_balance + 1));
+            .set(&DataKey::OwnerBalance(from.clone()), &from_balance);
+        e.storage()
+            .persistent()
+            .set(&DataKey::OwnerBalance(to.clone()), &to_balance);
 
         // Update owner tokens lists
         let mut from_tokens: Vec<u32> = e
@@ -635,6 +1568,15 @@ impl CommitmentNFTContract {
         e.storage()
             .persistent()
             .set(&DataKey::OwnerTokens(to.clone()), &to_tokens);
+        append_token_log(&e, token_id, symbol_short!("transfer"), &to);
+
+        // INTERACTIONS: keep the core commitment owner in sync
+        if let Err(err) = sync_core_owner(&e, &nft.metadata.commitment_id, &to) {
+            e.storage()
+                .instance()
+                .set(&DataKey::ReentrancyGuard, &false);
+            return Err(err);
+        }
 
         // Clear reentrancy guard
         e.storage()
@@ -661,7 +1603,10 @@ impl CommitmentNFTContract {
         Ok(nft.is_active)
     }
 
-    /// Get total supply of NFTs minted
+    /// Get total number of NFTs ever minted.
+    ///
+    /// Backed by the monotonic `TokenCounter`, so burned tokens are still
+    /// counted; use `live_supply` for the number of tokens that exist.
     pub fn total_supply(e: Env) -> u32 {
         e.storage()
             .instance()
@@ -669,6 +1614,48 @@ impl CommitmentNFTContract {
             .unwrap_or(0)
     }
 
+    /// Preview the token id the next successful mint will assign.
+    ///
+    /// Read-only; the value is only valid until the next mint (from any caller)
+    /// succeeds, so it must not be treated as a reservation.
+    pub fn peek_next_token_id(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::TokenCounter)
+            .unwrap_or(0)
+    }
+
+    /// Get (active, settled, total) NFT counts without enumerating tokens.
+    ///
+    /// Settled includes tokens closed early by `mark_inactive`; tokens under
+    /// review are in neither, so `active + settled <= total`.
+    pub fn get_supply_breakdown(e: Env) -> (u32, u32, u32) {
+        let active: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::ActiveSupply)
+            .unwrap_or(0);
+        let settled: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::SettledCount)
+            .unwrap_or(0);
+        (active, settled, Self::total_supply(e))
+    }
+
+    /// Get the number of NFTs that still exist (minted minus burned).
+    ///
+    /// Matches the length of `get_all_metadata` and the sum of `balance_of`
+    /// over all owners.
+    pub fn live_supply(e: Env) -> u32 {
+        let burned: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::BurnedCount)
+            .unwrap_or(0);
+        Self::total_supply(e).saturating_sub(burned)
+    }
+
     /// Get NFT count for a specific owner
     pub fn balance_of(e: Env, owner: Address) -> u32 {
         e.storage()
@@ -677,15 +1664,45 @@ impl CommitmentNFTContract {
             .unwrap_or(0)
     }
 
-    /// Get all NFTs metadata (for frontend)
-    pub fn get_all_metadata(e: Env) -> Vec<CommitmentNFT> {
-        let token_ids: Vec<u32> = e
+    /// Recompute an owner's balance from their token list (admin-only).
+    ///
+    /// `OwnerTokens` is authoritative; if `OwnerBalance` disagrees it is
+    /// overwritten and `BalanceReconciled` is emitted with the old and new
+    /// counts. Returns the corrected balance.
+    pub fn reconcile_owner(e: Env, admin: Address, owner: Address) -> Result<u32, ContractError> {
+        require_not_terminated(&e)?;
+        require_admin(&e, &admin)?;
+        let tokens: Vec<u32> = e
             .storage()
-            .instance()
-            .get(&DataKey::TokenIds)
+            .persistent()
+            .get(&DataKey::OwnerTokens(owner.clone()))
             .unwrap_or(Vec::new(&e));
-
-        let mut nfts: Vec<CommitmentNFT> = Vec::new(&e);
+        let corrected = tokens.len();
+        let recorded = Self::balance_of(e.clone(), owner.clone());
+        if recorded != corrected {
+            e.storage()
+                .persistent()
+                .set(&DataKey::OwnerBalance(owner.clone()), &corrected);
+            e.events().publish(
+                (Symbol::new(&e, "BalanceReconciled"), owner),
+                (recorded, corrected),
+            );
+        }
+        Ok(corrected)
+    }
+
+    /// Get all NFTs metadata (for frontend)
+    ///
+    /// Unbounded: the result grows with the number of tokens and will exceed
+    /// return size limits on large deployments. Prefer `get_all_metadata_paged`.
+    pub fn get_all_metadata(e: Env) -> Vec<CommitmentNFT> {
+        let token_ids: Vec<u32> = e
+            .storage()
+            .instance()
+            .get(&DataKey::TokenIds)
+            .unwrap_or(Vec::new(&e));
+
+        let mut nfts: Vec<CommitmentNFT> = Vec::new(&e);
 
         for token_id in token_ids.iter() {
             if let Some(nft) = e
@@ -700,6 +1717,18 @@ impl CommitmentNFTContract {
         nfts
     }
 
+    /// Get the NFTs at positions `start..start + limit` of the global token
+    /// list, in mint order. Burned ids in the window are skipped, so a page may
+    /// hold fewer than `limit` entries. `limit` is capped at MAX_PAGE_SIZE.
+    pub fn get_all_metadata_paged(e: Env, start: u32, limit: u32) -> Vec<CommitmentNFT> {
+        let token_ids: Vec<u32> = e
+            .storage()
+            .instance()
+            .get(&DataKey::TokenIds)
+            .unwrap_or(Vec::new(&e));
+        load_nfts_window(&e, &token_ids, start, limit)
+    }
+
     /// Get all NFTs owned by a specific address
     pub fn get_nfts_by_owner(e: Env, owner: Address) -> Vec<CommitmentNFT> {
         let token_ids: Vec<u32> = e
@@ -723,15 +1752,109 @@ impl CommitmentNFTContract {
         owned_nfts
     }
 
+    /// Get the NFTs at positions `start..start + limit` of `owner`'s token
+    /// list, together with the total number of tokens the owner holds.
+    ///
+    /// `limit` is capped at MAX_PAGE_SIZE; a zero `limit` or a `start` past
+    /// the end yields an empty page but still reports the total.
+    pub fn get_nfts_by_owner_paged(
+        e: Env,
+        owner: Address,
+        start: u32,
+        limit: u32,
+    ) -> (Vec<CommitmentNFT>, u32) {
+        let token_ids: Vec<u32> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::OwnerTokens(owner))
+            .unwrap_or(Vec::new(&e));
+        (
+            load_nfts_window(&e, &token_ids, start, limit),
+            token_ids.len(),
+        )
+    }
+
+    /// Get a page of token ids expiring on `day` (`expires_at / SECONDS_PER_DAY`),
+    /// in mint order. Settled tokens stay listed; `limit` is capped at MAX_PAGE_SIZE.
+    pub fn get_tokens_expiring_on(e: Env, day: u64, offset: u32, limit: u32) -> Vec<u32> {
+        let bucket: Vec<u32> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::ExpiryBucket(day))
+            .unwrap_or(Vec::new(&e));
+        let start = offset.min(bucket.len());
+        let end = start
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(bucket.len());
+        bucket.slice(start..end)
+    }
+
+    /// Get a page of settled (inactive) NFTs. Tokens under review are excluded.
+    ///
+    /// `offset` and `limit` apply to the settled subset, in mint order.
+    /// `limit` is capped at MAX_PAGE_SIZE.
+    pub fn get_settled_metadata(e: Env, offset: u32, limit: u32) -> Vec<CommitmentNFT> {
+        let token_ids: Vec<u32> = e
+            .storage()
+            .instance()
+            .get(&DataKey::TokenIds)
+            .unwrap_or(Vec::new(&e));
+
+        collect_nfts_page(&e, &token_ids, offset, limit, |nft| {
+            !nft.is_active && !is_under_review(&e, nft.token_id)
+        })
+    }
+
+    /// Export NFTs in token-id order for indexer bootstrapping.
+    ///
+    /// Start with `cursor = 0` and pass each returned `next_cursor` until it is
+    /// `None`. The cursor is a token id rather than a position, so tokens minted
+    /// while paging are appended at the end and never shift earlier chunks.
+    /// `limit` is capped at MAX_PAGE_SIZE.
+    pub fn export_state(e: Env, cursor: u32, limit: u32) -> StateChunk {
+        let minted: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::TokenCounter)
+            .unwrap_or(0);
+        let cap = limit.min(MAX_PAGE_SIZE);
+
+        let mut nfts: Vec<CommitmentNFT> = Vec::new(&e);
+        let mut token_id = cursor;
+        while token_id < minted && nfts.len() < cap {
+            if let Some(nft) = e
+                .storage()
+                .persistent()
+                .get::<DataKey, CommitmentNFT>(&DataKey::NFT(token_id))
+            {
+                nfts.push_back(nft);
+            }
+            token_id += 1;
+        }
+
+        StateChunk {
+            nfts,
+            next_cursor: if token_id < minted {
+                Some(token_id)
+            } else {
+                None
+            },
+        }
+    }
+
     // ========================================================================
     // Settlement (Issue #5 - Main Implementation)
     // ========================================================================
 
     /// Mark NFT as inactive (for early exit or other non-expiry scenarios)
     ///
+    /// Like `settle`, the stored core contract must authorize the call.
+    ///
     /// # Reentrancy Protection
     /// Uses checks-effects-interactions pattern.
     pub fn mark_inactive(e: Env, token_id: u32) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        require_core_auth(&e)?;
         // Reentrancy protection
         let guard: bool = e
             .storage()
@@ -772,6 +1895,8 @@ impl CommitmentNFTContract {
         // Mark as inactive
         nft.is_active = false;
         e.storage().persistent().set(&DataKey::NFT(token_id), &nft);
+        update_supply_counter(&e, DataKey::ActiveSupply, false);
+        update_supply_counter(&e, DataKey::SettledCount, true);
 
         // Clear reentrancy guard
         e.storage()
@@ -787,10 +1912,15 @@ impl CommitmentNFTContract {
 
     /// Mark NFT as settled (after maturity)
     ///
+    /// The stored core contract must authorize the call; fails with
+    /// `NotAuthorized` when no core contract is configured.
+    ///
     /// # Reentrancy Protection
     /// Uses checks-effects-interactions pattern. This function only writes to storage
     /// and doesn't make external calls, but still protected for consistency.
     pub fn settle(e: Env, token_id: u32) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        require_core_auth(&e)?;
         // Reentrancy protection
         let guard: bool = e
             .storage()
@@ -840,6 +1970,9 @@ impl CommitmentNFTContract {
         // Mark as inactive (settled)
         nft.is_active = false;
         e.storage().persistent().set(&DataKey::NFT(token_id), &nft);
+        update_supply_counter(&e, DataKey::ActiveSupply, false);
+        update_supply_counter(&e, DataKey::SettledCount, true);
+        append_token_log(&e, token_id, symbol_short!("settle"), &nft.owner);
 
         // Clear reentrancy guard
         e.storage()
@@ -853,6 +1986,74 @@ impl CommitmentNFTContract {
         Ok(())
     }
 
+    /// Settle an NFT, treating an already-settled matured token as success.
+    ///
+    /// Unlike `settle`, a token that is already inactive and past its expiry
+    /// returns `Ok(())` without emitting a second `Settle` event, so retries
+    /// from keepers are harmless. Unexpired tokens still fail with `NotExpired`,
+    /// and tokens under review are rejected like in `settle`.
+    pub fn settle_idempotent(e: Env, token_id: u32) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        let nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+
+        if !nft.is_active && !is_under_review(&e, token_id) {
+            if e.ledger().timestamp() < nft.metadata.expires_at {
+                return Err(ContractError::NotExpired);
+            }
+            return Ok(());
+        }
+
+        Self::settle(e, token_id)
+    }
+
+    /// Settle a matured NFT and burn it in one call.
+    ///
+    /// The owner and the stored core contract must both authorize the call;
+    /// the core contract's `settle_and_burn` drives it while closing the
+    /// commitment, so the token never disappears under an open commitment.
+    /// Emits `Settle` followed by `Burn`. Fails with `NotExpired` before
+    /// maturity and with `AlreadySettled` if the token was already settled;
+    /// either failure leaves the token untouched.
+    pub fn settle_and_burn(e: Env, token_id: u32) -> Result<(), ContractError> {
+        let nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+        nft.owner.require_auth();
+
+        Self::settle(e.clone(), token_id)?;
+        burn_token(&e, &nft);
+        Ok(())
+    }
+
+    /// Permanently destroy a settled NFT (owner-only).
+    ///
+    /// Active and under-review tokens are rejected with `TransferNotAllowed`.
+    /// The id is not reused; see `total_supply` and `live_supply`.
+    pub fn burn(e: Env, token_id: u32) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        Pausable::require_not_paused(&e);
+
+        let nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+        nft.owner.require_auth();
+
+        if nft.is_active || is_under_review(&e, token_id) {
+            return Err(ContractError::TransferNotAllowed);
+        }
+
+        burn_token(&e, &nft);
+        Ok(())
+    }
+
     /// Check if an NFT has expired (based on time)
     pub fn is_expired(e: Env, token_id: u32) -> Result<bool, ContractError> {
         let nft: CommitmentNFT = e
@@ -865,13 +2066,381 @@ impl CommitmentNFTContract {
         Ok(current_time >= nft.metadata.expires_at)
     }
 
+    /// Check whether `settle` would currently succeed for a token: it is
+    /// active, past expiry, and the contract is not paused, in emergency mode
+    /// or terminated. Core-only settlement authorization is not considered.
+    pub fn can_settle(e: Env, token_id: u32) -> bool {
+        if is_terminated(&e) || Pausable::is_paused(&e) || EmergencyControl::is_emergency_mode(&e) {
+            return false;
+        }
+        let nft: Option<CommitmentNFT> = e.storage().persistent().get(&DataKey::NFT(token_id));
+        match nft {
+            Some(nft) => nft.is_active && e.ledger().timestamp() >= nft.metadata.expires_at,
+            None => false,
+        }
+    }
+
+    /// Get owner, activity, expiry and metadata of an NFT in one call
+    pub fn get_nft_state(e: Env, token_id: u32) -> Result<NftState, ContractError> {
+        let nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+
+        Ok(NftState {
+            owner: nft.owner,
+            is_active: nft.is_active,
+            is_expired: e.ledger().timestamp() >= nft.metadata.expires_at,
+            metadata: nft.metadata,
+        })
+    }
+
+    /// Get the most recent `limit` lifecycle entries of a token, oldest first.
+    ///
+    /// At most `MAX_TOKEN_LOG` entries are kept; unknown tokens return an
+    /// empty log.
+    pub fn get_token_log(e: Env, token_id: u32, limit: u32) -> Vec<TokenLogEntry> {
+        let log: Vec<TokenLogEntry> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::TokenLog(token_id))
+            .unwrap_or(Vec::new(&e));
+        let start = log.len().saturating_sub(limit);
+        log.slice(start..)
+    }
+
+    /// Limit how many more NFTs `minter` may mint (admin-only).
+    ///
+    /// Each mint recorded for the minter (see `minter_of`) uses one unit;
+    /// once exhausted, mints fail with `QuotaExceeded` until refilled. A quota
+    /// of zero removes the limit.
+    pub fn set_minter_quota(
+        e: Env,
+        admin: Address,
+        minter: Address,
+        quota: u32,
+    ) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        require_admin(&e, &admin)?;
+        let key = DataKey::MinterQuota(minter.clone());
+        if quota == 0 {
+            e.storage().persistent().remove(&key);
+        } else {
+            e.storage().persistent().set(&key, &quota);
+        }
+        e.events()
+            .publish((Symbol::new(&e, "MinterQuotaSet"), minter), quota);
+        Ok(())
+    }
+
+    /// Get the mints `minter` has left, or `None` when unlimited.
+    pub fn get_minter_quota(e: Env, minter: Address) -> Option<u32> {
+        e.storage().persistent().get(&DataKey::MinterQuota(minter))
+    }
+
+    /// Authorize `minter` to call `mint` and add it to the minter list
+    /// (admin-only). The admin and core contract can always mint.
+    /// Adding an existing minter is a no-op.
+    pub fn add_minter(e: Env, admin: Address, minter: Address) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        require_admin(&e, &admin)?;
+        if Self::is_minter(e.clone(), minter.clone()) {
+            return Ok(());
+        }
+        e.storage()
+            .persistent()
+            .set(&DataKey::AuthorizedMinter(minter.clone()), &true);
+        let mut minters = Self::get_minters(e.clone());
+        minters.push_back(minter.clone());
+        e.storage().instance().set(&DataKey::Minters, &minters);
+        e.events()
+            .publish((Symbol::new(&e, "MinterAdded"),), minter);
+        Ok(())
+    }
+
+    /// Revoke `minter` and remove it from the minter list (admin-only).
+    /// Removing an address that is not a minter is a no-op.
+    pub fn remove_minter(e: Env, admin: Address, minter: Address) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        require_admin(&e, &admin)?;
+        if !Self::is_minter(e.clone(), minter.clone()) {
+            return Ok(());
+        }
+        e.storage()
+            .persistent()
+            .remove(&DataKey::AuthorizedMinter(minter.clone()));
+        let mut minters = Self::get_minters(e.clone());
+        if let Some(index) = minters.first_index_of(&minter) {
+            minters.remove(index);
+        }
+        e.storage().instance().set(&DataKey::Minters, &minters);
+        e.events()
+            .publish((Symbol::new(&e, "MinterRemoved"),), minter);
+        Ok(())
+    }
+
+    /// Get all authorized minters, in the order they were added.
+    pub fn get_minters(e: Env) -> Vec<Address> {
+        e.storage()
+            .instance()
+            .get(&DataKey::Minters)
+            .unwrap_or(Vec::new(&e))
+    }
+
+    /// Check whether an address is an authorized minter.
+    pub fn is_minter(e: Env, addr: Address) -> bool {
+        e.storage()
+            .persistent()
+            .get(&DataKey::AuthorizedMinter(addr))
+            .unwrap_or(false)
+    }
+
+    /// Get the address that minted an NFT
+    ///
+    /// For `mint` this is the caller; for `mint_backdated` it is the admin.
+    pub fn minter_of(e: Env, token_id: u32) -> Result<Address, ContractError> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::Minter(token_id))
+            .ok_or(ContractError::TokenNotFound)
+    }
+
+    /// Get the creation timestamp of an NFT's commitment
+    pub fn get_created_at(e: Env, token_id: u32) -> Result<u64, ContractError> {
+        let nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+        Ok(nft.metadata.created_at)
+    }
+
+    /// Get the expiration timestamp of an NFT's commitment
+    pub fn get_expires_at(e: Env, token_id: u32) -> Result<u64, ContractError> {
+        let nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+        Ok(nft.metadata.expires_at)
+    }
+
     /// Check if a token exists
     pub fn token_exists(e: Env, token_id: u32) -> bool {
         e.storage().persistent().has(&DataKey::NFT(token_id))
     }
 
+    // ========================================================================
+    // Review Status
+    // ========================================================================
+
+    /// Temporarily deactivate an active NFT for review (admin-only).
+    ///
+    /// Unlike settlement, this is reversible via `reactivate`.
+    pub fn deactivate(e: Env, admin: Address, token_id: u32) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        require_admin(&e, &admin)?;
+
+        let mut nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+        if !nft.is_active {
+            return Err(ContractError::AlreadySettled);
+        }
+
+        nft.is_active = false;
+        e.storage().persistent().set(&DataKey::NFT(token_id), &nft);
+        e.storage()
+            .persistent()
+            .set(&DataKey::UnderReview(token_id), &true);
+        update_supply_counter(&e, DataKey::ActiveSupply, false);
+
+        e.events().publish(
+            (Symbol::new(&e, "Deactivated"), token_id),
+            (admin, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Reactivate an NFT previously deactivated for review (admin-only).
+    ///
+    /// Settled NFTs are terminal and cannot be reactivated.
+    pub fn reactivate(e: Env, admin: Address, token_id: u32) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        require_admin(&e, &admin)?;
+
+        let mut nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+        if !is_under_review(&e, token_id) {
+            if !nft.is_active {
+                return Err(ContractError::AlreadySettled);
+            }
+            return Err(ContractError::NotUnderReview);
+        }
+
+        nft.is_active = true;
+        e.storage().persistent().set(&DataKey::NFT(token_id), &nft);
+        e.storage()
+            .persistent()
+            .remove(&DataKey::UnderReview(token_id));
+        update_supply_counter(&e, DataKey::ActiveSupply, true);
+
+        e.events().publish(
+            (Symbol::new(&e, "Reactivated"), token_id),
+            (admin, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Get the lifecycle status of an NFT
+    pub fn get_status(e: Env, token_id: u32) -> Result<TokenStatus, ContractError> {
+        let nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+
+        if nft.is_active {
+            Ok(TokenStatus::Active)
+        } else if is_under_review(&e, token_id) {
+            Ok(TokenStatus::UnderReview)
+        } else {
+            Ok(TokenStatus::Settled)
+        }
+    }
+
+    // ========================================================================
+    // Metadata Management
+    // ========================================================================
+
+    /// Correct an NFT's metadata (admin-only).
+    ///
+    /// The commitment_id cannot change. Fails with `MetadataLocked` while the
+    /// owner holds a metadata lock on the token.
+    pub fn update_metadata(
+        e: Env,
+        admin: Address,
+        token_id: u32,
+        metadata: CommitmentMetadata,
+    ) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        require_admin(&e, &admin)?;
+
+        let mut nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+        if is_metadata_locked(&e, token_id) {
+            return Err(ContractError::MetadataLocked);
+        }
+        if metadata.commitment_id != nft.metadata.commitment_id {
+            return Err(ContractError::InvalidCommitmentId);
+        }
+        Self::validate_mint_args(
+            &e,
+            &metadata.commitment_id,
+            metadata.duration_days,
+            metadata.max_loss_percent,
+            &metadata.commitment_type,
+            metadata.initial_amount,
+        )?;
+        if metadata.expires_at <= metadata.created_at {
+            return Err(ContractError::InvalidTimestamps);
+        }
+
+        nft.metadata = metadata;
+        e.storage().persistent().set(&DataKey::NFT(token_id), &nft);
+
+        e.events().publish(
+            (Symbol::new(&e, "MetadataUpdated"), token_id),
+            (admin, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Lock the token's metadata against admin edits (owner-only).
+    pub fn owner_lock_metadata(e: Env, owner: Address, token_id: u32) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        require_token_owner(&e, &owner, token_id)?;
+        e.storage()
+            .persistent()
+            .set(&DataKey::MetadataLock(token_id), &true);
+        e.events().publish(
+            (Symbol::new(&e, "MetadataLocked"), token_id),
+            (owner, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Lock the token against transfers until `unlock_at` (owner-only).
+    ///
+    /// A lock can only be extended: an `unlock_at` in the past or before the
+    /// current lock fails with `InvalidUnlockTime`. While locked, transfers
+    /// fail with `SelfLocked`.
+    pub fn set_self_lock(
+        e: Env,
+        owner: Address,
+        token_id: u32,
+        unlock_at: u64,
+    ) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        require_token_owner(&e, &owner, token_id)?;
+        if unlock_at <= e.ledger().timestamp() {
+            return Err(ContractError::InvalidUnlockTime);
+        }
+        if let Some(current) = Self::get_self_lock(e.clone(), token_id) {
+            if unlock_at < current {
+                return Err(ContractError::InvalidUnlockTime);
+            }
+        }
+        e.storage()
+            .persistent()
+            .set(&DataKey::SelfLock(token_id), &unlock_at);
+        e.events().publish(
+            (Symbol::new(&e, "SelfLocked"), token_id),
+            (owner, unlock_at, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Get the time until which the owner has self-locked the token, if set.
+    pub fn get_self_lock(e: Env, token_id: u32) -> Option<u64> {
+        e.storage().persistent().get(&DataKey::SelfLock(token_id))
+    }
+
+    /// Release the owner's metadata lock (owner-only).
+    pub fn owner_unlock_metadata(
+        e: Env,
+        owner: Address,
+        token_id: u32,
+    ) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
+        require_token_owner(&e, &owner, token_id)?;
+        e.storage()
+            .persistent()
+            .remove(&DataKey::MetadataLock(token_id));
+        e.events().publish(
+            (Symbol::new(&e, "MetadataUnlocked"), token_id),
+            (owner, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Check whether the owner has locked the token's metadata
+    pub fn is_metadata_locked(e: Env, token_id: u32) -> bool {
+        is_metadata_locked(&e, token_id)
+    }
+
     /// Set emergency mode (admin only)
     pub fn set_emergency_mode(e: Env, caller: Address, enabled: bool) -> Result<(), ContractError> {
+        require_not_terminated(&e)?;
         let admin: Address = e
             .storage()
             .instance()
@@ -888,6 +2457,260 @@ impl CommitmentNFTContract {
     }
 }
 
+/// Load the NFTs for `token_ids` that match `filter`, skipping the first
+/// `offset` matches and returning at most `limit` (capped at MAX_PAGE_SIZE).
+/// Ids whose NFT entry is missing are skipped.
+fn collect_nfts_page<F>(
+    e: &Env,
+    token_ids: &Vec<u32>,
+    offset: u32,
+    limit: u32,
+    filter: F,
+) -> Vec<CommitmentNFT>
+where
+    F: Fn(&CommitmentNFT) -> bool,
+{
+    let cap = limit.min(MAX_PAGE_SIZE);
+    let mut page: Vec<CommitmentNFT> = Vec::new(e);
+    if cap == 0 {
+        return page;
+    }
+
+    let mut skipped: u32 = 0;
+    for token_id in token_ids.iter() {
+        if let Some(nft) = e
+            .storage()
+            .persistent()
+            .get::<DataKey, CommitmentNFT>(&DataKey::NFT(token_id))
+        {
+            if !filter(&nft) {
+                continue;
+            }
+            if skipped < offset {
+                skipped += 1;
+                continue;
+            }
+            page.push_back(nft);
+            if page.len() >= cap {
+                break;
+            }
+        }
+    }
+
+    page
+}
+
+/// Load the NFTs for positions `start..start + limit` of `token_ids` (`limit`
+/// capped at MAX_PAGE_SIZE). Ids whose NFT entry is missing are skipped.
+fn load_nfts_window(e: &Env, token_ids: &Vec<u32>, start: u32, limit: u32) -> Vec<CommitmentNFT> {
+    let start = start.min(token_ids.len());
+    let end = start
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(token_ids.len());
+
+    let mut page: Vec<CommitmentNFT> = Vec::new(e);
+    for token_id in token_ids.slice(start..end).iter() {
+        if let Some(nft) = e
+            .storage()
+            .persistent()
+            .get::<DataKey, CommitmentNFT>(&DataKey::NFT(token_id))
+        {
+            page.push_back(nft);
+        }
+    }
+    page
+}
+
+/// Require `owner` to have authorized the call and to own `token_id`.
+fn require_token_owner(e: &Env, owner: &Address, token_id: u32) -> Result<(), ContractError> {
+    owner.require_auth();
+    let nft: CommitmentNFT = e
+        .storage()
+        .persistent()
+        .get(&DataKey::NFT(token_id))
+        .ok_or(ContractError::TokenNotFound)?;
+    if nft.owner != *owner {
+        return Err(ContractError::NotOwner);
+    }
+    Ok(())
+}
+
+/// Whether `to` may receive transfers under the current allowlist setting.
+fn is_recipient_allowed(e: &Env, to: &Address) -> bool {
+    let enabled: bool = e
+        .storage()
+        .instance()
+        .get(&DataKey::TransferAllowlistEnabled)
+        .unwrap_or(false);
+    !enabled
+        || e.storage()
+            .persistent()
+            .get(&DataKey::Allowlisted(to.clone()))
+            .unwrap_or(false)
+}
+
+/// Record a lifecycle step for `token_id`, dropping the oldest entries
+/// beyond `MAX_TOKEN_LOG`.
+fn append_token_log(e: &Env, token_id: u32, event_type: Symbol, counterparty: &Address) {
+    let key = DataKey::TokenLog(token_id);
+    let mut log: Vec<TokenLogEntry> = e.storage().persistent().get(&key).unwrap_or(Vec::new(e));
+    log.push_back(TokenLogEntry {
+        event_type,
+        timestamp: e.ledger().timestamp(),
+        counterparty: counterparty.clone(),
+    });
+    while log.len() > MAX_TOKEN_LOG {
+        log.pop_front();
+    }
+    e.storage().persistent().set(&key, &log);
+}
+
+/// Whether `caller` may `mint`: the admin, the core contract or an authorized minter.
+fn can_mint(e: &Env, caller: &Address) -> bool {
+    let admin: Option<Address> = e.storage().instance().get(&DataKey::Admin);
+    let core: Option<Address> = e.storage().instance().get(&DataKey::CoreContract);
+    admin.as_ref() == Some(caller)
+        || core.as_ref() == Some(caller)
+        || e.storage()
+            .persistent()
+            .get(&DataKey::AuthorizedMinter(caller.clone()))
+            .unwrap_or(false)
+}
+
+/// Require the stored core contract's authorization; `NotAuthorized` when unset.
+fn require_core_auth(e: &Env) -> Result<(), ContractError> {
+    let core: Address = e
+        .storage()
+        .instance()
+        .get(&DataKey::CoreContract)
+        .ok_or(ContractError::NotAuthorized)?;
+    core.require_auth();
+    Ok(())
+}
+
+/// Remove a settled token and every per-token record, then drop it from the
+/// owner, global and expiry indexes. `TokenCounter` is left alone so ids are never
+/// reused; the token is no longer counted in `SettledCount`.
+fn burn_token(e: &Env, nft: &CommitmentNFT) {
+    let token_id = nft.token_id;
+    let owner = &nft.owner;
+    let storage = e.storage().persistent();
+    storage.remove(&DataKey::NFT(token_id));
+    storage.remove(&DataKey::Minter(token_id));
+    storage.remove(&DataKey::TokenApproval(token_id));
+    storage.remove(&DataKey::MetadataLock(token_id));
+    storage.remove(&DataKey::SelfLock(token_id));
+    storage.remove(&DataKey::TransferMemos(token_id));
+    storage.remove(&DataKey::TokenLog(token_id));
+
+    let balance: u32 = storage
+        .get(&DataKey::OwnerBalance(owner.clone()))
+        .unwrap_or(0);
+    storage.set(
+        &DataKey::OwnerBalance(owner.clone()),
+        &balance.saturating_sub(1),
+    );
+    let mut owner_tokens: Vec<u32> = storage
+        .get(&DataKey::OwnerTokens(owner.clone()))
+        .unwrap_or(Vec::new(e));
+    if let Some(index) = owner_tokens.first_index_of(token_id) {
+        owner_tokens.remove(index);
+    }
+    storage.set(&DataKey::OwnerTokens(owner.clone()), &owner_tokens);
+
+    let bucket_key = DataKey::ExpiryBucket(nft.metadata.expires_at / SECONDS_PER_DAY);
+    let mut bucket: Vec<u32> = storage.get(&bucket_key).unwrap_or(Vec::new(e));
+    if let Some(index) = bucket.first_index_of(token_id) {
+        bucket.remove(index);
+    }
+    storage.set(&bucket_key, &bucket);
+
+    let mut token_ids: Vec<u32> = e
+        .storage()
+        .instance()
+        .get(&DataKey::TokenIds)
+        .unwrap_or(Vec::new(e));
+    if let Some(index) = token_ids.first_index_of(token_id) {
+        token_ids.remove(index);
+    }
+    e.storage().instance().set(&DataKey::TokenIds, &token_ids);
+    update_supply_counter(e, DataKey::SettledCount, false);
+    update_supply_counter(e, DataKey::BurnedCount, true);
+
+    e.events().publish(
+        (symbol_short!("Burn"), token_id),
+        (owner.clone(), e.ledger().timestamp()),
+    );
+}
+
+/// Recount `ActiveSupply` and `SettledCount` from `TokenIds`, so tokens minted
+/// before the counters existed are included. Tokens under review are in neither.
+fn seed_supply_counters(e: &Env) {
+    let token_ids: Vec<u32> = e
+        .storage()
+        .instance()
+        .get(&DataKey::TokenIds)
+        .unwrap_or(Vec::new(e));
+    let mut active: u32 = 0;
+    let mut settled: u32 = 0;
+    for token_id in token_ids.iter() {
+        let nft: Option<CommitmentNFT> = e.storage().persistent().get(&DataKey::NFT(token_id));
+        match nft {
+            Some(nft) if nft.is_active => active += 1,
+            Some(_) if !is_under_review(e, token_id) => settled += 1,
+            _ => {}
+        }
+    }
+    e.storage().instance().set(&DataKey::ActiveSupply, &active);
+    e.storage().instance().set(&DataKey::SettledCount, &settled);
+}
+
+/// Increment or decrement a supply counter (`ActiveSupply`, `SettledCount` or `BurnedCount`).
+fn update_supply_counter(e: &Env, key: DataKey, increment: bool) {
+    let count: u32 = e.storage().instance().get(&key).unwrap_or(0);
+    let updated = if increment {
+        count.saturating_add(1)
+    } else {
+        count.saturating_sub(1)
+    };
+    e.storage().instance().set(&key, &updated);
+}
+
+fn is_terminated(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&DataKey::Terminated)
+        .unwrap_or(false)
+}
+
+fn require_not_terminated(e: &Env) -> Result<(), ContractError> {
+    if is_terminated(e) {
+        return Err(ContractError::ContractTerminated);
+    }
+    Ok(())
+}
+
+fn is_metadata_locked(e: &Env, token_id: u32) -> bool {
+    e.storage()
+        .persistent()
+        .get::<_, bool>(&DataKey::MetadataLock(token_id))
+        .unwrap_or(false)
+}
+
+fn is_self_locked(e: &Env, token_id: u32) -> bool {
+    e.storage()
+        .persistent()
+        .get::<_, u64>(&DataKey::SelfLock(token_id))
+        .is_some_and(|unlock_at| e.ledger().timestamp() < unlock_at)
+}
+
+fn is_under_review(e: &Env, token_id: u32) -> bool {
+    e.storage()
+        .persistent()
+        .get::<_, bool>(&DataKey::UnderReview(token_id))
+        .unwrap_or(false)
+}
+
 fn read_version(e: &Env) -> u32 {
     e.storage()
         .instance()
@@ -895,6 +2718,62 @@ fn read_version(e: &Env) -> u32 {
         .unwrap_or(0)
 }
 
+/// When the core-commitment check is enabled and a core contract is set,
+/// ask the core whether `commitment_id` exists; otherwise allow the mint.
+///
+/// Soroban forbids re-entry, so the call fails with `InvalidAction` when the
+/// core itself is minting from `create_commitment`. The core stores the
+/// commitment before it mints, so that case is allowed; any other failed
+/// call counts as not found.
+fn core_commitment_exists(e: &Env, commitment_id: &String) -> bool {
+    let required: bool = e
+        .storage()
+        .instance()
+        .get(&DataKey::RequireCoreCommitment)
+        .unwrap_or(false);
+    let core: Option<Address> = e.storage().instance().get(&DataKey::CoreContract);
+    let core = match core {
+        Some(core) if required => core,
+        _ => return true,
+    };
+    let args = Vec::from_array(e, [commitment_id.clone().into_val(e)]);
+    let reentry =
+        soroban_sdk::Error::from_type_and_code(ScErrorType::Context, ScErrorCode::InvalidAction);
+    match e.try_invoke_contract::<bool, soroban_sdk::Error>(
+        &core,
+        &Symbol::new(e, "commitment_exists"),
+        args,
+    ) {
+        Ok(Ok(exists)) => exists,
+        Err(Ok(err)) => err == reentry,
+        _ => false,
+    }
+}
+
+/// Update the commitment owner in the core contract after a transfer, when
+/// ownership propagation is enabled and a core contract is configured.
+fn sync_core_owner(e: &Env, commitment_id: &String, to: &Address) -> Result<(), ContractError> {
+    let enabled: bool = e
+        .storage()
+        .instance()
+        .get(&DataKey::PropagateOwnership)
+        .unwrap_or(false);
+    let core: Option<Address> = e.storage().instance().get(&DataKey::CoreContract);
+    let core = match core {
+        Some(core) if enabled => core,
+        _ => return Ok(()),
+    };
+    let args = Vec::from_array(e, [commitment_id.into_val(e), to.into_val(e)]);
+    match e.try_invoke_contract::<(), soroban_sdk::Error>(
+        &core,
+        &Symbol::new(e, "transfer_ownership"),
+        args,
+    ) {
+        Ok(Ok(())) => Ok(()),
+        _ => Err(ContractError::CorePropagationFailed),
+    }
+}
+
 fn require_admin(e: &Env, caller: &Address) -> Result<(), ContractError> {
     caller.require_auth();
     let admin: Address = e
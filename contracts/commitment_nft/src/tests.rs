@@ -4,12 +4,35 @@ extern crate std;
 
 use crate::*;
 use soroban_sdk::{
-    symbol_short,
-    testutils::{Address as _, Events, Ledger},
-    vec, Address, Env, IntoVal, String,
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Events, Ledger, MockAuth, MockAuthInvoke},
+    vec, Address, Env, IntoVal, String, Val,
 };
 
+/// Core contract stand-in that only knows the commitment "c_known".
+#[contract]
+pub struct MockCoreContract;
+
+#[contractimpl]
+impl MockCoreContract {
+    pub fn commitment_exists(e: Env, commitment_id: String) -> bool {
+        commitment_id == String::from_str(&e, "c_known")
+    }
+
+    pub fn transfer_ownership(e: Env, commitment_id: String, new_owner: Address) {
+        if commitment_id != String::from_str(&e, "c_known") {
+            panic!("unknown commitment");
+        }
+        e.storage().instance().set(&commitment_id, &new_owner);
+    }
+
+    pub fn commitment_owner(e: Env, commitment_id: String) -> Option<Address> {
+        e.storage().instance().get(&commitment_id)
+    }
+}
+
 fn setup_contract(e: &Env) -> (Address, CommitmentNFTContractClient<'_>) {
+    e.mock_all_auths();
     let contract_id = e.register_contract(None, CommitmentNFTContract);
     let client = CommitmentNFTContractClient::new(e, &contract_id);
     let admin = Address::generate(e);
@@ -93,6 +116,7 @@ fn mint_to_owner(
     label: &str,
 ) -> u32 {
     client.mint(
+        &client.get_admin(),
         owner,
         &String::from_str(e, label),
         &1, // 1 day duration — easy to settle
@@ -104,6 +128,30 @@ fn mint_to_owner(
     )
 }
 
+/// Mint a 1-day NFT to `owner` with `caller` as the minter.
+fn try_mint_as(
+    e: &Env,
+    client: &CommitmentNFTContractClient,
+    caller: &Address,
+    owner: &Address,
+    label: &str,
+) -> Result<u32, ContractError> {
+    client
+        .try_mint(
+            caller,
+            owner,
+            &String::from_str(e, label),
+            &1,
+            &10,
+            &String::from_str(e, "balanced"),
+            &1000,
+            &Address::generate(e),
+            &5,
+        )
+        .map(|r| r.unwrap())
+        .map_err(|err| err.unwrap())
+}
+
 // ============================================================================
 // Initialization Tests
 // ============================================================================
@@ -135,6 +183,7 @@ fn test_mint() {
         create_test_metadata(&e, &asset_address);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &commitment_id,
         &duration,
@@ -178,6 +227,7 @@ fn test_mint_multiple() {
 
     // Mint 3 NFTs
     let token_id_0 = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_0"),
         &30,
@@ -190,6 +240,7 @@ fn test_mint_multiple() {
     assert_eq!(token_id_0, 0);
 
     let token_id_1 = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_1"),
         &30,
@@ -202,6 +253,7 @@ fn test_mint_multiple() {
     assert_eq!(token_id_1, 1);
 
     let token_id_2 = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_2"),
         &30,
@@ -221,7 +273,7 @@ fn test_mint_multiple() {
 #[should_panic(expected = "Error(Contract, #1)")] // NotInitialized
 fn test_mint_without_initialize_fails() {
     let e = Env::default();
-    let (_admin, client) = setup_contract(&e);
+    let (admin, client) = setup_contract(&e);
     let owner = Address::generate(&e);
     let asset_address = Address::generate(&e);
 
@@ -229,6 +281,7 @@ fn test_mint_without_initialize_fails() {
         create_test_metadata(&e, &asset_address);
 
     client.mint(
+        &admin,
         &owner,
         &commitment_id,
         &duration,
@@ -255,6 +308,7 @@ fn test_mint_empty_commitment_type() {
     client.initialize(&admin);
 
     client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_empty"),
         &30,
@@ -277,6 +331,7 @@ fn test_mint_invalid_commitment_type() {
     client.initialize(&admin);
 
     client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_invalid"),
         &30,
@@ -299,6 +354,7 @@ fn test_mint_wrong_case_commitment_type() {
     client.initialize(&admin);
 
     client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_case"),
         &30,
@@ -322,6 +378,7 @@ fn test_mint_valid_commitment_types_all_three() {
 
     // Test "safe"
     let token_id_safe = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_safe"),
         &30,
@@ -335,6 +392,7 @@ fn test_mint_valid_commitment_types_all_three() {
 
     // Test "balanced"
     let token_id_balanced = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_balanced"),
         &30,
@@ -348,6 +406,7 @@ fn test_mint_valid_commitment_types_all_three() {
 
     // Test "aggressive"
     let token_id_aggressive = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_aggressive"),
         &30,
@@ -393,6 +452,7 @@ fn test_mint_empty_commitment_id() {
 
     // Try to mint with empty commitment_id
     client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, ""), // Empty commitment_id
         &30,
@@ -423,6 +483,7 @@ fn test_mint_commitment_id_very_long() {
     // Attempt to mint with very long commitment_id
     // Should fail with InvalidCommitmentId since it exceeds the max length
     client.mint(
+        &admin,
         &owner,
         &long_id,
         &30,
@@ -450,6 +511,7 @@ fn test_mint_commitment_id_max_allowed_length() {
 
     // Should succeed since it's within the max length
     let token_id = client.mint(
+        &admin,
         &owner,
         &commitment_id,
         &30,
@@ -477,6 +539,7 @@ fn test_mint_commitment_id_normal_length() {
 
     let commitment_id = String::from_str(&e, "test_commitment_normal_length_123");
     let token_id = client.mint(
+        &admin,
         &owner,
         &commitment_id,
         &30,
@@ -509,6 +572,7 @@ fn test_get_metadata_with_long_commitment_id() {
 
     // Mint with long commitment_id
     let token_id = client.mint(
+        &admin,
         &owner,
         &long_id,
         &30,
@@ -538,6 +602,25 @@ fn test_get_metadata_with_long_commitment_id() {
 // get_metadata Tests
 // ============================================
 
+#[test]
+fn test_get_commitment_id_by_token() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+    mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_1");
+
+    assert_eq!(
+        client.get_commitment_id(&token_id),
+        String::from_str(&e, "c_1")
+    );
+    assert_eq!(
+        client.try_get_commitment_id(&999),
+        Err(Ok(ContractError::TokenNotFound))
+    );
+}
+
 #[test]
 fn test_get_metadata() {
     let e = Env::default();
@@ -554,6 +637,7 @@ fn test_get_metadata() {
     let amount = 5000i128;
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &commitment_id,
         &duration,
@@ -588,6 +672,439 @@ fn test_get_metadata_nonexistent_token() {
     client.get_metadata(&999);
 }
 
+#[test]
+fn test_timestamp_getters_match_metadata() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+    e.ledger().with_mut(|li| li.timestamp = 5_000);
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    let metadata = client.get_metadata(&token_id).metadata;
+
+    assert_eq!(client.get_created_at(&token_id), metadata.created_at);
+    assert_eq!(client.get_expires_at(&token_id), metadata.expires_at);
+    assert_eq!(metadata.created_at, 5_000);
+}
+
+#[test]
+fn test_timestamp_getters_nonexistent_token() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+
+    assert_eq!(
+        client.try_get_created_at(&999),
+        Err(Ok(ContractError::TokenNotFound))
+    );
+    assert_eq!(
+        client.try_get_expires_at(&999),
+        Err(Ok(ContractError::TokenNotFound))
+    );
+}
+
+#[test]
+fn test_get_nft_state_matches_individual_getters() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    let assert_matches = |token_id: u32| {
+        let state = client.get_nft_state(&token_id);
+        assert_eq!(state.owner, client.owner_of(&token_id));
+        assert_eq!(state.is_active, client.is_active(&token_id));
+        assert_eq!(state.is_expired, client.is_expired(&token_id));
+        assert_eq!(state.metadata, client.get_metadata(&token_id).metadata);
+        state
+    };
+
+    let minted = assert_matches(token_id);
+    assert!(minted.is_active);
+    assert!(!minted.is_expired);
+
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+    client.settle(&token_id);
+    let settled = assert_matches(token_id);
+    assert!(!settled.is_active);
+    assert!(settled.is_expired);
+}
+
+#[test]
+fn test_get_nft_state_nonexistent_token() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+
+    assert_eq!(
+        client.try_get_nft_state(&999),
+        Err(Ok(ContractError::TokenNotFound))
+    );
+}
+
+#[test]
+fn test_minter_of_records_caller() {
+    let e = Env::default();
+    let (admin, client, core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+
+    let by_core = try_mint_as(&e, &client, &core_id, &owner, "c_0").unwrap();
+    let by_admin = try_mint_as(&e, &client, &admin, &owner, "c_1").unwrap();
+    assert_eq!(client.minter_of(&by_core), core_id);
+    assert_eq!(client.minter_of(&by_admin), admin);
+    assert_ne!(client.minter_of(&by_core), owner);
+}
+
+#[test]
+fn test_minter_of_backdated_is_admin() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+
+    let token_id = mint_backdated_to(&e, &client, &admin, &owner, 1_000, 2_000).unwrap();
+    assert_eq!(client.minter_of(&token_id), admin);
+    assert_eq!(client.owner_of(&token_id), owner);
+}
+
+#[test]
+fn test_mint_token_counter_overflow_returns_error() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    e.as_contract(&client.address, || {
+        e.storage()
+            .instance()
+            .set(&DataKey::TokenCounter, &u32::MAX);
+    });
+
+    let result = client.try_mint(
+        &admin,
+        &owner,
+        &String::from_str(&e, "c_0"),
+        &1,
+        &10,
+        &String::from_str(&e, "balanced"),
+        &1000,
+        &Address::generate(&e),
+        &5,
+    );
+    assert_eq!(result, Err(Ok(ContractError::Overflow)));
+    assert!(!client.is_locked());
+}
+
+#[test]
+fn test_transfer_balance_overflow_returns_error() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    let token_id = mint_to_owner(&e, &client, &owner, &Address::generate(&e), "c_0");
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+    client.settle(&token_id);
+    e.as_contract(&client.address, || {
+        e.storage()
+            .persistent()
+            .set(&DataKey::OwnerBalance(recipient.clone()), &u32::MAX);
+    });
+
+    let result = client.try_transfer(&owner, &recipient, &token_id);
+    assert_eq!(result, Err(Ok(ContractError::Overflow)));
+    assert_eq!(client.owner_of(&token_id), owner);
+}
+
+#[test]
+fn test_minter_quota_limits_mints_until_refilled() {
+    let e = Env::default();
+    let (admin, client, core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+    assert_eq!(client.get_minter_quota(&core_id), None);
+
+    client.set_minter_quota(&admin, &core_id, &2);
+    try_mint_as(&e, &client, &core_id, &owner, "c_0").unwrap();
+    try_mint_as(&e, &client, &core_id, &owner, "c_1").unwrap();
+    assert_eq!(client.get_minter_quota(&core_id), Some(0));
+
+    let result = try_mint_as(&e, &client, &core_id, &owner, "c_2");
+    assert_eq!(result, Err(ContractError::QuotaExceeded));
+    // The quota only charges its own minter
+    mint_to_owner(&e, &client, &owner, &asset_address, "c_2");
+
+    client.set_minter_quota(&admin, &core_id, &1);
+    try_mint_as(&e, &client, &core_id, &owner, "c_3").unwrap();
+    assert_eq!(client.get_minter_quota(&core_id), Some(0));
+
+    // Zero lifts the limit
+    client.set_minter_quota(&admin, &core_id, &0);
+    assert_eq!(client.get_minter_quota(&core_id), None);
+    try_mint_as(&e, &client, &core_id, &owner, "c_4").unwrap();
+    assert_eq!(client.total_supply(), 5);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_mint_requires_caller_auth() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    e.set_auths(&[]);
+    mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+}
+
+#[test]
+fn test_set_minter_quota_non_admin_fails() {
+    let e = Env::default();
+    let (_admin, client, core_id) = setup_contract_with_core(&e);
+    let attacker = Address::generate(&e);
+
+    let result = client.try_set_minter_quota(&attacker, &core_id, &1);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+    assert_eq!(client.get_minter_quota(&core_id), None);
+}
+
+#[test]
+fn test_minter_list_tracks_adds_and_removes() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let minter_a = Address::generate(&e);
+    let minter_b = Address::generate(&e);
+    let minter_c = Address::generate(&e);
+    assert_eq!(client.get_minters(), Vec::new(&e));
+
+    client.add_minter(&admin, &minter_a);
+    client.add_minter(&admin, &minter_b);
+    client.add_minter(&admin, &minter_c);
+    // Adding twice does not duplicate the entry
+    client.add_minter(&admin, &minter_a);
+    assert_eq!(
+        client.get_minters(),
+        vec![&e, minter_a.clone(), minter_b.clone(), minter_c.clone()]
+    );
+
+    client.remove_minter(&admin, &minter_b);
+    assert_eq!(
+        client.get_minters(),
+        vec![&e, minter_a.clone(), minter_c.clone()]
+    );
+    assert!(client.is_minter(&minter_a));
+    assert!(!client.is_minter(&minter_b));
+
+    // Removing an unknown minter leaves the list untouched
+    client.remove_minter(&admin, &minter_b);
+    client.remove_minter(&admin, &minter_a);
+    client.add_minter(&admin, &minter_b);
+    assert_eq!(
+        client.get_minters(),
+        vec![&e, minter_c.clone(), minter_b.clone()]
+    );
+    assert!(!client.is_minter(&minter_a));
+}
+
+#[test]
+fn test_mint_requires_authorized_minter() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let minter = Address::generate(&e);
+    let owner = Address::generate(&e);
+
+    let result = try_mint_as(&e, &client, &minter, &owner, "c_0");
+    assert_eq!(result, Err(ContractError::NotAuthorized));
+    assert!(!client.is_locked());
+
+    client.add_minter(&admin, &minter);
+    let token_id = try_mint_as(&e, &client, &minter, &owner, "c_0").unwrap();
+    assert_eq!(client.minter_of(&token_id), minter);
+
+    client.remove_minter(&admin, &minter);
+    let result = try_mint_as(&e, &client, &minter, &owner, "c_1");
+    assert_eq!(result, Err(ContractError::NotAuthorized));
+    assert_eq!(client.total_supply(), 1);
+}
+
+#[test]
+fn test_add_minter_non_admin_fails() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let attacker = Address::generate(&e);
+
+    let result = client.try_add_minter(&attacker, &attacker);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+    assert!(client.get_minters().is_empty());
+    assert!(!client.is_minter(&attacker));
+}
+
+#[test]
+fn test_require_core_commitment_rejects_unknown_commitment() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (admin, client) = setup_contract(&e);
+    client.initialize(&admin);
+    client.set_core_contract(&e.register_contract(None, MockCoreContract));
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    // Off by default: minting does not consult the core contract
+    assert!(!client.is_core_commitment_required());
+    mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+
+    client.set_require_core_commitment(&admin, &true);
+    assert!(client.is_core_commitment_required());
+    let result = client.try_mint(
+        &admin,
+        &owner,
+        &String::from_str(&e, "c_1"),
+        &1,
+        &10,
+        &String::from_str(&e, "balanced"),
+        &1000,
+        &asset_address,
+        &5,
+    );
+    assert_eq!(result, Err(Ok(ContractError::CommitmentNotFound)));
+    assert_eq!(client.total_supply(), 1);
+    assert!(!client.is_locked());
+
+    mint_to_owner(&e, &client, &owner, &asset_address, "c_known");
+    assert_eq!(client.total_supply(), 2);
+}
+
+#[test]
+fn test_set_require_core_commitment_non_admin_fails() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let attacker = Address::generate(&e);
+
+    let result = client.try_set_require_core_commitment(&attacker, &true);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+    assert!(!client.is_core_commitment_required());
+}
+
+#[test]
+fn test_reconcile_owner_repairs_drifted_balance() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+    mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    mint_to_owner(&e, &client, &owner, &asset_address, "c_1");
+
+    // Consistent balances are left alone and emit nothing
+    assert_eq!(client.reconcile_owner(&admin, &owner), 2);
+    let topics: Vec<Val> = (Symbol::new(&e, "BalanceReconciled"), owner.clone()).into_val(&e);
+    let has_reconciled = |e: &Env| e.events().all().iter().any(|ev| ev.1 == topics);
+    assert!(!has_reconciled(&e));
+
+    // Force drift between the balance counter and the token list
+    e.as_contract(&client.address, || {
+        e.storage()
+            .persistent()
+            .set(&DataKey::OwnerBalance(owner.clone()), &5u32);
+    });
+    assert_eq!(client.balance_of(&owner), 5);
+
+    assert_eq!(client.reconcile_owner(&admin, &owner), 2);
+    assert!(has_reconciled(&e));
+    assert_eq!(client.balance_of(&owner), 2);
+    assert_eq!(client.get_nfts_by_owner(&owner).len(), 2);
+}
+
+#[test]
+fn test_reconcile_owner_non_admin_fails() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let attacker = Address::generate(&e);
+
+    let result = client.try_reconcile_owner(&attacker, &attacker);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+}
+
+#[test]
+fn test_minter_of_nonexistent_token() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+
+    assert_eq!(
+        client.try_minter_of(&999),
+        Err(Ok(ContractError::TokenNotFound))
+    );
+}
+
+#[test]
+fn test_token_log_records_lifecycle_in_order() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+    client.settle(&token_id);
+    e.ledger().with_mut(|li| li.timestamp = 172900);
+    client.transfer(&owner, &recipient, &token_id);
+
+    let log = client.get_token_log(&token_id, &MAX_TOKEN_LOG);
+    assert_eq!(
+        log,
+        vec![
+            &e,
+            TokenLogEntry {
+                event_type: symbol_short!("mint"),
+                timestamp: 1000,
+                counterparty: owner.clone(),
+            },
+            TokenLogEntry {
+                event_type: symbol_short!("settle"),
+                timestamp: 172800,
+                counterparty: owner.clone(),
+            },
+            TokenLogEntry {
+                event_type: symbol_short!("transfer"),
+                timestamp: 172900,
+                counterparty: recipient.clone(),
+            },
+        ]
+    );
+
+    // A smaller limit keeps the most recent entries
+    let recent = client.get_token_log(&token_id, &1);
+    assert_eq!(recent.len(), 1);
+    assert_eq!(recent.get(0).unwrap().event_type, symbol_short!("transfer"));
+    assert_eq!(client.get_token_log(&999, &10).len(), 0);
+}
+
+#[test]
+fn test_token_log_evicts_oldest_entries() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let other = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+    client.settle(&token_id);
+    for i in 0..MAX_TOKEN_LOG {
+        let (from, to) = if i % 2 == 0 {
+            (&owner, &other)
+        } else {
+            (&other, &owner)
+        };
+        client.transfer(from, to, &token_id);
+    }
+
+    let log = client.get_token_log(&token_id, &MAX_TOKEN_LOG);
+    assert_eq!(log.len(), MAX_TOKEN_LOG);
+    // mint and settle were evicted
+    for entry in log.iter() {
+        assert_eq!(entry.event_type, symbol_short!("transfer"));
+    }
+}
+
 // ============================================
 // owner_of Tests
 // ============================================
@@ -605,6 +1122,7 @@ fn test_owner_of() {
         create_test_metadata(&e, &asset_address);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &commitment_id,
         &duration,
@@ -647,6 +1165,7 @@ fn test_is_active() {
         create_test_metadata(&e, &asset_address);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &commitment_id,
         &duration,
@@ -738,6 +1257,7 @@ fn test_total_supply_after_minting() {
     // Mint 5 NFTs
     for _ in 0..5 {
         client.mint(
+            &admin,
             &owner,
             &String::from_str(&e, "commitment"),
             &30,
@@ -752,18 +1272,37 @@ fn test_total_supply_after_minting() {
     assert_eq!(client.total_supply(), 5);
 }
 
+#[test]
+fn test_peek_next_token_id_matches_minted_id() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+    assert_eq!(client.peek_next_token_id(), 0);
+
+    for label in ["c_0", "c_1", "c_2"] {
+        let expected = client.peek_next_token_id();
+        // Peeking does not advance the counter
+        assert_eq!(client.peek_next_token_id(), expected);
+        let token_id = mint_to_owner(&e, &client, &owner, &asset_address, label);
+        assert_eq!(token_id, expected);
+    }
+    assert_eq!(client.peek_next_token_id(), 3);
+}
+
 // Issue #111: total_supply unchanged after transfer or settle
 #[test]
 fn test_total_supply_unchanged_after_transfer_and_settle() {
     let e = Env::default();
     e.mock_all_auths();
-    let (_admin, client, core_id) = setup_contract_with_core(&e);
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
     let owner1 = Address::generate(&e);
     let owner2 = Address::generate(&e);
     let asset_address = Address::generate(&e);
 
     assert_eq!(client.total_supply(), 0);
     let token_id = client.mint(
+        &admin,
         &owner1,
         &String::from_str(&e, "c1"),
         &1,
@@ -783,17 +1322,77 @@ fn test_total_supply_unchanged_after_transfer_and_settle() {
     assert_eq!(client.total_supply(), 1);
 }
 
-// ============================================
-// balance_of Tests
-// ============================================
-
 #[test]
-fn test_balance_of_initial() {
+fn test_supply_breakdown_tracks_mint_settle_and_review() {
     let e = Env::default();
-    let (admin, client) = setup_contract(&e);
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
     let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+    assert_eq!(client.get_supply_breakdown(), (0, 0, 0));
+
+    let first = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    let second = mint_to_owner(&e, &client, &owner, &asset_address, "c_1");
+    let third = mint_to_owner(&e, &client, &owner, &asset_address, "c_2");
+    assert_eq!(client.get_supply_breakdown(), (3, 0, 3));
+
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+    client.settle(&first);
+    assert_eq!(client.get_supply_breakdown(), (2, 1, 3));
+
+    // Early-exited tokens count as settled
+    client.mark_inactive(&second);
+    assert_eq!(client.get_supply_breakdown(), (1, 2, 3));
+
+    // Tokens under review are neither active nor settled
+    client.deactivate(&admin, &third);
+    assert_eq!(client.get_supply_breakdown(), (0, 2, 3));
+    client.reactivate(&admin, &third);
+    assert_eq!(client.get_supply_breakdown(), (1, 2, 3));
+
+    // A failed settle leaves the counters untouched
+    assert!(client.try_settle(&first).is_err());
+    let (active, settled, total) = client.get_supply_breakdown();
+    assert_eq!((active, settled, total), (1, 2, 3));
+    assert_eq!(active + settled, total);
+}
 
-    client.initialize(&admin);
+#[test]
+fn test_migrate_seeds_supply_counters_from_token_ids() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let first = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    let second = mint_to_owner(&e, &client, &owner, &asset_address, "c_1");
+    mint_to_owner(&e, &client, &owner, &asset_address, "c_2");
+    mint_to_owner(&e, &client, &owner, &asset_address, "c_3");
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+    client.settle(&first);
+    client.deactivate(&admin, &second);
+
+    // Simulate tokens minted before the counters existed
+    e.as_contract(&client.address, || {
+        e.storage().instance().remove(&DataKey::ActiveSupply);
+        e.storage().instance().remove(&DataKey::SettledCount);
+    });
+    assert_eq!(client.get_supply_breakdown(), (0, 0, 4));
+
+    client.migrate(&admin, &0);
+    assert_eq!(client.get_supply_breakdown(), (2, 1, 4));
+}
+
+// ============================================
+// balance_of Tests
+// ============================================
+
+#[test]
+fn test_balance_of_initial() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+
+    client.initialize(&admin);
 
     // Owner with no NFTs should have balance 0
     assert_eq!(client.balance_of(&owner), 0);
@@ -812,6 +1411,7 @@ fn test_balance_of_after_minting() {
     // Mint 3 NFTs for owner1
     for _ in 0..3 {
         client.mint(
+            &admin,
             &owner1,
             &String::from_str(&e, "owner1_commitment"),
             &30,
@@ -826,6 +1426,7 @@ fn test_balance_of_after_minting() {
     // Mint 2 NFTs for owner2
     for _ in 0..2 {
         client.mint(
+            &admin,
             &owner2,
             &String::from_str(&e, "owner2_commitment"),
             &30,
@@ -846,13 +1447,14 @@ fn test_balance_of_after_minting() {
 fn test_balance_of_decremented_after_transfer() {
     let e = Env::default();
     e.mock_all_auths();
-    let (_admin, client, core_id) = setup_contract_with_core(&e);
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
     let owner = Address::generate(&e);
     let recipient = Address::generate(&e);
     let asset_address = Address::generate(&e);
 
     assert_eq!(client.balance_of(&owner), 0);
     let token_id = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "c1"),
         &1,
@@ -909,6 +1511,7 @@ fn test_get_all_metadata() {
     // Mint 3 NFTs
     for _ in 0..3 {
         client.mint(
+            &admin,
             &owner,
             &String::from_str(&e, "commitment"),
             &30,
@@ -929,159 +1532,1189 @@ fn test_get_all_metadata() {
     }
 }
 
-// ============================================
-// get_nfts_by_owner Tests
-// ============================================
-
 #[test]
-fn test_get_nfts_by_owner_empty() {
+fn test_get_all_metadata_paged_slices_token_list() {
     let e = Env::default();
-    let (admin, client) = setup_contract(&e);
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
     let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+    for label in ["c_0", "c_1", "c_2", "c_3", "c_4"] {
+        mint_to_owner(&e, &client, &owner, &asset_address, label);
+    }
 
-    client.initialize(&admin);
+    let page = client.get_all_metadata_paged(&1, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().token_id, 1);
+    assert_eq!(page.get(1).unwrap().token_id, 2);
 
-    let nfts = client.get_nfts_by_owner(&owner);
-    assert_eq!(nfts.len(), 0);
+    // The last page is truncated, and starting past the end yields nothing
+    assert_eq!(client.get_all_metadata_paged(&4, &10).len(), 1);
+    assert_eq!(client.get_all_metadata_paged(&5, &10).len(), 0);
+    assert_eq!(client.get_all_metadata_paged(&u32::MAX, &u32::MAX).len(), 0);
+    assert_eq!(client.get_all_metadata_paged(&0, &0).len(), 0);
 }
 
 #[test]
-fn test_get_nfts_by_owner_not_initialized_returns_empty() {
+fn test_get_all_metadata_paged_caps_limit() {
     let e = Env::default();
-    let (_admin, client) = setup_contract(&e);
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
     let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+    e.budget().reset_unlimited();
+    for i in 0..MAX_PAGE_SIZE + 1 {
+        mint_to_owner(&e, &client, &owner, &asset_address, &std::format!("c_{i}"));
+    }
 
-    let nfts = client.get_nfts_by_owner(&owner);
-    assert_eq!(nfts.len(), 0);
+    let page = client.get_all_metadata_paged(&0, &(MAX_PAGE_SIZE + 1));
+    assert_eq!(page.len(), MAX_PAGE_SIZE);
 }
 
+// ============================================
+// export_state Tests
+// ============================================
+
 #[test]
-fn test_get_nfts_by_owner() {
+fn test_export_state_pages_without_gaps_or_duplicates() {
     let e = Env::default();
-    let (admin, client) = setup_contract(&e);
-    let owner1 = Address::generate(&e);
-    let owner2 = Address::generate(&e);
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
     let asset_address = Address::generate(&e);
 
-    client.initialize(&admin);
+    for label in ["c_0", "c_1", "c_2", "c_3", "c_4"] {
+        mint_to_owner(&e, &client, &owner, &asset_address, label);
+    }
 
-    // Mint 2 NFTs for owner1
-    for _ in 0..2 {
-        client.mint(
-            &owner1,
-            &String::from_str(&e, "owner1"),
-            &30,
-            &10,
-            &String::from_str(&e, "safe"),
-            &1000,
-            &asset_address,
-            &5,
-        );
+    let mut exported = Vec::new(&e);
+    let mut cursor = Some(0u32);
+    let mut chunks = 0;
+    while let Some(c) = cursor {
+        let chunk = client.export_state(&c, &2);
+        assert!(chunk.nfts.len() <= 2);
+        exported.append(&chunk.nfts);
+        cursor = chunk.next_cursor;
+        chunks += 1;
+
+        // Minting mid-export lands after the cursor instead of shifting it
+        if chunks == 1 {
+            mint_to_owner(&e, &client, &owner, &asset_address, "c_5");
+        }
     }
 
-    // Mint 3 NFTs for owner2
-    for _ in 0..3 {
+    assert_eq!(chunks, 3);
+    assert_eq!(exported.len(), 6);
+    for (i, nft) in exported.iter().enumerate() {
+        assert_eq!(nft.token_id, i as u32);
+    }
+    assert_eq!(exported, client.get_all_metadata());
+}
+
+#[test]
+fn test_export_state_empty() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+
+    let chunk = client.export_state(&0, &10);
+    assert_eq!(chunk.nfts.len(), 0);
+    assert_eq!(chunk.next_cursor, None);
+}
+
+// ============================================
+// get_settled_metadata Tests
+// ============================================
+
+#[test]
+fn test_mint_indexes_tokens_by_expiry_day() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+    e.ledger()
+        .with_mut(|li| li.timestamp = 10 * SECONDS_PER_DAY + 3600);
+    let today = e.ledger().timestamp() / SECONDS_PER_DAY;
+
+    let mint_with_duration = |label: &str, days: u32| {
         client.mint(
-            &owner2,
-            &String::from_str(&e, "owner2"),
-            &30,
+            &admin,
+            &owner,
+            &String::from_str(&e, label),
+            &days,
             &10,
-            &String::from_str(&e, "safe"),
+            &String::from_str(&e, "balanced"),
             &1000,
             &asset_address,
             &5,
-        );
+        )
+    };
+    let one_day_a = mint_with_duration("c_0", 1);
+    let thirty_days = mint_with_duration("c_1", 30);
+    let one_day_b = mint_with_duration("c_2", 1);
+
+    let day_one = client.get_tokens_expiring_on(&(today + 1), &0, &10);
+    assert_eq!(day_one, vec![&e, one_day_a, one_day_b]);
+    let day_thirty = client.get_tokens_expiring_on(&(today + 30), &0, &10);
+    assert_eq!(day_thirty, vec![&e, thirty_days]);
+    let empty_day = client.get_tokens_expiring_on(&(today + 2), &0, &10);
+    assert_eq!(empty_day.len(), 0);
+}
+
+#[test]
+fn test_get_tokens_expiring_on_pages_bucket() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+    for label in ["c_0", "c_1", "c_2"] {
+        mint_to_owner(&e, &client, &owner, &asset_address, label);
     }
 
-    let owner1_nfts = client.get_nfts_by_owner(&owner1);
-    let owner2_nfts = client.get_nfts_by_owner(&owner2);
+    assert_eq!(client.get_tokens_expiring_on(&1, &1, &1), vec![&e, 1]);
+    assert_eq!(client.get_tokens_expiring_on(&1, &2, &10), vec![&e, 2]);
+    assert_eq!(client.get_tokens_expiring_on(&1, &5, &10).len(), 0);
+    assert_eq!(client.get_tokens_expiring_on(&1, &0, &0).len(), 0);
+}
 
-    assert_eq!(owner1_nfts.len(), 2);
-    assert_eq!(owner2_nfts.len(), 3);
+#[test]
+fn test_get_settled_metadata_empty() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
 
-    // Verify all owner1 NFTs belong to owner1
-    for nft in owner1_nfts.iter() {
-        assert_eq!(nft.owner, owner1);
+    client.initialize(&admin);
+
+    assert_eq!(client.get_settled_metadata(&0, &10).len(), 0);
+}
+
+#[test]
+fn test_get_settled_metadata_only_returns_settled() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token0 = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    let token1 = mint_to_owner(&e, &client, &owner, &asset_address, "c_1");
+    let token2 = mint_to_owner(&e, &client, &owner, &asset_address, "c_2");
+    let token3 = mint_to_owner(&e, &client, &owner, &asset_address, "c_3");
+
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+    client.settle(&token0);
+    client.settle(&token2);
+    client.settle(&token3);
+
+    let settled = client.get_settled_metadata(&0, &10);
+    assert_eq!(settled.len(), 3);
+    for nft in settled.iter() {
+        assert!(!nft.is_active);
+        assert_ne!(nft.token_id, token1);
     }
 }
 
+#[test]
+fn test_get_settled_metadata_pagination() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token0 = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    let _token1 = mint_to_owner(&e, &client, &owner, &asset_address, "c_1");
+    let token2 = mint_to_owner(&e, &client, &owner, &asset_address, "c_2");
+    let token3 = mint_to_owner(&e, &client, &owner, &asset_address, "c_3");
+
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+    client.settle(&token0);
+    client.settle(&token2);
+    client.settle(&token3);
+
+    let page1 = client.get_settled_metadata(&0, &2);
+    assert_eq!(page1.len(), 2);
+    assert_eq!(page1.get(0).unwrap().token_id, token0);
+    assert_eq!(page1.get(1).unwrap().token_id, token2);
+
+    let page2 = client.get_settled_metadata(&2, &2);
+    assert_eq!(page2.len(), 1);
+    assert_eq!(page2.get(0).unwrap().token_id, token3);
+
+    assert_eq!(client.get_settled_metadata(&3, &2).len(), 0);
+    assert_eq!(client.get_settled_metadata(&0, &0).len(), 0);
+}
+
 // ============================================
-// Transfer Tests
+// Metadata Lock Tests
 // ============================================
 
 #[test]
-fn test_owner_of_not_found() {
-    let (e, contract_id, _admin) = setup_env();
-    let client = CommitmentNFTContractClient::new(&e, &contract_id);
+fn test_owner_metadata_lock_blocks_admin_edits() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
 
-    let result = client.try_owner_of(&999);
-    assert!(result.is_err());
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    let mut metadata = client.get_metadata(&token_id).metadata;
+    metadata.max_loss_percent = 20;
+
+    client.owner_lock_metadata(&owner, &token_id);
+    assert!(client.is_metadata_locked(&token_id));
+    let result = client.try_update_metadata(&admin, &token_id, &metadata);
+    assert_eq!(result, Err(Ok(ContractError::MetadataLocked)));
+    assert_eq!(client.get_metadata(&token_id).metadata.max_loss_percent, 10);
+
+    client.owner_unlock_metadata(&owner, &token_id);
+    assert!(!client.is_metadata_locked(&token_id));
+    client.update_metadata(&admin, &token_id, &metadata);
+    assert_eq!(client.get_metadata(&token_id).metadata.max_loss_percent, 20);
 }
 
-// ============================================================================
-// Transfer Tests
-// ============================================================================
+#[test]
+fn test_owner_lock_metadata_requires_owner() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let other = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    let result = client.try_owner_lock_metadata(&other, &token_id);
+    assert_eq!(result, Err(Ok(ContractError::NotOwner)));
+    assert!(!client.is_metadata_locked(&token_id));
+}
 
 #[test]
-fn test_transfer() {
+fn test_self_lock_blocks_transfer_until_unlock() {
     let e = Env::default();
-    e.mock_all_auths();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let token_id = mint_to_owner(&e, &client, &owner, &Address::generate(&e), "c_0");
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+    client.settle(&token_id);
+    assert_eq!(client.get_self_lock(&token_id), None);
 
-    let (_admin, client, core_id) = setup_contract_with_core(&e);
-    let owner1 = Address::generate(&e);
-    let owner2 = Address::generate(&e);
+    client.set_self_lock(&owner, &token_id, &200000);
+    assert_eq!(client.get_self_lock(&token_id), Some(200000));
+    let result = client.try_transfer(&owner, &recipient, &token_id);
+    assert_eq!(result, Err(Ok(ContractError::SelfLocked)));
+    // transfer_all leaves self-locked tokens behind
+    assert_eq!(client.transfer_all(&owner, &recipient), 0);
+
+    e.ledger().with_mut(|li| li.timestamp = 200000);
+    client.transfer(&owner, &recipient, &token_id);
+    assert_eq!(client.owner_of(&token_id), recipient);
+}
+
+#[test]
+fn test_self_lock_cannot_be_shortened() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let token_id = mint_to_owner(&e, &client, &owner, &Address::generate(&e), "c_0");
+    client.set_self_lock(&owner, &token_id, &5000);
+
+    let result = client.try_set_self_lock(&owner, &token_id, &4999);
+    assert_eq!(result, Err(Ok(ContractError::InvalidUnlockTime)));
+    assert_eq!(client.get_self_lock(&token_id), Some(5000));
+
+    client.set_self_lock(&owner, &token_id, &6000);
+    assert_eq!(client.get_self_lock(&token_id), Some(6000));
+
+    let result = client.try_set_self_lock(&Address::generate(&e), &token_id, &7000);
+    assert_eq!(result, Err(Ok(ContractError::NotOwner)));
+}
+
+#[test]
+fn test_update_metadata_cannot_change_commitment_id() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
     let asset_address = Address::generate(&e);
 
-    // Mint with 1 day duration so we can settle it
-    let token_id = client.mint(
-        &owner1,
-        &String::from_str(&e, "commitment_001"),
-        &1, // 1 day duration
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    let mut metadata = client.get_metadata(&token_id).metadata;
+    metadata.commitment_id = String::from_str(&e, "c_other");
+
+    let result = client.try_update_metadata(&admin, &token_id, &metadata);
+    assert_eq!(result, Err(Ok(ContractError::InvalidCommitmentId)));
+}
+
+// ============================================
+// Mint Pause Tests
+// ============================================
+
+#[test]
+fn test_mint_paused_blocks_mint_but_allows_transfer_and_settle() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+
+    client.set_mint_paused(&admin, &true);
+    assert!(client.is_mint_paused());
+    assert!(!client.is_paused());
+
+    let result = client.try_mint(
+        &admin,
+        &owner,
+        &String::from_str(&e, "c_1"),
+        &1,
         &10,
         &String::from_str(&e, "balanced"),
         &1000,
         &asset_address,
         &5,
     );
+    assert_eq!(result, Err(Ok(ContractError::MintingPaused)));
+    assert_eq!(client.total_supply(), 1);
 
-    // Verify initial state
-    assert_eq!(client.owner_of(&token_id), owner1);
-    assert_eq!(client.balance_of(&owner1), 1);
-    assert_eq!(client.balance_of(&owner2), 0);
+    // Existing tokens can still be settled and transferred
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+    client.settle(&token_id);
+    client.transfer(&owner, &recipient, &token_id);
+    assert_eq!(client.owner_of(&token_id), recipient);
 
-    // Fast forward time past expiration and settle.
-    e.ledger().with_mut(|li| {
-        li.timestamp = 172800; // 2 days
-    });
+    client.set_mint_paused(&admin, &false);
+    mint_to_owner(&e, &client, &owner, &asset_address, "c_1");
+    assert_eq!(client.total_supply(), 2);
+}
+
+#[test]
+fn test_transfer_paused_blocks_transfer_but_allows_mint() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    e.ledger().with_mut(|li| li.timestamp = 172800);
     client.settle(&token_id);
 
-    // Verify NFT is now inactive (unlocked)
-    assert_eq!(client.is_active(&token_id), false);
+    client.set_transfer_paused(&admin, &true);
+    assert!(client.is_transfer_paused());
+    assert!(!client.is_mint_paused());
+    assert!(client.try_transfer(&owner, &recipient, &token_id).is_err());
+    assert!(client.try_transfer_all(&owner, &recipient).is_err());
+    assert_eq!(client.owner_of(&token_id), owner);
+
+    // Minting is unaffected
+    mint_to_owner(&e, &client, &owner, &asset_address, "c_1");
+
+    client.set_transfer_paused(&admin, &false);
+    client.transfer(&owner, &recipient, &token_id);
+    assert_eq!(client.owner_of(&token_id), recipient);
+}
+
+#[test]
+fn test_set_mint_paused_non_admin_fails() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let other = Address::generate(&e);
+
+    let result = client.try_set_mint_paused(&other, &true);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+    assert!(!client.is_mint_paused());
+}
+
+// ============================================
+// mint_backdated Tests
+// ============================================
+
+fn mint_backdated_to(
+    e: &Env,
+    client: &CommitmentNFTContractClient,
+    admin: &Address,
+    owner: &Address,
+    created_at: u64,
+    expires_at: u64,
+) -> Result<u32, ContractError> {
+    let metadata = CommitmentMetadata {
+        commitment_id: String::from_str(e, "c_backfill"),
+        duration_days: 30,
+        max_loss_percent: 10,
+        commitment_type: String::from_str(e, "safe"),
+        created_at,
+        expires_at,
+        initial_amount: 1000,
+        asset_address: Address::generate(e),
+    };
+    client
+        .try_mint_backdated(admin, owner, &metadata, &5)
+        .map(|r| r.unwrap())
+        .map_err(|err| err.unwrap())
+}
+
+#[test]
+fn test_mint_backdated_stores_explicit_timestamps() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    e.ledger().with_mut(|li| li.timestamp = 10_000_000);
+
+    let token_id = mint_backdated_to(&e, &client, &admin, &owner, 1_000, 2_593_000).unwrap();
+
+    let nft = client.get_metadata(&token_id);
+    assert_eq!(nft.metadata.created_at, 1_000);
+    assert_eq!(nft.metadata.expires_at, 2_593_000);
+    assert_eq!(nft.owner, owner);
+    assert_eq!(client.balance_of(&owner), 1);
+    assert_eq!(client.total_supply(), 1);
+}
+
+#[test]
+fn test_mint_backdated_rejects_invalid_timestamps() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+
+    let result = mint_backdated_to(&e, &client, &admin, &owner, 5_000, 5_000);
+    assert_eq!(result, Err(ContractError::InvalidTimestamps));
+    assert_eq!(client.total_supply(), 0);
+    assert!(!client.is_locked());
+}
+
+#[test]
+fn test_mint_backdated_non_admin_fails() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+
+    let result = mint_backdated_to(&e, &client, &owner, &owner, 1_000, 2_000);
+    assert_eq!(result, Err(ContractError::NotAuthorized));
+}
+
+// ============================================
+// Reentrancy Guard Diagnostics Tests
+// ============================================
+
+#[test]
+fn test_force_unlock_clears_stuck_guard() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+    assert!(!client.is_locked());
+
+    e.as_contract(&client.address, || {
+        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
+    });
+    assert!(client.is_locked());
+    let result = client.try_mint(
+        &admin,
+        &owner,
+        &String::from_str(&e, "c_0"),
+        &1,
+        &10,
+        &String::from_str(&e, "balanced"),
+        &1000,
+        &asset_address,
+        &5,
+    );
+    assert_eq!(result, Err(Ok(ContractError::ReentrancyDetected)));
+
+    client.force_unlock(&admin);
+    assert!(!client.is_locked());
+    mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+}
+
+#[test]
+fn test_force_unlock_non_admin_fails() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let attacker = Address::generate(&e);
+
+    e.as_contract(&client.address, || {
+        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
+    });
+
+    let result = client.try_force_unlock(&attacker);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+    assert!(client.is_locked());
+}
+
+// ============================================
+// Deactivate / Reactivate Tests
+// ============================================
+
+#[test]
+fn test_deactivate_and_reactivate() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    assert_eq!(client.get_status(&token_id), TokenStatus::Active);
+
+    client.deactivate(&admin, &token_id);
+    assert_eq!(client.get_status(&token_id), TokenStatus::UnderReview);
+    assert!(!client.is_active(&token_id));
+    assert_eq!(client.get_settled_metadata(&0, &10).len(), 0);
+
+    client.reactivate(&admin, &token_id);
+    assert_eq!(client.get_status(&token_id), TokenStatus::Active);
+    assert!(client.is_active(&token_id));
+}
+
+#[test]
+fn test_deactivate_non_admin_fails() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    let result = client.try_deactivate(&owner, &token_id);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+}
+
+#[test]
+fn test_under_review_token_cannot_be_transferred() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    client.deactivate(&admin, &token_id);
+
+    let result = client.try_transfer(&owner, &recipient, &token_id);
+    assert_eq!(result, Err(Ok(ContractError::NFTLocked)));
+}
+
+#[test]
+fn test_settled_token_cannot_be_reactivated() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+    client.settle(&token_id);
+    assert_eq!(client.get_status(&token_id), TokenStatus::Settled);
+
+    let result = client.try_reactivate(&admin, &token_id);
+    assert_eq!(result, Err(Ok(ContractError::AlreadySettled)));
+    let result = client.try_deactivate(&admin, &token_id);
+    assert_eq!(result, Err(Ok(ContractError::AlreadySettled)));
+}
+
+#[test]
+fn test_reactivate_active_token_fails() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    let result = client.try_reactivate(&admin, &token_id);
+    assert_eq!(result, Err(Ok(ContractError::NotUnderReview)));
+}
+
+// ============================================
+// Terminate Tests
+// ============================================
+
+#[test]
+fn test_terminate_blocks_mutations_permanently() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+
+    client.terminate(&admin);
+    assert!(client.is_terminated());
+
+    let (commitment_id, duration, max_loss, commitment_type, amount, asset, penalty) =
+        create_test_metadata(&e, &asset_address);
+    assert_eq!(
+        client.try_mint(
+            &admin,
+            &owner,
+            &commitment_id,
+            &duration,
+            &max_loss,
+            &commitment_type,
+            &amount,
+            &asset,
+            &penalty,
+        ),
+        Err(Ok(ContractError::ContractTerminated))
+    );
+    assert_eq!(
+        client.try_transfer(&owner, &recipient, &token_id),
+        Err(Ok(ContractError::ContractTerminated))
+    );
+    assert_eq!(
+        client.try_settle(&token_id),
+        Err(Ok(ContractError::ContractTerminated))
+    );
+    assert_eq!(
+        client.try_settle_idempotent(&token_id),
+        Err(Ok(ContractError::ContractTerminated))
+    );
+    assert_eq!(
+        client.try_deactivate(&admin, &token_id),
+        Err(Ok(ContractError::ContractTerminated))
+    );
+    assert_eq!(
+        client.try_set_admin(&admin, &recipient),
+        Err(Ok(ContractError::ContractTerminated))
+    );
+    assert_eq!(
+        client.try_set_mint_paused(&admin, &true),
+        Err(Ok(ContractError::ContractTerminated))
+    );
+    assert_eq!(
+        client.try_reconcile_owner(&admin, &owner),
+        Err(Ok(ContractError::ContractTerminated))
+    );
+    assert!(client.try_pause().is_err());
+
+    // Terminating again cannot reset or undo anything
+    assert_eq!(
+        client.try_terminate(&admin),
+        Err(Ok(ContractError::ContractTerminated))
+    );
+    assert!(client.is_terminated());
+}
+
+#[test]
+fn test_terminate_keeps_reads_available() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    client.terminate(&admin);
+
+    assert_eq!(client.owner_of(&token_id), owner);
+    assert_eq!(client.balance_of(&owner), 1);
+    assert_eq!(client.total_supply(), 1);
+    assert!(client.is_active(&token_id));
+    assert_eq!(client.get_metadata(&token_id).token_id, token_id);
+    assert_eq!(client.get_nfts_by_owner(&owner).len(), 1);
+}
+
+#[test]
+fn test_terminate_non_admin_fails() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let attacker = Address::generate(&e);
+
+    let result = client.try_terminate(&attacker);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+    assert!(!client.is_terminated());
+}
+
+// ============================================
+// settle_idempotent Tests
+// ============================================
+
+#[test]
+fn test_settle_idempotent_twice_succeeds() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+
+    client.settle_idempotent(&token_id);
+    client.settle_idempotent(&token_id);
+    assert_eq!(client.get_status(&token_id), TokenStatus::Settled);
+
+    // The strict path still reports the double settle
+    let result = client.try_settle(&token_id);
+    assert_eq!(result, Err(Ok(ContractError::AlreadySettled)));
+}
+
+#[test]
+fn test_settle_idempotent_unexpired_fails() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+
+    let result = client.try_settle_idempotent(&token_id);
+    assert_eq!(result, Err(Ok(ContractError::NotExpired)));
+    assert!(client.is_active(&token_id));
+}
+
+#[test]
+fn test_settle_and_burn_removes_matured_token() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let burned = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    let kept = mint_to_owner(&e, &client, &owner, &asset_address, "c_1");
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+
+    client.settle_and_burn(&burned);
+
+    assert!(!client.token_exists(&burned));
+    assert_eq!(
+        client.try_owner_of(&burned),
+        Err(Ok(ContractError::TokenNotFound))
+    );
+    assert_eq!(client.balance_of(&owner), 1);
+    let owned = client.get_nfts_by_owner(&owner);
+    assert_eq!(owned.len(), 1);
+    assert_eq!(owned.get(0).unwrap().token_id, kept);
+    let all = client.get_all_metadata();
+    assert_eq!(all.len(), 1);
+    assert_eq!(all.get(0).unwrap().token_id, kept);
+    assert_eq!(client.get_tokens_expiring_on(&1, &0, &10), vec![&e, kept]);
+    // Ids are never reused
+    assert_eq!(client.get_supply_breakdown(), (1, 0, 2));
+}
+
+#[test]
+fn test_settle_and_burn_unexpired_fails() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+
+    let result = client.try_settle_and_burn(&token_id);
+    assert_eq!(result, Err(Ok(ContractError::NotExpired)));
+    assert!(client.token_exists(&token_id));
+    assert!(client.is_active(&token_id));
+    assert_eq!(client.balance_of(&owner), 1);
+}
+
+#[test]
+fn test_burn_settled_token() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let burned = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    let kept = mint_to_owner(&e, &client, &owner, &asset_address, "c_1");
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+    client.settle(&burned);
+
+    client.burn(&burned);
+
+    let last_event = e.events().all().last().unwrap();
+    assert_eq!(
+        last_event.1,
+        vec![&e, symbol_short!("Burn").into_val(&e), burned.into_val(&e)]
+    );
+    let data: (Address, u64) = last_event.2.into_val(&e);
+    assert_eq!(data, (owner.clone(), 172800));
+
+    assert!(!client.token_exists(&burned));
+    assert_eq!(client.balance_of(&owner), 1);
+    assert_eq!(client.get_nfts_by_owner(&owner).len(), 1);
+    assert_eq!(client.get_all_metadata().get(0).unwrap().token_id, kept);
+    assert_eq!(client.total_supply(), 2);
+    assert_eq!(client.live_supply(), 1);
+    assert_eq!(
+        client.try_burn(&burned),
+        Err(Ok(ContractError::TokenNotFound))
+    );
+}
+
+#[test]
+fn test_burn_rejects_active_and_unminted_tokens() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    assert_eq!(client.try_burn(&999), Err(Ok(ContractError::TokenNotFound)));
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    assert_eq!(
+        client.try_burn(&token_id),
+        Err(Ok(ContractError::TransferNotAllowed))
+    );
+
+    client.deactivate(&admin, &token_id);
+    assert_eq!(
+        client.try_burn(&token_id),
+        Err(Ok(ContractError::TransferNotAllowed))
+    );
+    assert!(client.token_exists(&token_id));
+    assert_eq!(client.live_supply(), 1);
+}
+
+#[test]
+fn test_can_settle_tracks_token_state() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+    assert!(!client.can_settle(&999));
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    // Active but not yet expired
+    assert!(!client.can_settle(&token_id));
+
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+    assert!(client.can_settle(&token_id));
+
+    client.pause();
+    assert!(!client.can_settle(&token_id));
+    client.unpause();
+
+    client.settle(&token_id);
+    assert!(!client.can_settle(&token_id));
+}
+
+// ============================================
+// Settlement Authorization Tests
+// ============================================
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+fn test_settle_from_arbitrary_address_panics() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+
+    // Nobody, including the owner, can stand in for the core contract
+    e.set_auths(&[]);
+    client.settle(&token_id);
+}
+
+#[test]
+fn test_settle_with_core_auth_succeeds() {
+    let e = Env::default();
+    let (_admin, client, core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+
+    client.settle(&token_id);
+    assert_eq!(e.auths()[0].0, core_id);
+    assert!(!client.is_active(&token_id));
+}
+
+#[test]
+fn test_mark_inactive_and_settle_and_burn_require_core_auth() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+
+    e.set_auths(&[]);
+    assert!(client.try_mark_inactive(&token_id).is_err());
+
+    // The owner's own authorization is not enough to close the token
+    e.mock_auths(&[MockAuth {
+        address: &owner,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "settle_and_burn",
+            args: (token_id,).into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_settle_and_burn(&token_id).is_err());
+    assert!(client.token_exists(&token_id));
+    assert!(client.is_active(&token_id));
+}
+
+#[test]
+fn test_settle_without_core_contract_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (admin, client) = setup_contract(&e);
+    client.initialize(&admin);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+
+    let result = client.try_settle(&token_id);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+    assert!(client.is_active(&token_id));
+}
+
+// ============================================
+// get_nfts_by_owner Tests
+// ============================================
+
+#[test]
+fn test_get_nfts_by_owner_empty() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+
+    client.initialize(&admin);
+
+    let nfts = client.get_nfts_by_owner(&owner);
+    assert_eq!(nfts.len(), 0);
+}
+
+#[test]
+fn test_get_nfts_by_owner_not_initialized_returns_empty() {
+    let e = Env::default();
+    let (_admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+
+    let nfts = client.get_nfts_by_owner(&owner);
+    assert_eq!(nfts.len(), 0);
+}
+
+#[test]
+fn test_get_nfts_by_owner() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner1 = Address::generate(&e);
+    let owner2 = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    client.initialize(&admin);
+
+    // Mint 2 NFTs for owner1
+    for _ in 0..2 {
+        client.mint(
+            &admin,
+            &owner1,
+            &String::from_str(&e, "owner1"),
+            &30,
+            &10,
+            &String::from_str(&e, "safe"),
+            &1000,
+            &asset_address,
+            &5,
+        );
+    }
+
+    // Mint 3 NFTs for owner2
+    for _ in 0..3 {
+        client.mint(
+            &admin,
+            &owner2,
+            &String::from_str(&e, "owner2"),
+            &30,
+            &10,
+            &String::from_str(&e, "safe"),
+            &1000,
+            &asset_address,
+            &5,
+        );
+    }
+
+    let owner1_nfts = client.get_nfts_by_owner(&owner1);
+    let owner2_nfts = client.get_nfts_by_owner(&owner2);
+
+    assert_eq!(owner1_nfts.len(), 2);
+    assert_eq!(owner2_nfts.len(), 3);
+
+    // Verify all owner1 NFTs belong to owner1
+    for nft in owner1_nfts.iter() {
+        assert_eq!(nft.owner, owner1);
+    }
+}
+
+#[test]
+fn test_get_nfts_by_owner_paged_reports_total() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let other = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+    for label in ["c_0", "c_1", "c_2"] {
+        mint_to_owner(&e, &client, &owner, &asset_address, label);
+    }
+    let foreign = mint_to_owner(&e, &client, &other, &asset_address, "c_3");
+    let last = mint_to_owner(&e, &client, &owner, &asset_address, "c_4");
+
+    let (page, total) = client.get_nfts_by_owner_paged(&owner, &2, &2);
+    assert_eq!(total, 4);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().token_id, 2);
+    assert_eq!(page.get(1).unwrap().token_id, last);
+    assert!(page.iter().all(|nft| nft.token_id != foreign));
+
+    // Zero limits and out-of-range starts return an empty page with the total
+    assert_eq!(
+        client.get_nfts_by_owner_paged(&owner, &0, &0),
+        (vec![&e], 4)
+    );
+    assert_eq!(
+        client.get_nfts_by_owner_paged(&owner, &u32::MAX, &u32::MAX),
+        (vec![&e], 4)
+    );
+    assert_eq!(client.get_nfts_by_owner_paged(&other, &0, &10).1, 1);
+}
+
+// ============================================
+// Transfer Tests
+// ============================================
+
+#[test]
+fn test_owner_of_not_found() {
+    let (e, contract_id, _admin) = setup_env();
+    let client = CommitmentNFTContractClient::new(&e, &contract_id);
+
+    let result = client.try_owner_of(&999);
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// Transfer Tests
+// ============================================================================
+
+#[test]
+fn test_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner1 = Address::generate(&e);
+    let owner2 = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    // Mint with 1 day duration so we can settle it
+    let token_id = client.mint(
+        &admin,
+        &owner1,
+        &String::from_str(&e, "commitment_001"),
+        &1, // 1 day duration
+        &10,
+        &String::from_str(&e, "balanced"),
+        &1000,
+        &asset_address,
+        &5,
+    );
+
+    // Verify initial state
+    assert_eq!(client.owner_of(&token_id), owner1);
+    assert_eq!(client.balance_of(&owner1), 1);
+    assert_eq!(client.balance_of(&owner2), 0);
+
+    // Fast forward time past expiration and settle.
+    e.ledger().with_mut(|li| {
+        li.timestamp = 172800; // 2 days
+    });
+    client.settle(&token_id);
+
+    // Verify NFT is now inactive (unlocked)
+    assert_eq!(client.is_active(&token_id), false);
+
+    // Transfer NFT
+    client.transfer(&owner1, &owner2, &token_id);
+
+    // Verify transfer
+    assert_eq!(client.owner_of(&token_id), owner2);
+    assert_eq!(client.balance_of(&owner1), 0);
+    assert_eq!(client.balance_of(&owner2), 1);
+
+    // Verify Transfer event
+    let events = e.events().all();
+    let last_event = events.last().unwrap();
+
+    assert_eq!(last_event.0, client.address);
+    assert_eq!(
+        last_event.1,
+        vec![
+            &e,
+            symbol_short!("Transfer").into_val(&e),
+            owner1.into_val(&e),
+            owner2.into_val(&e)
+        ]
+    );
+    let data: (u32, u64) = last_event.2.into_val(&e);
+    assert_eq!(data.0, token_id);
+}
+
+#[test]
+fn test_transfer_with_memo_records_memo() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+    let memo_token = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    let plain_token = mint_to_owner(&e, &client, &owner, &asset_address, "c_1");
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+    client.settle(&memo_token);
+    client.settle(&plain_token);
+
+    let memo = String::from_str(&e, "INV-2024-0042");
+    client.transfer_with_memo(&owner, &recipient, &memo_token, &memo);
+    assert_eq!(client.owner_of(&memo_token), recipient);
 
-    // Transfer NFT
-    client.transfer(&owner1, &owner2, &token_id);
+    let events = e.events().all();
+    let last_event = events.last().unwrap();
+    assert_eq!(
+        last_event.1,
+        vec![
+            &e,
+            Symbol::new(&e, "TransferMemo").into_val(&e),
+            owner.into_val(&e),
+            recipient.into_val(&e)
+        ]
+    );
+    let data: (u32, String, u64) = last_event.2.into_val(&e);
+    assert_eq!((data.0, data.1), (memo_token, memo.clone()));
 
-    // Verify transfer
-    assert_eq!(client.owner_of(&token_id), owner2);
-    assert_eq!(client.balance_of(&owner1), 0);
-    assert_eq!(client.balance_of(&owner2), 1);
+    let memos = client.get_transfer_memos(&memo_token, &10);
+    assert_eq!(memos.len(), 1);
+    assert_eq!(memos.get(0).unwrap().memo, memo);
+    assert_eq!(memos.get(0).unwrap().to, recipient);
 
-    // Verify Transfer event
+    // A plain transfer emits only the regular event and records no memo
+    client.transfer(&owner, &recipient, &plain_token);
     let events = e.events().all();
     let last_event = events.last().unwrap();
-
-    assert_eq!(last_event.0, client.address);
     assert_eq!(
         last_event.1,
         vec![
             &e,
             symbol_short!("Transfer").into_val(&e),
-            owner1.into_val(&e),
-            owner2.into_val(&e)
+            owner.into_val(&e),
+            recipient.into_val(&e)
         ]
     );
-    let data: (u32, u64) = last_event.2.into_val(&e);
-    assert_eq!(data.0, token_id);
+    assert_eq!(client.get_transfer_memos(&plain_token, &10).len(), 0);
+}
+
+#[test]
+fn test_transfer_with_memo_rejects_long_memo() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let token_id = mint_to_owner(&e, &client, &owner, &Address::generate(&e), "c_0");
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+    client.settle(&token_id);
+
+    let memo = String::from_str(&e, &"x".repeat(MAX_MEMO_LENGTH as usize + 1));
+    let result = client.try_transfer_with_memo(&owner, &recipient, &token_id, &memo);
+    assert_eq!(result, Err(Ok(ContractError::MemoTooLong)));
+    assert_eq!(client.owner_of(&token_id), owner);
 }
 
 #[test]
@@ -1102,6 +2735,7 @@ fn test_transfer_not_owner() {
         create_test_metadata(&e, &asset_address);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &commitment_id,
         &duration,
@@ -1147,6 +2781,7 @@ fn test_transfer_to_self() {
         create_test_metadata(&e, &asset_address);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &commitment_id,
         &duration,
@@ -1177,6 +2812,7 @@ fn test_transfer_locked_nft() {
         create_test_metadata(&e, &asset_address);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &commitment_id,
         &duration,
@@ -1207,13 +2843,14 @@ fn test_transfer_after_settlement() {
     let e = Env::default();
     e.mock_all_auths();
 
-    let (_admin, client, core_id) = setup_contract_with_core(&e);
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
     let owner = Address::generate(&e);
     let recipient = Address::generate(&e);
     let asset_address = Address::generate(&e);
 
     // Mint with 1 day duration
     let token_id = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "test_commitment"),
         &1, // 1 day duration
@@ -1247,6 +2884,259 @@ fn test_transfer_after_settlement() {
     assert_eq!(client.balance_of(&recipient), 1);
 }
 
+#[test]
+fn test_batch_transfer_single_auth_per_sender() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let mut transfers = Vec::new(&e);
+    for label in ["c_0", "c_1", "c_2"] {
+        let token_id = mint_to_owner(&e, &client, &owner, &asset_address, label);
+        transfers.push_back(TransferParams {
+            from: owner.clone(),
+            to: recipient.clone(),
+            token_id,
+        });
+    }
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+    for params in transfers.iter() {
+        client.settle(&params.token_id);
+    }
+
+    client.batch_transfer(&transfers);
+
+    // One authorization covers all three tokens
+    let auths = e.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, owner);
+
+    for params in transfers.iter() {
+        assert_eq!(client.owner_of(&params.token_id), recipient);
+    }
+    assert_eq!(client.balance_of(&owner), 0);
+    assert_eq!(client.balance_of(&recipient), 3);
+}
+
+#[test]
+fn test_batch_transfer_is_atomic() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let other = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token_a = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    let token_b = mint_to_owner(&e, &client, &other, &asset_address, "c_1");
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+    client.settle(&token_a);
+    client.settle(&token_b);
+
+    // Second entry claims a token `owner` does not hold
+    let transfers = vec![
+        &e,
+        TransferParams {
+            from: owner.clone(),
+            to: recipient.clone(),
+            token_id: token_a,
+        },
+        TransferParams {
+            from: owner.clone(),
+            to: recipient.clone(),
+            token_id: token_b,
+        },
+    ];
+    let result = client.try_batch_transfer(&transfers);
+    assert_eq!(result, Err(Ok(ContractError::NotOwner)));
+    assert_eq!(client.owner_of(&token_a), owner);
+    assert_eq!(client.owner_of(&token_b), other);
+}
+
+#[test]
+fn test_batch_transfer_empty_fails() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+
+    let result = client.try_batch_transfer(&Vec::new(&e));
+    assert_eq!(result, Err(Ok(ContractError::InvalidBatch)));
+}
+
+#[test]
+fn test_batch_transfer_oversized_fails_without_event() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let token_id = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    let mut transfers = Vec::new(&e);
+    for _ in 0..51 {
+        transfers.push_back(TransferParams {
+            from: owner.clone(),
+            to: recipient.clone(),
+            token_id,
+        });
+    }
+
+    let result = client.try_batch_transfer(&transfers);
+    assert_eq!(result, Err(Ok(ContractError::InvalidBatch)));
+    assert_eq!(client.owner_of(&token_id), owner);
+
+    // The error is the only report; a failed call publishes no events
+    let rejected = Symbol::new(&e, "BatchRejected").into_val(&e);
+    assert!(!e
+        .events()
+        .all()
+        .iter()
+        .any(|ev| ev.1.first().map_or(false, |t| t.shallow_eq(&rejected))));
+}
+
+#[test]
+fn test_transfer_all_moves_collection() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let new_owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    let mut token_ids = Vec::new(&e);
+    for label in ["c_0", "c_1", "c_2"] {
+        token_ids.push_back(mint_to_owner(&e, &client, &owner, &asset_address, label));
+    }
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+    for token_id in token_ids.iter() {
+        client.settle(&token_id);
+    }
+    // Still locked: stays with the original owner
+    let locked = mint_to_owner(&e, &client, &owner, &asset_address, "c_3");
+
+    assert_eq!(client.transfer_all(&owner, &new_owner), 3);
+
+    assert_eq!(client.balance_of(&owner), 1);
+    assert_eq!(client.balance_of(&new_owner), 3);
+    for token_id in token_ids.iter() {
+        assert_eq!(client.owner_of(&token_id), new_owner);
+    }
+    let remaining = client.get_nfts_by_owner(&owner);
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining.get(0).unwrap().token_id, locked);
+    assert_eq!(client.get_nfts_by_owner(&new_owner).len(), 3);
+
+    // Nothing left to move
+    assert_eq!(client.transfer_all(&owner, &new_owner), 0);
+}
+
+#[test]
+fn test_transfer_all_to_self_fails() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+
+    let result = client.try_transfer_all(&owner, &owner);
+    assert_eq!(result, Err(Ok(ContractError::TransferToZeroAddress)));
+}
+
+// ============================================
+// Transfer Allowlist Tests
+// ============================================
+
+/// Mint a 1-day NFT to `owner` and settle it so it can be transferred.
+fn mint_settled(
+    e: &Env,
+    client: &CommitmentNFTContractClient,
+    owner: &Address,
+    label: &str,
+) -> u32 {
+    let token_id = mint_to_owner(e, client, owner, &Address::generate(e), label);
+    e.ledger().with_mut(|li| li.timestamp += 172800);
+    client.settle(&token_id);
+    token_id
+}
+
+#[test]
+fn test_allowlist_restricts_recipients() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let allowed = Address::generate(&e);
+    let stranger = Address::generate(&e);
+
+    let token_a = mint_settled(&e, &client, &owner, "c_0");
+    let token_b = mint_settled(&e, &client, &owner, "c_1");
+
+    client.set_transfer_allowlist_enabled(&admin, &true);
+    client.add_to_allowlist(&admin, &allowed);
+    assert!(client.is_transfer_allowlist_enabled());
+    assert!(client.is_allowlisted(&allowed));
+
+    assert_eq!(
+        client.try_transfer(&owner, &stranger, &token_a),
+        Err(Ok(ContractError::RecipientNotAllowed))
+    );
+    let to_stranger = vec![
+        &e,
+        TransferParams {
+            from: owner.clone(),
+            to: stranger.clone(),
+            token_id: token_b,
+        },
+    ];
+    assert_eq!(
+        client.try_batch_transfer(&to_stranger),
+        Err(Ok(ContractError::RecipientNotAllowed))
+    );
+    assert_eq!(
+        client.try_transfer_all(&owner, &stranger),
+        Err(Ok(ContractError::RecipientNotAllowed))
+    );
+
+    client.transfer(&owner, &allowed, &token_a);
+    assert_eq!(client.owner_of(&token_a), allowed);
+
+    // Removal closes the door again
+    client.remove_from_allowlist(&admin, &allowed);
+    assert!(!client.is_allowlisted(&allowed));
+    assert_eq!(
+        client.try_transfer(&owner, &allowed, &token_b),
+        Err(Ok(ContractError::RecipientNotAllowed))
+    );
+}
+
+#[test]
+fn test_disabling_allowlist_restores_open_transfers() {
+    let e = Env::default();
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let stranger = Address::generate(&e);
+
+    let token_id = mint_settled(&e, &client, &owner, "c_0");
+    client.set_transfer_allowlist_enabled(&admin, &true);
+    client.set_transfer_allowlist_enabled(&admin, &false);
+
+    client.transfer(&owner, &stranger, &token_id);
+    assert_eq!(client.owner_of(&token_id), stranger);
+}
+
+#[test]
+fn test_allowlist_admin_only() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let attacker = Address::generate(&e);
+
+    assert_eq!(
+        client.try_set_transfer_allowlist_enabled(&attacker, &true),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+    assert_eq!(
+        client.try_add_to_allowlist(&attacker, &attacker),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+}
+
 // ============================================
 // Transfer Edge Cases Tests
 // ============================================
@@ -1275,6 +3165,7 @@ fn test_transfer_edge_case_self_transfer() {
         create_test_metadata(&e, &asset_address);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &commitment_id,
         &duration,
@@ -1320,6 +3211,7 @@ fn test_transfer_edge_case_from_non_owner() {
         create_test_metadata(&e, &asset_address);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &commitment_id,
         &duration,
@@ -1365,6 +3257,7 @@ fn test_transfer_edge_case_address_validation_by_sdk() {
         create_test_metadata(&e, &asset_address);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &commitment_id,
         &duration,
@@ -1410,7 +3303,7 @@ fn test_transfer_edge_cases_comprehensive() {
     let e = Env::default();
     e.mock_all_auths();
 
-    let (_admin, client, core_id) = setup_contract_with_core(&e);
+    let (admin, client, core_id) = setup_contract_with_core(&e);
     let owner1 = Address::generate(&e);
     let owner2 = Address::generate(&e);
     let owner3 = Address::generate(&e);
@@ -1418,6 +3311,7 @@ fn test_transfer_edge_cases_comprehensive() {
 
     // Mint two separate NFTs to test transfer chains
     let token_id_1 = client.mint(
+        &admin,
         &owner1,
         &String::from_str(&e, "commitment_edge_case_1"),
         &1, // 1 day to allow settlement
@@ -1429,6 +3323,7 @@ fn test_transfer_edge_cases_comprehensive() {
     );
 
     let token_id_2 = client.mint(
+        &admin,
         &owner1,
         &String::from_str(&e, "commitment_edge_case_2"),
         &1, // 1 day to allow settlement
@@ -1561,12 +3456,13 @@ fn test_transfer_edge_cases_comprehensive() {
 #[test]
 fn test_settle() {
     let e = Env::default();
-    let (_admin, client, core_id) = setup_contract_with_core(&e);
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
     let owner = Address::generate(&e);
     let asset_address = Address::generate(&e);
 
     // Mint with 1 day duration
     let token_id = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "test_commitment"),
         &1, // 1 day duration
@@ -1616,11 +3512,12 @@ fn test_settle() {
 #[should_panic(expected = "Error(Contract, #9)")] // NotExpired
 fn test_settle_not_expired() {
     let e = Env::default();
-    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
     let owner = Address::generate(&e);
     let asset_address = Address::generate(&e);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "test_commitment"),
         &30, // 30 days duration
@@ -1639,11 +3536,12 @@ fn test_settle_not_expired() {
 #[should_panic(expected = "Error(Contract, #8)")] // AlreadySettled
 fn test_settle_already_settled() {
     let e = Env::default();
-    let (_admin, client, core_id) = setup_contract_with_core(&e);
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
     let owner = Address::generate(&e);
     let asset_address = Address::generate(&e);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "test_commitment"),
         &1,
@@ -1666,11 +3564,12 @@ fn test_settle_already_settled() {
 #[test]
 fn test_settle_succeeds_after_expiry() {
     let e = Env::default();
-    let (_admin, client, core_id) = setup_contract_with_core(&e);
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
     let owner = Address::generate(&e);
     let asset_address = Address::generate(&e);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "test_commitment"),
         &1,
@@ -1701,6 +3600,7 @@ fn test_is_expired() {
     client.initialize(&admin);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "test_commitment"),
         &1, // 1 day
@@ -1754,6 +3654,7 @@ fn test_token_exists() {
         create_test_metadata(&e, &asset_address);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &commitment_id,
         &duration,
@@ -1785,6 +3686,16 @@ fn test_get_admin() {
     assert_eq!(client.get_admin(), admin);
 }
 
+#[test]
+fn test_is_initialized_reflects_initialize() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+
+    assert!(!client.is_initialized());
+    client.initialize(&admin);
+    assert!(client.is_initialized());
+}
+
 #[test]
 #[should_panic(expected = "Error(Contract, #1)")] // NotInitialized
 fn test_get_admin_not_initialized() {
@@ -1826,6 +3737,7 @@ fn test_mint_max_loss_percent_over_100() {
     client.initialize(&admin);
 
     client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_001"),
         &30,
@@ -1847,6 +3759,7 @@ fn test_mint_max_loss_percent_zero() {
     client.initialize(&admin);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_001"),
         &30,
@@ -1873,6 +3786,7 @@ fn test_mint_duration_days_zero() {
     client.initialize(&admin);
 
     client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_001"),
         &0, // duration_days = 0
@@ -1894,6 +3808,7 @@ fn test_mint_duration_days_one() {
     client.initialize(&admin);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_001"),
         &1, // duration_days = 1 (minimum valid)
@@ -1919,6 +3834,7 @@ fn test_mint_duration_days_max() {
     client.initialize(&admin);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_001"),
         &u32::MAX, // duration_days = u32::MAX
@@ -1959,6 +3875,7 @@ fn test_metadata_timestamps() {
     client.initialize(&admin);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "test"),
         &30, // 30 days
@@ -1982,13 +3899,14 @@ fn test_balance_updates_after_transfer() {
     let e = Env::default();
     e.mock_all_auths();
 
-    let (_admin, client, core_id) = setup_contract_with_core(&e);
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
     let owner1 = Address::generate(&e);
     let owner2 = Address::generate(&e);
     let asset_address = Address::generate(&e);
 
     // Mint multiple NFTs for owner1 with 1 day duration so we can settle them
     client.mint(
+        &admin,
         &owner1,
         &String::from_str(&e, "commitment_0"),
         &1, // 1 day duration
@@ -1999,6 +3917,7 @@ fn test_balance_updates_after_transfer() {
         &5,
     );
     client.mint(
+        &admin,
         &owner1,
         &String::from_str(&e, "commitment_1"),
         &1, // 1 day duration
@@ -2009,6 +3928,7 @@ fn test_balance_updates_after_transfer() {
         &5,
     );
     client.mint(
+        &admin,
         &owner1,
         &String::from_str(&e, "commitment_2"),
         &1, // 1 day duration
@@ -2064,6 +3984,7 @@ fn test_mint_blocked_when_paused() {
     client.pause();
 
     client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "paused_commitment"),
         &30,
@@ -2089,6 +4010,7 @@ fn test_transfer_blocked_when_paused() {
     client.initialize(&admin);
 
     let token_id = client.mint(
+        &admin,
         &owner1,
         &String::from_str(&e, "commitment_001"),
         &30,
@@ -2108,12 +4030,13 @@ fn _test_unpause_restores_transfer() {
     let e = Env::default();
     e.mock_all_auths();
 
-    let (_admin, client, core_id) = setup_contract_with_core(&e);
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
     let owner1 = Address::generate(&e);
     let owner2 = Address::generate(&e);
     let asset_address = Address::generate(&e);
 
     let token_id = client.mint(
+        &admin,
         &owner1,
         &String::from_str(&e, "commitment_002"),
         &1, // 1 day duration so we can settle
@@ -2476,12 +4399,13 @@ fn test_invariant_transfer_chain_preserves_supply() {
 fn test_owner_multiple_nfts_balance() {
     let e = Env::default();
     e.mock_all_auths();
-    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
     let owner = Address::generate(&e);
     let asset_address = Address::generate(&e);
 
     // Mint 3 NFTs to the same owner
     let _token1 = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_001"),
         &30,
@@ -2493,6 +4417,7 @@ fn test_owner_multiple_nfts_balance() {
     );
 
     let _token2 = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_002"),
         &30,
@@ -2504,6 +4429,7 @@ fn test_owner_multiple_nfts_balance() {
     );
 
     let _token3 = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_003"),
         &30,
@@ -2523,12 +4449,13 @@ fn test_owner_multiple_nfts_balance() {
 fn test_owner_multiple_nfts_owner_of_each() {
     let e = Env::default();
     e.mock_all_auths();
-    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
     let owner = Address::generate(&e);
     let asset_address = Address::generate(&e);
 
     // Mint 3 NFTs to the same owner
     let token1 = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_001"),
         &30,
@@ -2540,6 +4467,7 @@ fn test_owner_multiple_nfts_owner_of_each() {
     );
 
     let token2 = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_002"),
         &30,
@@ -2551,6 +4479,7 @@ fn test_owner_multiple_nfts_owner_of_each() {
     );
 
     let token3 = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_003"),
         &30,
@@ -2571,12 +4500,13 @@ fn test_owner_multiple_nfts_owner_of_each() {
 fn test_owner_multiple_nfts_settle_one() {
     let e = Env::default();
     e.mock_all_auths();
-    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let (admin, client, _core_id) = setup_contract_with_core(&e);
     let owner = Address::generate(&e);
     let asset_address = Address::generate(&e);
 
     // Mint 3 NFTs with 1-day duration
     let token1 = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_001"),
         &1,
@@ -2588,6 +4518,7 @@ fn test_owner_multiple_nfts_settle_one() {
     );
 
     let token2 = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_002"),
         &1,
@@ -2599,6 +4530,7 @@ fn test_owner_multiple_nfts_settle_one() {
     );
 
     let token3 = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_003"),
         &1,
@@ -2634,3 +4566,252 @@ fn test_owner_multiple_nfts_settle_one() {
     let nft3 = client.try_get_metadata(&token3).unwrap().unwrap();
     assert_eq!(nft3.is_active, true);
 }
+
+/// Contract with a mock core set, plus one settled (transferable) token per
+/// label. Returns (admin, client, core_id, owner, token_ids).
+fn setup_propagation_test<'a>(
+    e: &'a Env,
+    labels: &[&str],
+) -> (
+    Address,
+    CommitmentNFTContractClient<'a>,
+    Address,
+    Address,
+    std::vec::Vec<u32>,
+) {
+    e.mock_all_auths();
+    let (admin, client) = setup_contract(e);
+    client.initialize(&admin);
+    let core_id = e.register_contract(None, MockCoreContract);
+    client.set_core_contract(&core_id);
+    let owner = Address::generate(e);
+    let asset_address = Address::generate(e);
+    let token_ids: std::vec::Vec<u32> = labels
+        .iter()
+        .map(|label| mint_to_owner(e, &client, &owner, &asset_address, label))
+        .collect();
+    e.ledger().with_mut(|li| li.timestamp = 172800);
+    for token_id in token_ids.iter() {
+        client.settle(token_id);
+    }
+    (admin, client, core_id, owner, token_ids)
+}
+
+#[test]
+fn test_transfer_propagates_owner_to_core() {
+    let e = Env::default();
+    let (admin, client, core_id, owner, token_ids) = setup_propagation_test(&e, &["c_known"]);
+    let core = MockCoreContractClient::new(&e, &core_id);
+    let recipient = Address::generate(&e);
+    let commitment_id = String::from_str(&e, "c_known");
+
+    // Off by default: the core is not told about transfers
+    assert!(!client.is_ownership_propagated());
+    client.transfer(&owner, &recipient, &token_ids[0]);
+    assert_eq!(core.commitment_owner(&commitment_id), None);
+
+    client.set_propagate_ownership(&admin, &true);
+    assert!(client.is_ownership_propagated());
+    client.transfer(&recipient, &owner, &token_ids[0]);
+    assert_eq!(core.commitment_owner(&commitment_id), Some(owner));
+}
+
+#[test]
+fn test_transfer_fails_when_core_rejects_ownership_update() {
+    let e = Env::default();
+    let (admin, client, _core_id, owner, token_ids) = setup_propagation_test(&e, &["c_other"]);
+    let recipient = Address::generate(&e);
+    client.set_propagate_ownership(&admin, &true);
+
+    let result = client.try_transfer(&owner, &recipient, &token_ids[0]);
+    assert_eq!(result, Err(Ok(ContractError::CorePropagationFailed)));
+    assert_eq!(client.owner_of(&token_ids[0]), owner);
+    assert_eq!(client.balance_of(&owner), 1);
+    assert_eq!(client.balance_of(&recipient), 0);
+
+    // A failed transfer_all is rolled back as a whole
+    let result = client.try_transfer_all(&owner, &recipient);
+    assert_eq!(result, Err(Ok(ContractError::CorePropagationFailed)));
+    assert_eq!(client.owner_of(&token_ids[0]), owner);
+}
+
+#[test]
+fn test_set_propagate_ownership_non_admin_fails() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let attacker = Address::generate(&e);
+
+    let result = client.try_set_propagate_ownership(&attacker, &true);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+    assert!(!client.is_ownership_propagated());
+}
+
+#[test]
+fn test_batch_approve_best_effort_skips_non_owned() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let other = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+    let first = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    let second = mint_to_owner(&e, &client, &owner, &asset_address, "c_1");
+    let foreign = mint_to_owner(&e, &client, &other, &asset_address, "c_2");
+
+    let token_ids = vec![&e, first, foreign, second, 99];
+    let result = client.batch_approve(&owner, &spender, &token_ids, &BatchMode::BestEffort);
+
+    assert!(!result.success);
+    assert_eq!(result.success_count, 2);
+    assert_eq!(result.errors.len(), 2);
+    let not_owned = result.errors.get(0).unwrap();
+    assert_eq!(not_owned.index, 1);
+    assert_eq!(not_owned.error_code, ContractError::NotOwner as u32);
+    let missing = result.errors.get(1).unwrap();
+    assert_eq!(missing.index, 3);
+    assert_eq!(missing.error_code, ContractError::TokenNotFound as u32);
+
+    assert_eq!(client.get_approved(&first), Some(spender.clone()));
+    assert_eq!(client.get_approved(&second), Some(spender));
+    assert_eq!(client.get_approved(&foreign), None);
+}
+
+#[test]
+fn test_batch_approve_atomic_aborts_on_non_owned() {
+    let e = Env::default();
+    let (_admin, client, _core_id) = setup_contract_with_core(&e);
+    let owner = Address::generate(&e);
+    let other = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+    let first = mint_to_owner(&e, &client, &owner, &asset_address, "c_0");
+    let foreign = mint_to_owner(&e, &client, &other, &asset_address, "c_1");
+
+    let token_ids = vec![&e, first, foreign];
+    let result = client.batch_approve(&owner, &spender, &token_ids, &BatchMode::Atomic);
+
+    assert!(!result.success);
+    assert_eq!(result.success_count, 0);
+    assert_eq!(
+        result.errors.get(0).unwrap().error_code,
+        ContractError::NotOwner as u32
+    );
+    assert_eq!(client.get_approved(&first), None);
+
+    let result = client.batch_approve(&owner, &spender, &vec![&e, first], &BatchMode::Atomic);
+    assert!(result.success);
+    assert_eq!(client.get_approved(&first), Some(spender));
+}
+
+#[test]
+fn test_transfer_clears_approval() {
+    let e = Env::default();
+    let (_admin, client, _core_id, owner, token_ids) = setup_propagation_test(&e, &["c_0"]);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    client.batch_approve(
+        &owner,
+        &spender,
+        &vec![&e, token_ids[0]],
+        &BatchMode::Atomic,
+    );
+    assert_eq!(client.get_approved(&token_ids[0]), Some(spender));
+
+    client.transfer(&owner, &recipient, &token_ids[0]);
+    assert_eq!(client.get_approved(&token_ids[0]), None);
+}
+
+#[test]
+fn test_approve_non_owner_fails() {
+    let e = Env::default();
+    let (_admin, client, _core_id, _owner, token_ids) = setup_propagation_test(&e, &["c_0"]);
+    let attacker = Address::generate(&e);
+
+    let result = client.try_approve(&attacker, &attacker, &token_ids[0]);
+    assert_eq!(result, Err(Ok(ContractError::NotOwner)));
+    assert_eq!(client.get_approved(&token_ids[0]), None);
+}
+
+#[test]
+fn test_transfer_from_by_approved_spender_consumes_approval() {
+    let e = Env::default();
+    let (_admin, client, _core_id, owner, token_ids) = setup_propagation_test(&e, &["c_0"]);
+    let spender = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let token_id = token_ids[0];
+
+    client.approve(&owner, &spender, &token_id);
+    assert_eq!(client.get_approved(&token_id), Some(spender.clone()));
+
+    client.transfer_from(&spender, &owner, &buyer, &token_id);
+    assert_eq!(client.owner_of(&token_id), buyer);
+    assert_eq!(client.balance_of(&owner), 0);
+    assert_eq!(client.balance_of(&buyer), 1);
+    assert_eq!(client.get_approved(&token_id), None);
+
+    // The consumed approval cannot move the token again
+    let result = client.try_transfer_from(&spender, &buyer, &owner, &token_id);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+    assert_eq!(client.owner_of(&token_id), buyer);
+}
+
+#[test]
+fn test_transfer_from_unapproved_spender_fails() {
+    let e = Env::default();
+    let (_admin, client, _core_id, owner, token_ids) = setup_propagation_test(&e, &["c_0"]);
+    let stranger = Address::generate(&e);
+
+    let result = client.try_transfer_from(&stranger, &owner, &stranger, &token_ids[0]);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+
+    // The owner acting as its own spender needs no approval
+    client.transfer_from(&owner, &owner, &stranger, &token_ids[0]);
+    assert_eq!(client.owner_of(&token_ids[0]), stranger);
+}
+
+#[test]
+fn test_set_approval_for_all_grant_and_revoke() {
+    let e = Env::default();
+    let (_admin, client, _core_id, owner, _token_ids) = setup_propagation_test(&e, &["c_0"]);
+    let operator = Address::generate(&e);
+
+    assert!(!client.is_approved_for_all(&owner, &operator));
+    client.set_approval_for_all(&owner, &operator, &true);
+    assert!(client.is_approved_for_all(&owner, &operator));
+    assert!(!client.is_approved_for_all(&operator, &owner));
+
+    client.set_approval_for_all(&owner, &operator, &false);
+    assert!(!client.is_approved_for_all(&owner, &operator));
+}
+
+#[test]
+fn test_operator_transfers_any_owned_token() {
+    let e = Env::default();
+    let (_admin, client, _core_id, owner, token_ids) = setup_propagation_test(&e, &["c_0", "c_1"]);
+    let operator = Address::generate(&e);
+    let buyer = Address::generate(&e);
+
+    client.set_approval_for_all(&owner, &operator, &true);
+    client.transfer_from(&operator, &owner, &buyer, &token_ids[0]);
+    client.transfer_from(&operator, &owner, &buyer, &token_ids[1]);
+    assert_eq!(client.balance_of(&buyer), 2);
+
+    // Operator rights are per owner and do not follow the tokens
+    let result = client.try_transfer_from(&operator, &buyer, &owner, &token_ids[0]);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+}
+
+#[test]
+fn test_revoked_operator_cannot_transfer() {
+    let e = Env::default();
+    let (_admin, client, _core_id, owner, token_ids) = setup_propagation_test(&e, &["c_0"]);
+    let operator = Address::generate(&e);
+    let buyer = Address::generate(&e);
+
+    client.set_approval_for_all(&owner, &operator, &true);
+    client.set_approval_for_all(&owner, &operator, &false);
+
+    let result = client.try_transfer_from(&operator, &owner, &buyer, &token_ids[0]);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+    assert_eq!(client.owner_of(&token_ids[0]), owner);
+}
@@ -1,5 +1,5 @@
 #![allow(unused)]
-use soroban_sdk::{contracttype, Address, Env, IntoVal, Map, String, TryFromVal, Val, Vec};
+use soroban_sdk::{contracttype, Address, Env, IntoVal, Map, String, Symbol, TryFromVal, Val, Vec};
 
 /// Batch processing mode for handling multiple operations
 #[contracttype]
@@ -23,6 +23,30 @@ pub struct BatchError {
     pub context: String,
 }
 
+/// Processing accounting for a batch, reported separately from success/failure
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchStats {
+    /// Number of operations submitted in the batch
+    pub total: u32,
+    /// Number of operations examined by the processor (succeeded or failed)
+    pub processed: u32,
+    /// Number of operations never examined (e.g. after an atomic abort)
+    pub skipped: u32,
+}
+
+impl BatchStats {
+    /// Build stats for a batch of `total` items of which `processed` were examined
+    pub fn new(total: u32, processed: u32) -> Self {
+        let processed = processed.min(total);
+        BatchStats {
+            total,
+            processed,
+            skipped: total - processed,
+        }
+    }
+}
+
 /// Result of a batch operation returning Strings (e.g., commitment IDs)
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -33,6 +57,22 @@ pub struct BatchResultString {
     pub results: Vec<String>,
     /// List of errors encountered (empty if all succeeded)
     pub errors: Vec<BatchError>,
+    /// Processed/skipped accounting for the batch
+    pub stats: BatchStats,
+}
+
+/// Result of a batch operation returning amounts (e.g., per-commitment payouts)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchResultI128 {
+    /// Overall success status (true if all succeeded, false if any failed)
+    pub success: bool,
+    /// Results from each successful operation, in batch order
+    pub results: Vec<i128>,
+    /// List of errors encountered (empty if all succeeded)
+    pub errors: Vec<BatchError>,
+    /// Processed/skipped accounting for the batch
+    pub stats: BatchStats,
 }
 
 /// Result of a batch operation with no return values (just success/failure)
@@ -45,36 +85,96 @@ pub struct BatchResultVoid {
     pub success_count: u32,
     /// List of errors encountered (empty if all succeeded)
     pub errors: Vec<BatchError>,
+    /// Processed/skipped accounting for the batch
+    pub stats: BatchStats,
 }
 
 impl BatchResultString {
     /// Create a new successful batch result
     pub fn success(e: &Env, results: Vec<String>) -> Self {
+        let count = results.len();
         BatchResultString {
             success: true,
             results,
             errors: Vec::new(e),
+            stats: BatchStats::new(count, count),
         }
     }
 
     /// Create a new failed batch result
+    ///
+    /// Stats are zeroed; callers that know how far the batch got should
+    /// attach them with `with_stats`.
     pub fn failure(e: &Env, errors: Vec<BatchError>) -> Self {
         BatchResultString {
             success: false,
             results: Vec::new(e),
             errors,
+            stats: BatchStats::new(0, 0),
         }
     }
 
     /// Create a partial result (BestEffort mode)
     pub fn partial(results: Vec<String>, errors: Vec<BatchError>) -> Self {
         let success = errors.is_empty();
+        let count = results.len() + errors.len();
         BatchResultString {
             success,
             results,
             errors,
+            stats: BatchStats::new(count, count),
         }
     }
+
+    /// Replace the processed/skipped accounting
+    pub fn with_stats(mut self, total: u32, processed: u32) -> Self {
+        self.stats = BatchStats::new(total, processed);
+        self
+    }
+}
+
+impl BatchResultI128 {
+    /// Create a new successful batch result
+    pub fn success(e: &Env, results: Vec<i128>) -> Self {
+        let count = results.len();
+        BatchResultI128 {
+            success: true,
+            results,
+            errors: Vec::new(e),
+            stats: BatchStats::new(count, count),
+        }
+    }
+
+    /// Create a new failed batch result
+    ///
+    /// Stats are zeroed; callers that know how far the batch got should
+    /// attach them with `with_stats`.
+    pub fn failure(e: &Env, errors: Vec<BatchError>) -> Self {
+        BatchResultI128 {
+            success: false,
+            results: Vec::new(e),
+            errors,
+            stats: BatchStats::new(0, 0),
+        }
+    }
+
+    /// Create a partial result (BestEffort mode)
+    pub fn partial(results: Vec<i128>, errors: Vec<BatchError>) -> Self {
+        let success = errors.is_empty();
+        let count = results.len() + errors.len();
+        BatchResultI128 {
+            success,
+            results,
+            errors,
+            stats: BatchStats::new(count, count),
+        }
+    }
+
+    /// Replace the processed/skipped accounting
+    pub fn with_stats(mut self, total: u32, processed: u32) -> Self {
+        self.stats = BatchStats::new(total, processed);
+        self
+    }
 }
 
 impl BatchResultVoid {
@@ -84,27 +184,40 @@ impl BatchResultVoid {
             success: true,
             success_count: count,
             errors: Vec::new(e),
+            stats: BatchStats::new(count, count),
         }
     }
 
     /// Create a new failed batch result
+    ///
+    /// Stats are zeroed; callers that know how far the batch got should
+    /// attach them with `with_stats`.
     pub fn failure(e: &Env, errors: Vec<BatchError>) -> Self {
         BatchResultVoid {
             success: false,
             success_count: 0,
             errors,
+            stats: BatchStats::new(0, 0),
         }
     }
 
     /// Create a partial result (BestEffort mode)
     pub fn partial(count: u32, errors: Vec<BatchError>) -> Self {
         let success = errors.is_empty();
+        let total = count + errors.len();
         BatchResultVoid {
             success,
             success_count: count,
             errors,
+            stats: BatchStats::new(total, total),
         }
     }
+
+    /// Replace the processed/skipped accounting
+    pub fn with_stats(mut self, total: u32, processed: u32) -> Self {
+        self.stats = BatchStats::new(total, processed);
+        self
+    }
 }
 
 /// Detailed operation report for BestEffort mode
@@ -315,6 +428,27 @@ impl BatchProcessor {
         Self::validate_batch_size(e, batch_size, max_size)
     }
 
+    /// Emit a `BatchRejected` event for a batch that failed `enforce_batch_limits`.
+    ///
+    /// Event data is `(batch_size, max_size, error_code)` so monitoring can spot
+    /// oversized or empty submissions.
+    pub fn emit_batch_rejected(
+        e: &Env,
+        batch_size: u32,
+        contract_name: Option<String>,
+        error_code: u32,
+    ) {
+        let max_size = if let Some(name) = contract_name {
+            Self::get_contract_limit(e, name)
+        } else {
+            Self::max_batch_size(e)
+        };
+        e.events().publish(
+            (Symbol::new(e, "BatchRejected"),),
+            (batch_size, max_size, error_code),
+        );
+    }
+
     /// Initialize batch configuration with default values
     pub fn initialize_batch_config(e: &Env) {
         if !e.storage().instance().has(&BatchDataKey::Config) {
@@ -419,6 +553,7 @@ mod tests {
         assert!(!batch_result.success);
         assert_eq!(batch_result.results.len(), 1);
         assert_eq!(batch_result.errors.len(), 1);
+        assert_eq!(batch_result.stats, BatchStats::new(2, 2));
     }
 
     #[test]
@@ -444,6 +579,34 @@ mod tests {
         assert!(!batch_result.success);
         assert_eq!(batch_result.success_count, 3);
         assert_eq!(batch_result.errors.len(), 1);
+        assert_eq!(batch_result.stats, BatchStats::new(4, 4));
+        assert_eq!(batch_result.stats.skipped, 0);
+    }
+
+    #[test]
+    fn test_batch_result_void_failure_with_stats() {
+        let e = Env::default();
+        let mut errors = Vec::new(&e);
+        errors.push_back(BatchError {
+            index: 1,
+            error_code: 1,
+            context: String::from_str(&e, "test error"),
+        });
+
+        // Atomic abort at index 1 of a 5-item batch
+        let batch_result = BatchResultVoid::failure(&e, errors).with_stats(5, 2);
+        assert!(!batch_result.success);
+        assert_eq!(batch_result.success_count, 0);
+        assert_eq!(batch_result.stats.total, 5);
+        assert_eq!(batch_result.stats.processed, 2);
+        assert_eq!(batch_result.stats.skipped, 3);
+    }
+
+    #[test]
+    fn test_batch_stats_clamps_processed() {
+        let stats = BatchStats::new(3, 10);
+        assert_eq!(stats.processed, 3);
+        assert_eq!(stats.skipped, 0);
     }
 
     #[test]
@@ -0,0 +1,35 @@
+//! Commitment data types shared by the core contract and its consumers
+//!
+//! The core contract stores these records and other contracts (e.g. the
+//! attestation engine) decode them from cross-contract calls, so both must
+//! use the same definitions to keep their ABIs in sync.
+
+use soroban_sdk::{contracttype, Address, String};
+
+/// Rules a commitment must follow
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitmentRules {
+    pub duration_days: u32,
+    pub max_loss_percent: u32,
+    pub commitment_type: String, // "safe", "balanced", "aggressive"
+    pub early_exit_penalty: u32,
+    pub min_fee_threshold: i128,
+    pub grace_period_days: u32,
+}
+
+/// A commitment as stored by the core contract
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Commitment {
+    pub commitment_id: String,
+    pub owner: Address,
+    pub nft_token_id: u32,
+    pub rules: CommitmentRules,
+    pub amount: i128,
+    pub asset_address: Address,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub current_value: i128,
+    pub status: String, // "active", "settled", "violated", "early_exit"
+}
@@ -12,9 +12,11 @@
 //! - Access control patterns
 //! - Event emission patterns
 //! - Rate limiting helpers
+//! - Commitment data types shared across contracts
 
 pub mod access_control;
 pub mod batch;
+pub mod commitment;
 pub mod emergency;
 pub mod error_codes;
 pub mod errors;
@@ -32,6 +34,7 @@ mod tests;
 // Re-export commonly used items
 pub use access_control::*;
 pub use batch::*;
+pub use commitment::*;
 pub use emergency::EmergencyControl;
 pub use error_codes::*;
 pub use errors::*;
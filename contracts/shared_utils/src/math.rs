@@ -1,5 +1,19 @@
 //! Math utilities for safe arithmetic operations and percentage calculations
 
+use soroban_sdk::contracttype;
+
+/// How integer division rounds its result
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoundingMode {
+    /// Round toward negative infinity (matches truncation for non-negative values)
+    Floor,
+    /// Round toward positive infinity
+    Ceil,
+    /// Round to the closest integer, halves away from zero
+    Nearest,
+}
+
 /// Safe math operations to prevent overflow/underflow
 pub struct SafeMath;
 
@@ -27,6 +41,34 @@ impl SafeMath {
         a.checked_div(b).expect("Math: division overflow")
     }
 
+    /// Divide `a` by `b`, rounding the quotient according to `mode`
+    pub fn div_rounded(a: i128, b: i128, mode: RoundingMode) -> i128 {
+        if b == 0 {
+            panic!("Math: division by zero");
+        }
+        let quotient = Self::div(a, b);
+        let remainder = a % b;
+        if remainder == 0 {
+            return quotient;
+        }
+        // Sign of the exact quotient; truncation moved it toward zero
+        let negative = (a < 0) != (b < 0);
+        let away = match mode {
+            RoundingMode::Floor => negative,
+            RoundingMode::Ceil => !negative,
+            RoundingMode::Nearest => {
+                Self::mul(remainder.unsigned_abs() as i128, 2) >= b.unsigned_abs() as i128
+            }
+        };
+        if !away {
+            quotient
+        } else if negative {
+            Self::sub(quotient, 1)
+        } else {
+            Self::add(quotient, 1)
+        }
+    }
+
     /// Calculate percentage: (value * percent) / 100
     ///
     /// # Arguments
@@ -79,6 +121,36 @@ impl SafeMath {
         Self::percent_from(loss, initial)
     }
 
+    /// Calculate loss percentage like `loss_percent`, rounding per `mode`
+    ///
+    /// With `RoundingMode::Floor` a 9.6% loss reports 9; `Ceil` and
+    /// `Nearest` report 10.
+    pub fn loss_percent_rounded(initial: i128, current: i128, mode: RoundingMode) -> i128 {
+        if initial == 0 {
+            panic!("Math: cannot calculate loss percent from zero initial value");
+        }
+        let loss = Self::sub(initial, current);
+        Self::div_rounded(Self::mul(loss, 100), initial, mode)
+    }
+
+    /// Calculate loss in basis points: ((initial - current) * 10000) / initial
+    ///
+    /// Finer-grained than `loss_percent`, e.g. a 0.5% loss is 50 bps rather than 0%.
+    ///
+    /// # Arguments
+    /// * `initial` - The initial value
+    /// * `current` - The current value
+    ///
+    /// # Returns
+    /// The loss in basis points as i128 (can be negative if current > initial)
+    pub fn loss_bps(initial: i128, current: i128) -> i128 {
+        if initial == 0 {
+            panic!("Math: cannot calculate loss bps from zero initial value");
+        }
+        let loss = Self::sub(initial, current);
+        Self::div(Self::mul(loss, 10000), initial)
+    }
+
     /// Calculate gain percentage: ((current - initial) * 100) / initial
     ///
     /// # Arguments
@@ -119,6 +191,14 @@ impl SafeMath {
     pub fn penalty_amount(value: i128, penalty_percent: u32) -> i128 {
         Self::percent(value, penalty_percent)
     }
+
+    /// Calculate the penalty amount like `penalty_amount`, rounding per `mode`
+    pub fn penalty_amount_rounded(value: i128, penalty_percent: u32, mode: RoundingMode) -> i128 {
+        if penalty_percent > 100 {
+            panic!("Math: percent must be <= 100");
+        }
+        Self::div_rounded(Self::mul(value, penalty_percent as i128), 100, mode)
+    }
 }
 
 #[cfg(test)]
@@ -176,6 +256,13 @@ mod tests {
         assert_eq!(SafeMath::loss_percent(1000, 1000), 0);
     }
 
+    #[test]
+    fn test_loss_bps() {
+        assert_eq!(SafeMath::loss_bps(1000, 995), 50);
+        assert_eq!(SafeMath::loss_bps(1000, 900), 1000);
+        assert_eq!(SafeMath::loss_bps(1000, 1100), -1000);
+    }
+
     #[test]
     fn test_gain_percent() {
         assert_eq!(SafeMath::gain_percent(1000, 1100), 10);
@@ -196,4 +283,53 @@ mod tests {
         assert_eq!(SafeMath::penalty_amount(1000, 5), 50);
         assert_eq!(SafeMath::penalty_amount(1000, 0), 0);
     }
+
+    #[test]
+    fn test_div_rounded() {
+        assert_eq!(SafeMath::div_rounded(96, 10, RoundingMode::Floor), 9);
+        assert_eq!(SafeMath::div_rounded(96, 10, RoundingMode::Ceil), 10);
+        assert_eq!(SafeMath::div_rounded(96, 10, RoundingMode::Nearest), 10);
+        assert_eq!(SafeMath::div_rounded(94, 10, RoundingMode::Nearest), 9);
+        assert_eq!(SafeMath::div_rounded(95, 10, RoundingMode::Nearest), 10);
+        assert_eq!(SafeMath::div_rounded(90, 10, RoundingMode::Ceil), 9);
+        assert_eq!(SafeMath::div_rounded(-96, 10, RoundingMode::Floor), -10);
+        assert_eq!(SafeMath::div_rounded(-96, 10, RoundingMode::Ceil), -9);
+        assert_eq!(SafeMath::div_rounded(-95, 10, RoundingMode::Nearest), -10);
+    }
+
+    #[test]
+    fn test_loss_percent_rounded() {
+        assert_eq!(
+            SafeMath::loss_percent_rounded(1000, 904, RoundingMode::Floor),
+            9
+        );
+        assert_eq!(
+            SafeMath::loss_percent_rounded(1000, 904, RoundingMode::Ceil),
+            10
+        );
+        assert_eq!(
+            SafeMath::loss_percent_rounded(1000, 904, RoundingMode::Nearest),
+            10
+        );
+        assert_eq!(
+            SafeMath::loss_percent_rounded(1000, 904, RoundingMode::Floor),
+            SafeMath::loss_percent(1000, 904)
+        );
+    }
+
+    #[test]
+    fn test_penalty_amount_rounded() {
+        assert_eq!(
+            SafeMath::penalty_amount_rounded(999, 5, RoundingMode::Floor),
+            49
+        );
+        assert_eq!(
+            SafeMath::penalty_amount_rounded(999, 5, RoundingMode::Ceil),
+            50
+        );
+        assert_eq!(
+            SafeMath::penalty_amount_rounded(999, 5, RoundingMode::Nearest),
+            50
+        );
+    }
 }
@@ -10,6 +10,12 @@ impl Pausable {
     /// Storage key for the paused state
     pub const PAUSED_KEY: Symbol = symbol_short!("paused");
 
+    /// Storage key for the mint-only pause flag
+    pub const MINT_PAUSED_KEY: Symbol = symbol_short!("p_mint");
+
+    /// Storage key for the transfer-only pause flag
+    pub const TRANSFER_PAUSED_KEY: Symbol = symbol_short!("p_xfer");
+
     /// Check if the contract is currently paused
     ///
     /// # Arguments
@@ -88,4 +94,195 @@ impl Pausable {
             panic!("Contract is not paused");
         }
     }
+
+    /// Set the paused state without requiring it to change
+    ///
+    /// Unlike `pause`/`unpause` this is idempotent, for admin setters that
+    /// take the desired state. Emits `Pause`/`Unpause` only on a change.
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    /// * `paused` - The desired paused state
+    pub fn set_paused(e: &Env, paused: bool) {
+        if Self::is_paused(e) == paused {
+            return;
+        }
+        if paused {
+            Self::pause(e);
+        } else {
+            Self::unpause(e);
+        }
+    }
+
+    /// Pause or resume minting only
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    /// * `paused` - Whether minting is paused
+    pub fn set_mint_paused(e: &Env, paused: bool) {
+        e.storage().instance().set(&Self::MINT_PAUSED_KEY, &paused);
+        Events::emit(e, symbol_short!("MintPause"), paused);
+    }
+
+    /// Pause or resume transfers only
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    /// * `paused` - Whether transfers are paused
+    pub fn set_transfer_paused(e: &Env, paused: bool) {
+        e.storage()
+            .instance()
+            .set(&Self::TRANSFER_PAUSED_KEY, &paused);
+        Events::emit(e, symbol_short!("XferPause"), paused);
+    }
+
+    /// Check if minting is blocked, either by the mint flag or a full pause
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    ///
+    /// # Returns
+    /// `true` if minting is paused, `false` otherwise
+    pub fn is_mint_paused(e: &Env) -> bool {
+        Self::is_paused(e)
+            || e.storage()
+                .instance()
+                .get::<_, bool>(&Self::MINT_PAUSED_KEY)
+                .unwrap_or(false)
+    }
+
+    /// Check if transfers are blocked, either by the transfer flag or a full pause
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    ///
+    /// # Returns
+    /// `true` if transfers are paused, `false` otherwise
+    pub fn is_transfer_paused(e: &Env) -> bool {
+        Self::is_paused(e)
+            || e.storage()
+                .instance()
+                .get::<_, bool>(&Self::TRANSFER_PAUSED_KEY)
+                .unwrap_or(false)
+    }
+
+    /// Modifier to require that minting is not paused
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    ///
+    /// # Panics
+    /// Panics if the contract or minting is paused
+    pub fn require_mint_not_paused(e: &Env) {
+        Self::require_not_paused(e);
+        if Self::is_mint_paused(e) {
+            panic!("Minting is paused - operation not allowed");
+        }
+    }
+
+    /// Modifier to require that transfers are not paused
+    ///
+    /// # Arguments
+    /// * `e` - The environment
+    ///
+    /// # Panics
+    /// Panics if the contract or transfers are paused
+    pub fn require_transfer_not_paused(e: &Env) {
+        Self::require_not_paused(e);
+        if Self::is_transfer_paused(e) {
+            panic!("Transfers are paused - operation not allowed");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{contract, contractimpl};
+
+    #[contract]
+    pub struct TestPausableContract;
+
+    #[contractimpl]
+    impl TestPausableContract {
+        pub fn stub() {}
+    }
+
+    #[test]
+    fn test_set_paused_toggles_and_is_idempotent() {
+        let e = Env::default();
+        let contract_id = e.register_contract(None, TestPausableContract);
+        e.as_contract(&contract_id, || {
+            assert!(!Pausable::is_paused(&e));
+            Pausable::set_paused(&e, true);
+            Pausable::set_paused(&e, true);
+            assert!(Pausable::is_paused(&e));
+            Pausable::set_paused(&e, false);
+            Pausable::set_paused(&e, false);
+            assert!(!Pausable::is_paused(&e));
+        });
+    }
+
+    #[test]
+    fn test_sub_pauses_are_independent() {
+        let e = Env::default();
+        let contract_id = e.register_contract(None, TestPausableContract);
+        e.as_contract(&contract_id, || {
+            Pausable::set_mint_paused(&e, true);
+            assert!(Pausable::is_mint_paused(&e));
+            assert!(!Pausable::is_transfer_paused(&e));
+            assert!(!Pausable::is_paused(&e));
+            Pausable::require_transfer_not_paused(&e);
+
+            Pausable::set_mint_paused(&e, false);
+            Pausable::set_transfer_paused(&e, true);
+            assert!(!Pausable::is_mint_paused(&e));
+            assert!(Pausable::is_transfer_paused(&e));
+            Pausable::require_mint_not_paused(&e);
+        });
+    }
+
+    #[test]
+    fn test_full_pause_blocks_sub_operations() {
+        let e = Env::default();
+        let contract_id = e.register_contract(None, TestPausableContract);
+        e.as_contract(&contract_id, || {
+            Pausable::set_paused(&e, true);
+            assert!(Pausable::is_mint_paused(&e));
+            assert!(Pausable::is_transfer_paused(&e));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract is paused")]
+    fn test_require_not_paused_enforced() {
+        let e = Env::default();
+        let contract_id = e.register_contract(None, TestPausableContract);
+        e.as_contract(&contract_id, || {
+            Pausable::set_paused(&e, true);
+            Pausable::require_not_paused(&e);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Minting is paused")]
+    fn test_require_mint_not_paused_enforced() {
+        let e = Env::default();
+        let contract_id = e.register_contract(None, TestPausableContract);
+        e.as_contract(&contract_id, || {
+            Pausable::set_mint_paused(&e, true);
+            Pausable::require_mint_not_paused(&e);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Transfers are paused")]
+    fn test_require_transfer_not_paused_enforced() {
+        let e = Env::default();
+        let contract_id = e.register_contract(None, TestPausableContract);
+        e.as_contract(&contract_id, || {
+            Pausable::set_transfer_paused(&e, true);
+            Pausable::require_transfer_not_paused(&e);
+        });
+    }
 }
@@ -8,12 +8,25 @@
 //! - Decimal/rounding edge cases
 
 use crate::harness::{TestHarness, DEFAULT_USER_BALANCE, SECONDS_PER_DAY};
+use attestation_engine::AttestationEngineContractClient;
 use soroban_sdk::{
+    contract, contractimpl,
     testutils::Address as _,
     token::{Client as TokenClient, StellarAssetClient},
     Address, Env,
 };
 
+/// Token stand-in that only reports its decimals.
+#[contract]
+pub struct MockDecimalsToken;
+
+#[contractimpl]
+impl MockDecimalsToken {
+    pub fn decimals(_e: Env) -> u32 {
+        6
+    }
+}
+
 /// Test: Basic token transfer
 #[test]
 fn test_token_basic_transfer() {
@@ -452,3 +465,22 @@ fn test_token_conservation() {
     // Total should be unchanged
     assert_eq!(total_before, total_after);
 }
+
+/// Test: Attestation fee setter records the fee token's decimals
+#[test]
+fn test_attestation_fee_records_token_decimals() {
+    let harness = TestHarness::new();
+    let admin = &harness.accounts.admin;
+    let engine =
+        AttestationEngineContractClient::new(&harness.env, &harness.contracts.attestation_engine);
+    let mock_token = harness.env.register_contract(None, MockDecimalsToken);
+
+    engine.set_attestation_fee(admin, &250_000, &mock_token);
+    assert_eq!(engine.get_fee_decimals(&mock_token), 6);
+
+    // The harness token is a Stellar asset with 7 decimals
+    engine.set_attestation_fee(admin, &1_000, &harness.contracts.token);
+    assert_eq!(engine.get_fee_decimals(&harness.contracts.token), 7);
+    // Earlier assets keep their recorded decimals
+    assert_eq!(engine.get_fee_decimals(&mock_token), 6);
+}